@@ -63,6 +63,7 @@ fn default_config() -> cbindgen::Config {
                 ("VoidArg".into(), "void".into()),
                 ("KeyEventArg".into(), "KeyEvent".into()),
                 ("PointerEventArg".into(), "PointerEvent".into()),
+                ("DropEventArg".into(), "DropEvent".into()),
                 ("PointArg".into(), "Point".into()),
                 ("FloatArg".into(), "float".into()),
                 ("Coord".into(), "float".into()),
@@ -123,6 +124,8 @@ fn gen_corelib(
         "TouchArea",
         "FocusScope",
         "Flickable",
+        "DragArea",
+        "DropArea",
         "Text",
         "Path",
         "WindowItem",
@@ -140,6 +143,7 @@ fn gen_corelib(
         "WindowAdapterRcOpaque",
         "PropertyAnimation",
         "EasingCurve",
+        "EasingKeyframe",
         "TextHorizontalAlignment",
         "TextVerticalAlignment",
         "TextOverflow",
@@ -153,6 +157,7 @@ fn gen_corelib(
         "PointerEventKind",
         "PointerEventButton",
         "PointerEvent",
+        "DropEvent",
         "Rect",
         "SortOrder",
     ]
@@ -174,6 +179,7 @@ fn gen_corelib(
         "StandardListViewItem",
         "Rgb8Pixel",
         "Rgba8Pixel",
+        "AnimationEvent",
     ];
 
     config.export.exclude = [
@@ -195,11 +201,14 @@ fn gen_corelib(
         "slint_property_listener_scope_evaluate",
         "slint_property_listener_scope_is_dirty",
         "PropertyTrackerOpaque",
+        "AnimationControllerOpaque",
+        "ChangeTrackerOpaque",
         "CallbackOpaque",
         "WindowAdapterRc",
         "VoidArg",
         "KeyEventArg",
         "PointerEventArg",
+        "DropEventArg",
         "PointArg",
         "Point",
         "slint_color_brighter",
@@ -209,6 +218,8 @@ fn gen_corelib(
         "slint_image_load_from_path",
         "slint_image_load_from_embedded_data",
         "slint_image_from_embedded_textures",
+        "slint_clipboard_image",
+        "slint_set_clipboard_image",
         "Coord",
         "LogicalRect",
         "LogicalPoint",
@@ -279,6 +290,8 @@ fn gen_corelib(
                 "slint_image_load_from_path",
                 "slint_image_load_from_embedded_data",
                 "slint_image_from_embedded_textures",
+                "slint_clipboard_image",
+                "slint_set_clipboard_image",
                 "SharedPixelBuffer",
                 "SharedImageBuffer",
                 "StaticTextures",
@@ -324,6 +337,9 @@ fn gen_corelib(
             "slint_windowrc_set_focus_item",
             "slint_windowrc_set_component",
             "slint_windowrc_show_popup",
+            "slint_windowrc_has_child_window",
+            "slint_windowrc_show_child_window",
+            "slint_windowrc_hide_child_window",
             "slint_windowrc_set_rendering_notifier",
             "slint_windowrc_request_redraw",
             "slint_windowrc_on_close_requested",
@@ -333,6 +349,12 @@ fn gen_corelib(
             "slint_windowrc_size",
             "slint_windowrc_set_logical_size",
             "slint_windowrc_set_physical_size",
+            "slint_windowrc_is_fullscreen",
+            "slint_windowrc_set_fullscreen",
+            "slint_windowrc_is_maximized",
+            "slint_windowrc_set_maximized",
+            "slint_windowrc_is_minimized",
+            "slint_windowrc_set_minimized",
             "slint_windowrc_dark_color_scheme",
             "slint_windowrc_dispatch_pointer_event",
             "slint_windowrc_dispatch_key_event",
@@ -345,6 +367,8 @@ fn gen_corelib(
             "slint_image_load_from_path",
             "slint_image_load_from_embedded_data",
             "slint_image_from_embedded_textures",
+            "slint_clipboard_image",
+            "slint_set_clipboard_image",
         ]
         .iter()
         .filter(|exclusion| !rust_types.iter().any(|inclusion| inclusion == *exclusion))
@@ -416,6 +440,7 @@ fn gen_corelib(
     cbindgen::Builder::new()
         .with_config(public_config)
         .with_src(crate_dir.join("timers.rs"))
+        .with_src(crate_dir.join("animations.rs"))
         .with_src(crate_dir.join("graphics.rs"))
         .with_src(crate_dir.join("window.rs"))
         .with_src(crate_dir.join("api.rs"))
@@ -455,7 +480,10 @@ fn gen_corelib(
     config.export.body.insert(
         "EasingCurve".to_owned(),
         "    constexpr EasingCurve() : tag(Tag::Linear), cubic_bezier{{0,0,1,1}} {}
-    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+    constexpr explicit EasingCurve(EasingCurve::Tag tag) : tag(tag), cubic_bezier{{0,0,1,1}} {}
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, float stiffness, float damping, float mass) : tag(tag), spring{{stiffness,damping,mass}} {}
+    constexpr EasingCurve(const EasingKeyframe *keyframes, uintptr_t len) : tag(Tag::Keyframes), keyframes{{const_cast<EasingKeyframe *>(keyframes), len}} {}".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -496,7 +524,7 @@ fn gen_corelib(
     namespace cbindgen_private {
         using slint::private_api::WindowAdapterRc;
         using namespace vtable;
-        struct KeyEvent; struct PointerEvent;
+        struct KeyEvent; struct PointerEvent; struct DropEvent;
         using private_api::Property;
         using private_api::PathData;
         using private_api::Point;
@@ -536,6 +564,9 @@ fn gen_backend_qt(
         "NativeComboBoxPopup",
         "NativeTabWidget",
         "NativeTab",
+        "NativeMenuItem",
+        "NativeMenuFrame",
+        "NativeMenuBarItem",
         "NativeStyleMetrics",
     ];
 
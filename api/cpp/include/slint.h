@@ -29,6 +29,7 @@ struct ItemVTable;
 #include "slint_internal.h"
 #include "slint_size.h"
 #include "slint_point.h"
+#include "slint_date_time.h"
 #include "slint_backend_internal.h"
 #include "slint_qt_internal.h"
 
@@ -63,6 +64,7 @@ using ItemArray = slint::cbindgen_private::Slice<ItemArrayEntry>;
 using cbindgen_private::KeyboardModifiers;
 using cbindgen_private::KeyEvent;
 using cbindgen_private::PointerEvent;
+using cbindgen_private::DropEvent;
 using cbindgen_private::TableColumn;
 
 /// Internal function that checks that the API that must be called from the main
@@ -153,10 +155,33 @@ public:
 
     template<typename Component, typename Parent>
     void show_popup(const Parent *parent_component, cbindgen_private::Point p,
+                    cbindgen_private::PopupClosePolicy close_policy,
                     cbindgen_private::ItemRc parent_item) const
     {
-        auto popup = Component::create(parent_component).into_dyn();
-        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, &parent_item);
+        auto popup = Component::create(parent_component, *this).into_dyn();
+        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, close_policy, &parent_item);
+    }
+
+    template<typename Component, typename Parent>
+    void show_child_window(const Parent *parent_component, uintptr_t index,
+                           cbindgen_private::ItemRc parent_item) const
+    {
+        if (cbindgen_private::slint_windowrc_has_child_window(&inner, index, &parent_item)) {
+            cbindgen_private::slint_windowrc_show_child_window(&inner, index, &parent_item,
+                                                               nullptr, nullptr);
+        } else {
+            // The default constructor creates a new window adapter
+            WindowAdapterRc child_window_adapter;
+            auto child_window =
+                    Component::create(parent_component, child_window_adapter).into_dyn();
+            cbindgen_private::slint_windowrc_show_child_window(
+                    &inner, index, &parent_item, &child_window_adapter.inner, &child_window);
+        }
+    }
+
+    void hide_child_window(uintptr_t index, cbindgen_private::ItemRc parent_item) const
+    {
+        cbindgen_private::slint_windowrc_hide_child_window(&inner, index, &parent_item);
     }
 
     template<std::invocable<RenderingState, GraphicsAPI> F>
@@ -222,6 +247,24 @@ public:
         cbindgen_private::slint_windowrc_set_physical_size(&inner, &size);
     }
 
+    bool is_fullscreen() const { return cbindgen_private::slint_windowrc_is_fullscreen(&inner); }
+    void set_fullscreen(bool value) const
+    {
+        cbindgen_private::slint_windowrc_set_fullscreen(&inner, value);
+    }
+
+    bool is_maximized() const { return cbindgen_private::slint_windowrc_is_maximized(&inner); }
+    void set_maximized(bool value) const
+    {
+        cbindgen_private::slint_windowrc_set_maximized(&inner, value);
+    }
+
+    bool is_minimized() const { return cbindgen_private::slint_windowrc_is_minimized(&inner); }
+    void set_minimized(bool value) const
+    {
+        cbindgen_private::slint_windowrc_set_minimized(&inner, value);
+    }
+
     void dispatch_key_event(const cbindgen_private::KeyInputEvent &event)
     {
         private_api::assert_main_thread();
@@ -479,6 +522,30 @@ public:
     /// a window frame (if present).
     void set_size(const slint::PhysicalSize &size) { inner.set_physical_size(size); }
 
+    /// Returns true if the window is in full screen mode.
+    ///
+    /// This is the value of the `full-screen` property of the `Window` element, which is also
+    /// updated when the user leaves the full screen mode.
+    bool is_fullscreen() const { return inner.is_fullscreen(); }
+    /// Shows the window in full screen mode, or leaves the full screen mode.
+    void set_fullscreen(bool fullscreen) { inner.set_fullscreen(fullscreen); }
+
+    /// Returns true if the window is maximized.
+    ///
+    /// This is the value of the `maximized` property of the `Window` element, which is also
+    /// updated when the user maximizes or restores the window.
+    bool is_maximized() const { return inner.is_maximized(); }
+    /// Maximizes the window, or restores it to its previous size.
+    void set_maximized(bool maximized) { inner.set_maximized(maximized); }
+
+    /// Returns true if the window is minimized.
+    ///
+    /// This is the value of the `minimized` property of the `Window` element, which is also
+    /// updated when the user minimizes or restores the window.
+    bool is_minimized() const { return inner.is_minimized(); }
+    /// Minimizes the window, or restores it.
+    void set_minimized(bool minimized) { inner.set_minimized(minimized); }
+
     /// Dispatch a key press event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -1291,6 +1358,10 @@ class Repeater
         {
             State state = State::Dirty;
             std::optional<ComponentHandle<C>> ptr;
+            // Position of the component in the viewport of a ListView, set by
+            // compute_layout_listview
+            float listview_y = 0;
+            float listview_height = 0;
         };
         std::vector<ComponentWithState> data;
         private_api::Property<bool> is_dirty { true };
@@ -1378,18 +1449,81 @@ public:
         }
     }
 
+    /// Returns the range of the rows that are visible in the ListView
     template<typename Parent>
-    void ensure_updated_listview(const Parent *parent,
-                                 const private_api::Property<float> *viewport_width,
-                                 const private_api::Property<float> *viewport_height,
-                                 [[maybe_unused]] const private_api::Property<float> *viewport_y,
-                                 float listview_width, [[maybe_unused]] float listview_height) const
+    private_api::IndexRange
+    ensure_updated_listview(const Parent *parent,
+                            const private_api::Property<float> *viewport_width,
+                            const private_api::Property<float> *viewport_height,
+                            const private_api::Property<float> *viewport_y, float listview_width,
+                            float listview_height) const
     {
         // TODO: the rust code in model.rs try to only allocate as many items as visible items
         ensure_updated(parent);
 
         float h = compute_layout_listview(viewport_width, listview_width);
         viewport_height->set(h);
+
+        private_api::IndexRange visible_rows { 0, 0 };
+        if (!inner)
+            return visible_rows;
+        float top = -viewport_y->get();
+        float bottom = top + listview_height;
+        for (std::size_t i = 0; i < inner->data.size(); ++i) {
+            const auto &x = inner->data[i];
+            if (x.listview_y + x.listview_height <= top) {
+                continue;
+            }
+            if (x.listview_y >= bottom) {
+                break;
+            }
+            if (visible_rows.start == visible_rows.end) {
+                visible_rows.start = i;
+            }
+            visible_rows.end = i + 1;
+        }
+        return visible_rows;
+    }
+
+    /// Scroll the viewport of the ListView such that `row` is visible and placed according to
+    /// `alignment`. Returns false if the rows were not laid out yet.
+    bool listview_scroll_to(int row, cbindgen_private::ScrollAlignment alignment,
+                            const private_api::Property<float> *viewport_y,
+                            float listview_height) const
+    {
+        auto m = model.get();
+        if (!m || m->row_count() == 0) {
+            return true;
+        }
+        if (!inner || inner->data.size() != m->row_count()) {
+            return false;
+        }
+        const auto &x = inner->data[std::min(std::size_t(row), inner->data.size() - 1)];
+        const auto &last = inner->data.back();
+        float content_height = last.listview_y + last.listview_height;
+
+        float current_y = viewport_y->get();
+        float new_y = current_y;
+        switch (alignment) {
+        case cbindgen_private::ScrollAlignment::Start:
+            new_y = -x.listview_y;
+            break;
+        case cbindgen_private::ScrollAlignment::Center:
+            new_y = (listview_height - x.listview_height) / 2 - x.listview_y;
+            break;
+        case cbindgen_private::ScrollAlignment::End:
+            new_y = listview_height - x.listview_height - x.listview_y;
+            break;
+        case cbindgen_private::ScrollAlignment::Nearest:
+            if (x.listview_y < -current_y) {
+                new_y = -x.listview_y;
+            } else if (x.listview_y + x.listview_height > -current_y + listview_height) {
+                new_y = listview_height - x.listview_height - x.listview_y;
+            }
+            break;
+        }
+        viewport_y->set(std::min(0.f, std::max(new_y, listview_height - content_height)));
+        return true;
     }
 
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
@@ -1430,7 +1564,9 @@ public:
         if (!inner)
             return offset;
         for (auto &x : inner->data) {
+            x.listview_y = offset;
             (*x.ptr)->listview_layout(&offset, viewport_width);
+            x.listview_height = offset - x.listview_y;
         }
         return offset;
     }
@@ -1502,6 +1638,149 @@ inline void quit_event_loop()
     cbindgen_private::slint_quit_event_loop();
 }
 
+namespace private_api {
+inline SharedString translate(const SharedString &original, const SharedString &context,
+                              const SharedString &domain,
+                              cbindgen_private::Slice<SharedString> arguments, int n,
+                              const SharedString &plural)
+{
+    SharedString result = original;
+    cbindgen_private::slint_translate(&result, &context, &domain, arguments, n, &plural);
+    return result;
+}
+
+/// Implementation of `Clipboard.text()`
+inline SharedString clipboard_text()
+{
+    SharedString result;
+    cbindgen_private::slint_clipboard_text(&result);
+    return result;
+}
+
+/// Implementation of `Clipboard.set-text(text)`
+inline void set_clipboard_text(const SharedString &text)
+{
+    cbindgen_private::slint_set_clipboard_text(&text);
+}
+
+/// Implementation of `string.split(separator)`. An empty separator doesn't split the string.
+inline std::shared_ptr<Model<SharedString>> string_split(const SharedString &str,
+                                                         const SharedString &separator)
+{
+    std::vector<SharedString> parts;
+    std::string_view view = str;
+    std::string_view sep = separator;
+    if (!sep.empty()) {
+        for (auto pos = view.find(sep); pos != std::string_view::npos; pos = view.find(sep)) {
+            parts.emplace_back(view.substr(0, pos));
+            view.remove_prefix(pos + sep.size());
+        }
+    }
+    parts.emplace_back(view);
+    return std::make_shared<VectorModel<SharedString>>(std::move(parts));
+}
+
+/// Call \a f with each element of the model and its index, until \a f returns true.
+/// The dependencies are registered so that the binding calling this function is re-evaluated
+/// when the model changes.
+template<typename M, typename F>
+void array_visit(const M &model, F &&f)
+{
+    model->track_row_count_changes();
+    for (size_t i = 0; i < model->row_count(); ++i) {
+        if (auto data = model->row_data_tracked(i)) {
+            if (f(*data, int(i)))
+                return;
+        }
+    }
+}
+
+/// Implementation of `array.filter(x => ...)` and `array.slice(start, end)`
+template<typename M, typename F>
+auto array_filter(const M &model, F &&predicate)
+{
+    using T = std::decay_t<decltype(*model->row_data(0))>;
+    std::vector<T> result;
+    array_visit(model, [&](const T &element, int index) {
+        if (predicate(element, index))
+            result.push_back(element);
+        return false;
+    });
+    return std::shared_ptr<Model<T>>(std::make_shared<VectorModel<T>>(std::move(result)));
+}
+
+/// Implementation of `array.map(x => ...)`
+template<typename R, typename M, typename F>
+std::shared_ptr<Model<R>> array_map(const M &model, F &&map)
+{
+    std::vector<R> result;
+    array_visit(model, [&](const auto &element, int index) {
+        result.push_back(map(element, index));
+        return false;
+    });
+    return std::make_shared<VectorModel<R>>(std::move(result));
+}
+
+/// Implementation of `array.any(x => ...)`
+template<typename M, typename F>
+bool array_any(const M &model, F &&predicate)
+{
+    bool result = false;
+    array_visit(model, [&](const auto &element, int index) {
+        result = predicate(element, index);
+        return result;
+    });
+    return result;
+}
+
+/// Implementation of `array.all(x => ...)`
+template<typename M, typename F>
+bool array_all(const M &model, F &&predicate)
+{
+    bool result = true;
+    array_visit(model, [&](const auto &element, int index) {
+        result = predicate(element, index);
+        return !result;
+    });
+    return result;
+}
+
+/// Implementation of `array.sum()` and `array.sum(x => ...)`
+template<typename R, typename M, typename F>
+R array_sum(const M &model, F &&value)
+{
+    R result = 0;
+    array_visit(model, [&](const auto &element, int index) {
+        result += value(element, index);
+        return false;
+    });
+    return result;
+}
+
+/// Implementation of `array.index-of(value)`
+template<typename M, typename F>
+int array_index_of(const M &model, F &&predicate)
+{
+    int result = -1;
+    array_visit(model, [&](const auto &element, int index) {
+        if (predicate(element, index))
+            result = index;
+        return result >= 0;
+    });
+    return result;
+}
+}
+
+/// Re-translate all the strings marked with `@tr(...)` in the `.slint` files.
+///
+/// Call this function after changing the language at run-time (for example by changing the
+/// `LANGUAGE` environment variable when using gettext), so that the bindings that depend on
+/// translated strings are re-evaluated.
+inline void update_all_translations()
+{
+    cbindgen_private::slint_translations_mark_dirty();
+}
+
 /// Adds the specified functor to an internal queue, notifies the event loop to wake up.
 /// Once woken up, any queued up functors will be invoked.
 /// This function is thread-safe and can be called from any thread, including the one
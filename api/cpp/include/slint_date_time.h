@@ -0,0 +1,171 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+#pragma once
+
+#include <chrono>
+#include <compare>
+#include <cstdint>
+#include <cstdio>
+#include <ostream>
+#include <string_view>
+#include "slint_string.h"
+
+namespace slint {
+
+/// A DateTime represents a point in time, with a precision of a millisecond. It is stored as
+/// the number of milliseconds since the Unix epoch (1970-01-01T00:00:00Z).
+///
+/// The calendar components such as year() or hour() are computed in UTC, using the
+/// proleptic Gregorian calendar.
+///
+/// This type corresponds to the `date-time` type in the .slint language.
+class DateTime
+{
+public:
+    /// Default constructs a DateTime at the Unix epoch.
+    DateTime() = default;
+
+    /// Constructs a DateTime from a std::chrono::system_clock time point.
+    explicit DateTime(std::chrono::system_clock::time_point time)
+        : timestamp_ms(std::chrono::duration_cast<std::chrono::milliseconds>(
+                               time.time_since_epoch())
+                               .count())
+    {
+    }
+
+    /// Creates a DateTime from a number of seconds since the Unix epoch.
+    static DateTime from_timestamp(int64_t seconds)
+    {
+        return from_timestamp_millis(seconds * 1000);
+    }
+
+    /// Creates a DateTime from a number of milliseconds since the Unix epoch.
+    static DateTime from_timestamp_millis(int64_t milliseconds)
+    {
+        DateTime d;
+        d.timestamp_ms = milliseconds;
+        return d;
+    }
+
+    /// Creates a DateTime from its calendar components, in UTC.
+    ///
+    /// \a month and \a day start at 1. Values out of their range overflow into the next
+    /// component, so that for example the 32nd of January is the first of February.
+    static DateTime from_components(int32_t year, int32_t month, int32_t day, int32_t hour = 0,
+                                    int32_t minute = 0, int32_t second = 0)
+    {
+        int64_t m = int64_t(month) - 1;
+        int64_t y = year + div_euclid(m, 12);
+        int64_t days = days_from_civil(y, unsigned(rem_euclid(m, 12)) + 1, 1) + day - 1;
+        int64_t seconds = int64_t(hour) * 3600 + int64_t(minute) * 60 + second;
+        return from_timestamp_millis(days * millis_per_day + seconds * 1000);
+    }
+
+    /// Returns the current date and time, from the system clock.
+    static DateTime now() { return DateTime(std::chrono::system_clock::now()); }
+
+    /// Returns the number of seconds since the Unix epoch, rounded down.
+    int64_t timestamp() const { return div_euclid(timestamp_ms, 1000); }
+    /// Returns the number of milliseconds since the Unix epoch.
+    int64_t timestamp_millis() const { return timestamp_ms; }
+
+    /// Returns the year.
+    int32_t year() const { return int32_t(civil().year); }
+    /// Returns the month, between 1 and 12.
+    int32_t month() const { return int32_t(civil().month); }
+    /// Returns the day of the month, between 1 and 31.
+    int32_t day() const { return int32_t(civil().day); }
+    /// Returns the hour, between 0 and 23.
+    int32_t hour() const { return int32_t(millis_of_day() / 3600'000); }
+    /// Returns the minute, between 0 and 59.
+    int32_t minute() const { return int32_t(millis_of_day() / 60'000 % 60); }
+    /// Returns the second, between 0 and 59.
+    int32_t second() const { return int32_t(millis_of_day() / 1000 % 60); }
+    /// Returns the day of the week, between 0 (Sunday) and 6 (Saturday).
+    int32_t day_of_week() const
+    {
+        // The Unix epoch was a Thursday
+        return int32_t(rem_euclid(div_euclid(timestamp_ms, millis_per_day) + 4, 7));
+    }
+
+    /// Returns the date and time formatted according to ISO 8601, such as `2023-05-04T12:30:00Z`.
+    SharedString to_iso_string() const
+    {
+        auto c = civil();
+        char buffer[64];
+        int len = std::snprintf(buffer, sizeof(buffer), "%04lld-%02u-%02uT%02d:%02d:%02dZ",
+                                static_cast<long long>(c.year), c.month, c.day, hour(), minute(),
+                                second());
+        return SharedString(std::string_view(buffer, len));
+    }
+
+    /// Converts this DateTime to a std::chrono::system_clock time point.
+    std::chrono::system_clock::time_point to_time_point() const
+    {
+        return std::chrono::system_clock::time_point(
+                std::chrono::duration_cast<std::chrono::system_clock::duration>(
+                        std::chrono::milliseconds(timestamp_ms)));
+    }
+
+    /// Compares this DateTime with \a other.
+    auto operator<=>(const DateTime &other) const = default;
+
+    /// Writes the \a date formatted according to ISO 8601 to the specified \a stream and returns
+    /// a reference to the stream.
+    friend std::ostream &operator<<(std::ostream &stream, const DateTime &date)
+    {
+        return stream << date.to_iso_string();
+    }
+
+private:
+    static constexpr int64_t millis_per_day = 24 * 3600'000;
+
+    struct Civil
+    {
+        int64_t year;
+        unsigned month;
+        unsigned day;
+    };
+
+    static int64_t div_euclid(int64_t a, int64_t b)
+    {
+        int64_t q = a / b;
+        return (a % b < 0) ? q - 1 : q;
+    }
+    static int64_t rem_euclid(int64_t a, int64_t b)
+    {
+        int64_t r = a % b;
+        return r < 0 ? r + b : r;
+    }
+
+    int64_t millis_of_day() const { return rem_euclid(timestamp_ms, millis_per_day); }
+
+    // Algorithms from http://howardhinnant.github.io/date_algorithms.html
+    static int64_t days_from_civil(int64_t y, unsigned m, unsigned d)
+    {
+        y -= m <= 2;
+        int64_t era = div_euclid(y, 400);
+        int64_t yoe = rem_euclid(y, 400);
+        int64_t doy = (153 * (int64_t(m) + (m > 2 ? -3 : 9)) + 2) / 5 + d - 1;
+        int64_t doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        return era * 146097 + doe - 719468;
+    }
+
+    Civil civil() const
+    {
+        int64_t z = div_euclid(timestamp_ms, millis_per_day) + 719468;
+        int64_t era = div_euclid(z, 146097);
+        int64_t doe = rem_euclid(z, 146097);
+        int64_t yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        int64_t doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        int64_t mp = (5 * doy + 2) / 153;
+        unsigned d = unsigned(doy - (153 * mp + 2) / 5 + 1);
+        unsigned m = unsigned(mp < 10 ? mp + 3 : mp - 9);
+        return Civil { yoe + era * 400 + (m <= 2), m, d };
+    }
+
+    int64_t timestamp_ms = 0;
+};
+
+}
@@ -106,6 +106,11 @@ private:
 ///   raw_data[i] = { bgr_data[i * 3 + 2], bgr_data[i * 3 + 1], bgr_data[i * 3] };
 /// }
 /// ```
+struct Image;
+namespace private_api {
+inline void set_clipboard_image(const Image &image);
+}
+
 struct Image
 {
 public:
@@ -165,6 +170,7 @@ public:
     explicit Image(cbindgen_private::types::Image inner) : data(inner) { }
 
 private:
+    friend void private_api::set_clipboard_image(const Image &image);
     using Tag = cbindgen_private::types::ImageInner::Tag;
     using Data = cbindgen_private::types::Image;
     Data data;
@@ -191,6 +197,20 @@ inline Image image_from_embedded_textures(const cbindgen_private::types::StaticT
     cbindgen_private::types::slint_image_from_embedded_textures(textures, &img);
     return Image(img);
 }
+
+/// Implementation of `Clipboard.image()`
+inline Image clipboard_image()
+{
+    cbindgen_private::types::Image img(cbindgen_private::types::Image::ImageInner_None());
+    cbindgen_private::types::slint_clipboard_image(&img);
+    return Image(img);
+}
+
+/// Implementation of `Clipboard.set-image(image)`
+inline void set_clipboard_image(const Image &image)
+{
+    cbindgen_private::types::slint_set_clipboard_image(&image.data);
+}
 }
 
 }
@@ -365,6 +365,18 @@ public:
         }
     }
 
+    /// Returns a std::optional that contains a DateTime if the type of this Value is
+    /// Type::DateTime, otherwise an empty optional is returned.
+    std::optional<DateTime> to_date_time() const
+    {
+        int64_t timestamp_ms = 0;
+        if (cbindgen_private::slint_interpreter_value_to_date_time(&inner, &timestamp_ms)) {
+            return DateTime::from_timestamp_millis(timestamp_ms);
+        } else {
+            return {};
+        }
+    }
+
     // template<typename T> std::optional<T> get() const;
 
     /// Constructs a new Value that holds the double \a value.
@@ -397,6 +409,12 @@ public:
     /// Constructs a new Value that holds the Image \a img.
     Value(const Image &img) { cbindgen_private::slint_interpreter_value_new_image(&img, &inner); }
 
+    /// Constructs a new Value that holds the DateTime \a date.
+    Value(const DateTime &date)
+    {
+        cbindgen_private::slint_interpreter_value_new_date_time(date.timestamp_millis(), &inner);
+    }
+
     /// Returns the type the variant holds.
     Type type() const { return cbindgen_private::slint_interpreter_value_type(&inner); }
 
@@ -4,6 +4,16 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <chrono>
+#include <concepts>
+#include <cstdint>
+#include <optional>
+#include <type_traits>
+#include <utility>
+
+namespace slint {
+enum class AnimationEvent : uint8_t;
+}
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
@@ -11,6 +21,95 @@ struct PropertyAnimation;
 
 #include "slint_properties_internal.h"
 
+namespace slint {
+
+namespace private_api {
+template<typename T>
+struct Property;
+}
+
+/// A handle to query and control the animation of a property that has an `animate` declaration.
+///
+/// The controller stays valid for the successive animations of the property: when the property
+/// gets a new value, a new animation starts and is controlled by the same controller.
+/// Copying the controller gives another handle to the same animation.
+class AnimationController
+{
+public:
+    /// Creates a new handle to the same animation as \a other.
+    AnimationController(const AnimationController &other)
+    {
+        cbindgen_private::slint_animation_controller_clone(&other.inner, &inner);
+    }
+    /// Makes this handle refer to the same animation as \a other.
+    AnimationController &operator=(const AnimationController &other)
+    {
+        if (this != &other) {
+            cbindgen_private::slint_animation_controller_drop(&inner);
+            cbindgen_private::slint_animation_controller_clone(&other.inner, &inner);
+        }
+        return *this;
+    }
+    /// Destroys the handle. This doesn't affect the animation.
+    ~AnimationController() { cbindgen_private::slint_animation_controller_drop(&inner); }
+
+    /// Returns true if the animation has started and has not finished yet.
+    /// A paused animation is still running.
+    bool is_running() const
+    {
+        return cbindgen_private::slint_animation_controller_is_running(&inner);
+    }
+    /// Returns true if the animation is paused.
+    bool is_paused() const
+    {
+        return cbindgen_private::slint_animation_controller_is_paused(&inner);
+    }
+    /// Returns the progress of the current iteration of the animation, between 0 and 1, before
+    /// the easing curve is applied.
+    float progress() const
+    {
+        return cbindgen_private::slint_animation_controller_progress(&inner);
+    }
+
+    /// Pauses the animation. The property keeps its current value until resume() is called.
+    void pause() const { cbindgen_private::slint_animation_controller_pause(&inner); }
+    /// Resumes an animation that was paused with pause().
+    void resume() const { cbindgen_private::slint_animation_controller_resume(&inner); }
+    /// Moves the animation to the given \a position from its start, including its delay.
+    /// If the animation had already finished, it is started again from that position.
+    void seek(std::chrono::milliseconds position) const
+    {
+        cbindgen_private::slint_animation_controller_seek(&inner, position.count());
+    }
+    /// Stops the animation: the property jumps to its final value, and the animation is reported
+    /// as finished.
+    void cancel() const { cbindgen_private::slint_animation_controller_cancel(&inner); }
+
+    /// Sets the function that is called with an AnimationEvent when the animation starts or
+    /// finishes. The \a handler is called on the next animation frame after the event happened.
+    template<std::invocable<AnimationEvent> F>
+    void set_event_handler(F handler) const
+    {
+        cbindgen_private::slint_animation_controller_set_event_handler(
+                &inner,
+                [](void *user_data, AnimationEvent event) {
+                    (*reinterpret_cast<F *>(user_data))(event);
+                },
+                new F(std::move(handler)),
+                [](void *user_data) { delete reinterpret_cast<F *>(user_data); });
+    }
+
+private:
+    template<typename T>
+    friend struct private_api::Property;
+    explicit AnimationController(cbindgen_private::AnimationControllerOpaque inner) : inner(inner)
+    {
+    }
+    cbindgen_private::AnimationControllerOpaque inner;
+};
+
+} // namespace slint
+
 namespace slint::private_api {
 
 using cbindgen_private::StateInfo;
@@ -138,6 +237,15 @@ struct Property
     bool is_dirty() const { return cbindgen_private::slint_property_is_dirty(&inner); }
     void mark_dirty() const { cbindgen_private::slint_property_mark_dirty(&inner); }
 
+    std::optional<AnimationController> animation_controller() const
+    {
+        cbindgen_private::AnimationControllerOpaque controller;
+        if (!cbindgen_private::slint_property_animation_controller(&inner, &controller)) {
+            return {};
+        }
+        return AnimationController(controller);
+    }
+
     static void link_two_way(const Property<T> *p1, const Property<T> *p2)
     {
         auto value = p2->get();
@@ -292,4 +400,56 @@ private:
     cbindgen_private::PropertyTrackerOpaque inner;
 };
 
+/// ChangeTracker calls a functor when the value returned by another functor changes.
+/// Contrary to a binding, the functor isn't called right away when the properties change, but
+/// later, from the event loop, once the property changes have settled.
+struct ChangeTracker
+{
+    /// Constructs a new change tracker instance.
+    ChangeTracker() { cbindgen_private::slint_change_tracker_construct(&inner); }
+    /// Destroys the change tracker.
+    ~ChangeTracker() { cbindgen_private::slint_change_tracker_drop(&inner); }
+    /// The copy constructor is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker(const ChangeTracker &) = delete;
+    /// The assignment operator is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker &operator=(const ChangeTracker &) = delete;
+
+    /// Initializes the change tracker. \a fn_eval is invoked with \a data right away to compute
+    /// the initial value. Then \a fn_notify is invoked with \a data and the new value each time
+    /// the value returned by \a fn_eval changes.
+    template<typename Data, typename FnEval, typename FnNotify>
+    void init(Data data, FnEval fn_eval, FnNotify fn_notify) const
+    {
+        using Value = std::invoke_result_t<FnEval, const Data &>;
+        struct Inner
+        {
+            Data data;
+            FnEval fn_eval;
+            FnNotify fn_notify;
+            Value value = {};
+        };
+        cbindgen_private::slint_change_tracker_init(
+                &inner, new Inner { std::move(data), std::move(fn_eval), std::move(fn_notify) },
+                [](void *d) { delete reinterpret_cast<Inner *>(d); },
+                [](void *d) {
+                    auto inner = reinterpret_cast<Inner *>(d);
+                    auto value = inner->fn_eval(std::as_const(inner->data));
+                    if (value == inner->value) {
+                        return false;
+                    }
+                    inner->value = std::move(value);
+                    return true;
+                },
+                [](void *d) {
+                    auto inner = reinterpret_cast<Inner *>(d);
+                    // Copy the value so that the functor can cause the tracker to be evaluated again
+                    auto value = inner->value;
+                    inner->fn_notify(std::as_const(inner->data), value);
+                });
+    }
+
+private:
+    cbindgen_private::ChangeTrackerOpaque inner;
+};
+
 } // namespace slint::private_api
@@ -223,6 +223,39 @@ inline cbindgen_private::Slice<uint8_t> string_to_slice(std::string_view str)
         const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(str.data())), str.size()
     };
 }
+
+inline SharedString string_to_uppercase(const SharedString &str)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_to_uppercase(&result, &str);
+    return result;
+}
+
+inline SharedString string_to_lowercase(const SharedString &str)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_to_lowercase(&result, &str);
+    return result;
+}
+
+inline SharedString string_trim(const SharedString &str)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_trim(&result, &str);
+    return result;
+}
+
+inline int string_character_count(const SharedString &str)
+{
+    return int(cbindgen_private::slint_shared_string_character_count(&str));
+}
+
+inline SharedString format_number(double value, int decimals, bool grouping)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_format_number(&result, value, decimals, grouping);
+    return result;
+}
 }
 
 }
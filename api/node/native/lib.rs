@@ -172,6 +172,9 @@ fn to_eval_value<'cx>(
             Ok(Value::Number(val.downcast_or_throw::<JsNumber, _>(cx)?.value()))
         }
         Type::String => Ok(Value::String(val.to_string(cx)?.value().into())),
+        Type::DateTime => Ok(Value::DateTime(slint_interpreter::DateTime::from_timestamp_millis(
+            val.downcast_or_throw::<JsNumber, _>(cx)?.value() as i64,
+        ))),
         Type::Color | Type::Brush => {
             let c = val
                 .to_string(cx)?
@@ -224,7 +227,14 @@ fn to_eval_value<'cx>(
                     .collect::<Result<_, _>>()?,
             ))
         }
-        Type::Enumeration(_) => todo!(),
+        Type::Enumeration(e) => {
+            let value = val.to_string(cx)?.value();
+            if !e.values.contains(&value) {
+                return cx
+                    .throw_error(format!("{:?} is not a value of the enum {}", value, e.name));
+            }
+            Ok(Value::EnumerationValue(e.name.clone(), value))
+        }
         Type::Invalid
         | Type::Void
         | Type::InferredProperty
@@ -250,6 +260,7 @@ fn to_js_value<'cx>(
         Value::Number(n) => JsNumber::new(cx, n).as_value(cx),
         Value::String(s) => JsString::new(cx, s.as_str()).as_value(cx),
         Value::Bool(b) => JsBoolean::new(cx, b).as_value(cx),
+        Value::DateTime(d) => JsNumber::new(cx, d.timestamp_millis() as f64).as_value(cx),
         Value::Image(r) => match (&r).into() {
             &ImageInner::None => JsUndefined::new().as_value(cx),
             &ImageInner::EmbeddedImage { .. }
@@ -285,6 +296,7 @@ fn to_js_value<'cx>(
             &format!("#{:02x}{:02x}{:02x}{:02x}", c.red(), c.green(), c.blue(), c.alpha()),
         )
         .as_value(cx),
+        Value::EnumerationValue(_, value) => JsString::new(cx, value.as_str()).as_value(cx),
         _ => todo!("converting {:?} to js has not been implemented", val),
     })
 }
@@ -313,6 +313,8 @@ pub fn compile_with_config(
         });
     }
 
+    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
 
     // 'spin_on' is ok here because the compiler in single threaded and does not block if there is no blocking future
@@ -372,6 +372,7 @@ pub fn slint(stream: TokenStream) -> TokenStream {
     }
 
     compiler_config.include_paths = include_paths;
+    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
     let (root_component, diag) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
     //println!("{:#?}", tree);
@@ -27,6 +27,7 @@ pub mod generated_code {
     /// ```slint
     /// export component SampleComponent inherits Window {
     ///     in-out property<int> counter;
+    ///     animate counter { duration: 250ms; }
     ///     // note that dashes will be replaced by underscores in the generated code
     ///     in-out property<string> user-name;
     ///     callback hello();
@@ -51,6 +52,18 @@ pub fn get_counter(&self) -> i32 {
         /// In this case, this is the setter that sets the value of the `counter` property
         /// declared in the `.slint` design markup.
         pub fn set_counter(&self, value: i32) {}
+        /// For each animated property declared at the root of the component, a function is generated
+        /// that returns the [`AnimationController`](crate::AnimationController) of the animation
+        /// currently running on the property, or `None` if the property isn't being animated.
+        pub fn get_counter_animation(&self) -> Option<crate::AnimationController> {
+            unimplemented!()
+        }
+        /// For each property declared at the root of the component, a function is generated
+        /// that installs a callback, called with the new value when the property has changed.
+        /// The callback isn't called right away, but from the event loop once the property changes
+        /// have settled, so several changes in a row only cause one call.
+        /// The callback stays installed as long as the component is alive.
+        pub fn observe_counter(&self, f: impl FnMut(i32) + 'static) {}
         /// Returns the value of the `user_name` property declared in the `.slint` design markup.
         pub fn get_user_name(&self) -> crate::SharedString {
             unimplemented!()
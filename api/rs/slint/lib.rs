@@ -164,6 +164,9 @@ struct MyComponent{ /*...*/ }
 
 To run a function with a delay or with an interval use a [`Timer`].
 
+Use [`spawn_local`] to run a [`Future`](core::future::Future) in the event loop. It can update the components
+directly, and await [`sleep`], [`Window::next_frame`], or futures that are completed from other threads.
+
 ## Type Mappings
 
 The types used for properties in `.slint` design markup each translate to specific types in Rust.
@@ -182,6 +185,7 @@ struct MyComponent{ /*...*/ }
 | `length` | `f32` | At run-time, logical lengths are automatically translated to physical pixels using the device pixel ratio. |
 | `duration` | `i64` | At run-time, durations are always represented as signed 64-bit integers with millisecond precision. |
 | `angle` | `f32` | The value in degrees |
+| `date-time` | [`DateTime`] | |
 | `relative-font-size` | `f32` | Relative font size factor that is multiplied with the `Window.default-font-size` and can be converted to a `length`. |
 | structure | `struct` of the same name | |
 | anonymous object | anonymous tuple | The fields are in alphabetical order. |
@@ -242,7 +246,9 @@ struct MyStruct {
 
 pub use slint_macros::slint;
 
+pub use i_slint_core::animations::{AnimationController, AnimationEvent};
 pub use i_slint_core::api::*;
+pub use i_slint_core::date_time::DateTime;
 pub use i_slint_core::graphics::{
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
@@ -263,6 +269,36 @@ pub fn run_event_loop() -> Result<(), PlatformError> {
     i_slint_backend_selector::with_platform(|b| b.run_event_loop())
 }
 
+/// Processes the pending events and renders the visible windows, waiting up to `timeout` for
+/// new events if there are none. Use this function instead of [`run_event_loop()`] to integrate
+/// Slint into another event loop, such as the main loop of a game.
+///
+/// Returns [`ControlFlow::Break`](core::ops::ControlFlow::Break) when the event loop would have
+/// exited. See [`Platform::process_events()`](platform::Platform::process_events()) for details,
+/// and [`platform::set_event_loop_waker()`] to know when there are new events to process.
+///
+/// With the winit backend, each call runs one iteration of winit's event loop with `run_return()`.
+/// Winit doesn't officially support calling it repeatedly to pump events: it's not available on
+/// the web, and on macOS the operating system may deliver some events, such as the ones of the
+/// application menu, only while [`run_event_loop()`] is running. Prefer [`run_event_loop()`] when
+/// there is no other event loop to integrate with.
+///
+/// ## Example
+/// ```rust,no_run
+/// # i_slint_backend_testing::init();
+/// slint::slint!{ export component MyApp inherits Window {} }
+/// let ui = MyApp::new().unwrap();
+/// ui.show().unwrap();
+/// while slint::process_events(std::time::Duration::from_millis(16)).unwrap().is_continue() {
+///     // Update the game state ...
+/// }
+/// ```
+pub fn process_events(
+    timeout: core::time::Duration,
+) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+    i_slint_backend_selector::with_platform(|b| b.process_events(timeout))
+}
+
 /// Include the code generated with the slint-build crate from the build script. After calling `slint_build::compile`
 /// in your `build.rs` build script, the use of this macro includes the generated Rust code and makes the exported types
 /// available for you to instantiate.
@@ -275,6 +311,40 @@ macro_rules! include_modules {
     };
 }
 
+/// Initialize the translations when using the `gettext` feature.
+///
+/// Call this in your main function with the path of the directory in which gettext
+/// should search for the translations of the strings marked with `@tr(...)` in the `.slint` files.
+/// The argument must be an expression that implements `Into<std::path::PathBuf>`.
+///
+/// The translations are expected to be found in `<dirname>/<locale>/LC_MESSAGES/<crate>.mo`,
+/// where `locale` is a locale name (e.g., `en`, `en_GB`, `fr`) and `crate` is the package name
+/// of your crate, as given by the `CARGO_PKG_NAME` environment variable.
+///
+/// ### Example
+/// ```rust
+/// fn main() {
+///    slint::init_translations!(concat!(env!("CARGO_MANIFEST_DIR"), "/translations/"));
+///    // ...
+/// }
+/// ```
+#[cfg(feature = "gettext")]
+#[macro_export]
+macro_rules! init_translations {
+    ($dirname:expr) => {
+        $crate::private_unstable_api::init_translations(env!("CARGO_PKG_NAME"), $dirname);
+    };
+}
+
+/// Re-translate all the strings marked with `@tr(...)` in the `.slint` files.
+///
+/// Call this function after changing the language at run-time (for example by changing the
+/// `LANGUAGE` environment variable when using gettext), so that the bindings that depend on
+/// translated strings are re-evaluated.
+pub fn update_all_translations() {
+    i_slint_core::translations::mark_all_translations_dirty()
+}
+
 /// This module contains items that you need to use or implement if you want use Slint in an environment without
 /// one of the supplied platform backends such as qt or winit.
 ///
@@ -100,6 +100,70 @@ pub fn set_animated_property_binding_for_transition<
     )
 }
 
+/// Install the `animation-started` and `animation-finished` handler on the animation
+/// controller of the property, if the property is currently animated.
+pub fn set_animation_event_handler<T: Clone + 'static, StrongRef: StrongComponentRef + 'static>(
+    property: Pin<&Property<T>>,
+    component_strong: &StrongRef,
+    handler: fn(StrongRef, AnimationEvent),
+) {
+    if let Some(controller) = property.animation_controller() {
+        let weak = component_strong.to_weak();
+        controller.set_event_handler(move |event| {
+            // The events are delivered on the next animation tick, the component might be gone by then
+            if let Some(strong) = <StrongRef as StrongComponentRef>::from_weak(&weak) {
+                handler(strong, event)
+            }
+        })
+    }
+}
+
+/// Initialize the change tracker of a `changed` handler: `handler` is called when
+/// the value returned by `eval` changes.
+pub fn init_change_tracker<
+    T: Default + PartialEq + Clone + 'static,
+    StrongRef: StrongComponentRef + 'static,
+>(
+    change_tracker: &ChangeTracker,
+    component_strong: &StrongRef,
+    eval: fn(StrongRef) -> T,
+    handler: fn(StrongRef),
+) {
+    change_tracker.init(
+        component_strong.to_weak(),
+        move |weak| {
+            <StrongRef as StrongComponentRef>::from_weak(weak).map_or_else(T::default, eval)
+        },
+        move |weak, _| {
+            if let Some(strong) = <StrongRef as StrongComponentRef>::from_weak(weak) {
+                handler(strong)
+            }
+        },
+    )
+}
+
+/// Install an observer that calls `callback` with the value returned by `eval` when it changes.
+/// The observer is kept in `observers`, and so lives as long as the component.
+pub fn observe_property<
+    T: Default + PartialEq + Clone + 'static,
+    StrongRef: StrongComponentRef + 'static,
+>(
+    observers: &core::cell::RefCell<alloc::vec::Vec<ChangeTracker>>,
+    component_strong: &StrongRef,
+    eval: fn(StrongRef) -> T,
+    callback: impl FnMut(T) + 'static,
+) {
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        (component_strong.to_weak(), core::cell::RefCell::new(callback)),
+        move |(weak, _)| {
+            <StrongRef as StrongComponentRef>::from_weak(weak).map_or_else(T::default, eval)
+        },
+        |(_, callback), value| (callback.borrow_mut())(value.clone()),
+    );
+    observers.borrow_mut().push(tracker);
+}
+
 pub fn set_property_state_binding<StrongRef: StrongComponentRef + 'static>(
     property: Pin<&Property<StateInfo>>,
     component_strong: &StrongRef,
@@ -138,6 +202,91 @@ pub fn debug(s: SharedString) {
     }
 }
 
+pub fn translate(
+    original: SharedString,
+    context: SharedString,
+    domain: SharedString,
+    arguments: Slice<SharedString>,
+    n: i32,
+    plural: SharedString,
+) -> SharedString {
+    i_slint_core::translations::translate(
+        &original,
+        &context,
+        &domain,
+        arguments.as_slice(),
+        n,
+        &plural,
+    )
+}
+
+/// Implementation of `string.split(separator)`. An empty separator doesn't split the string.
+pub fn string_split(s: SharedString, separator: SharedString) -> ModelRc<SharedString> {
+    let parts: Vec<SharedString> = if separator.is_empty() {
+        vec![s]
+    } else {
+        s.split(separator.as_str()).map(SharedString::from).collect()
+    };
+    ModelRc::new(VecModel::from(parts))
+}
+
+/// Iterate over the elements of the model and their index, registering the dependencies
+/// so that the binding calling this function is re-evaluated when the model changes.
+fn array_elements<T>(model: &ModelRc<T>) -> impl Iterator<Item = (T, i32)> + '_ {
+    model.model_tracker().track_row_count_changes();
+    (0..model.row_count()).filter_map(|row| Some((model.row_data_tracked(row)?, row as i32)))
+}
+
+/// Implementation of `array.filter(x => ...)` and `array.slice(start, end)`
+pub fn array_filter<T: Clone + 'static>(
+    model: ModelRc<T>,
+    predicate: impl Fn(T, i32) -> bool,
+) -> ModelRc<T> {
+    let result: Vec<T> = array_elements(&model)
+        .filter(|(element, index)| predicate(element.clone(), *index))
+        .map(|(element, _)| element)
+        .collect();
+    ModelRc::new(VecModel::from(result))
+}
+
+/// Implementation of `array.map(x => ...)`
+pub fn array_map<T, U: Clone + 'static>(
+    model: ModelRc<T>,
+    map: impl Fn(T, i32) -> U,
+) -> ModelRc<U> {
+    let result: Vec<U> =
+        array_elements(&model).map(|(element, index)| map(element, index)).collect();
+    ModelRc::new(VecModel::from(result))
+}
+
+/// Implementation of `array.any(x => ...)`
+pub fn array_any<T>(model: ModelRc<T>, predicate: impl Fn(T, i32) -> bool) -> bool {
+    array_elements(&model).any(|(element, index)| predicate(element, index))
+}
+
+/// Implementation of `array.all(x => ...)`
+pub fn array_all<T>(model: ModelRc<T>, predicate: impl Fn(T, i32) -> bool) -> bool {
+    array_elements(&model).all(|(element, index)| predicate(element, index))
+}
+
+/// Implementation of `array.sum()` and `array.sum(x => ...)`
+pub fn array_sum<T>(model: ModelRc<T>, value: impl Fn(T, i32) -> f64) -> f64 {
+    array_elements(&model).map(|(element, index)| value(element, index)).sum()
+}
+
+/// Implementation of `array.index-of(value)`
+pub fn array_index_of<T>(model: ModelRc<T>, predicate: impl Fn(T, i32) -> bool) -> i32 {
+    array_elements(&model)
+        .filter_map(|(element, index)| predicate(element, index).then_some(index))
+        .next()
+        .unwrap_or(-1)
+}
+
+#[cfg(feature = "gettext")]
+pub fn init_translations(domain: &str, dirname: impl Into<std::path::PathBuf>) {
+    i_slint_core::translations::gettext_bindtextdomain(domain, dirname.into()).unwrap()
+}
+
 /// Creates a new window to render components in.
 pub fn create_window_adapter(
 ) -> Result<alloc::rc::Rc<dyn i_slint_core::window::WindowAdapter>, crate::PlatformError> {
@@ -155,12 +304,18 @@ pub mod re_exports {
     pub use core::iter::FromIterator;
     pub use i_slint_backend_selector::native_widgets::*;
     pub use i_slint_core::accessibility::AccessibleStringProperty;
-    pub use i_slint_core::animations::{animation_tick, EasingCurve};
+    pub use i_slint_core::animations::{
+        animation_tick, AnimationController, AnimationEvent, EasingCurve, EasingKeyframe,
+    };
     pub use i_slint_core::callbacks::Callback;
+    pub use i_slint_core::clipboard::{
+        clipboard_image, clipboard_text, set_clipboard_image, set_clipboard_text,
+    };
     pub use i_slint_core::component::{
         register_component, unregister_component, Component, ComponentRefPin, ComponentVTable,
         ComponentWeak, IndexRange,
     };
+    pub use i_slint_core::date_time::DateTime;
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
         key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyboardModifiers,
@@ -174,8 +329,11 @@ pub mod re_exports {
     pub use i_slint_core::layout::*;
     pub use i_slint_core::lengths::LogicalLength;
     pub use i_slint_core::model::*;
-    pub use i_slint_core::properties::{set_state_binding, Property, PropertyTracker, StateInfo};
+    pub use i_slint_core::properties::{
+        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+    };
     pub use i_slint_core::slice::Slice;
+    pub use i_slint_core::string::format_number;
     pub use i_slint_core::window::{WindowAdapter, WindowInner};
     pub use i_slint_core::Color;
     pub use i_slint_core::ComponentVTable_static;
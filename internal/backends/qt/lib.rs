@@ -83,7 +83,10 @@ pub extern "C" fn slint_qt_get_widget(
     (qt_widgets::NativeComboBoxPopup,
     (qt_widgets::NativeTabWidget,
     (qt_widgets::NativeTab,
-            ())))))))))))));
+    (qt_widgets::NativeMenuItem,
+    (qt_widgets::NativeMenuFrame,
+    (qt_widgets::NativeMenuBarItem,
+            ()))))))))))))))));
 
 #[cfg(not(no_qt))]
 #[rustfmt::skip]
@@ -167,6 +170,57 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
         Err("Qt platform requested but Slint is compiled without Qt support".into())
     }
 
+    #[cfg(not(no_qt))]
+    fn process_events(
+        &self,
+        timeout: core::time::Duration,
+    ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+        // Schedule any timers with Qt that were set up since the last call.
+        crate::qt_window::timer_event();
+        let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+        use cpp::cpp;
+        let exit = cpp! {unsafe [timeout_ms as "int"] -> bool as "bool" {
+            ensure_initialized(true);
+            // Catch the quit event sent by quit_event_loop(), as there is no event loop to quit.
+            struct QuitEventFilter : QObject {
+                bool quit_requested = false;
+                bool eventFilter(QObject *, QEvent *event) override {
+                    if (event->type() == QEvent::Quit) {
+                        quit_requested = true;
+                        return true;
+                    }
+                    return false;
+                }
+            } quit_filter;
+            qApp->installEventFilter(&quit_filter);
+            // Wake up after the timeout if there is no other event.
+            QTimer timeout_timer;
+            timeout_timer.setSingleShot(true);
+            timeout_timer.start(timeout_ms);
+            QCoreApplication::processEvents(QEventLoop::WaitForMoreEvents);
+            QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+            qApp->removeEventFilter(&quit_filter);
+
+            if (quit_filter.quit_requested) {
+                return true;
+            }
+            if (!qApp->quitOnLastWindowClosed()) {
+                return false;
+            }
+            for (auto widget : QApplication::topLevelWidgets()) {
+                if (widget->isVisible() && !(widget->windowType() == Qt::Popup || widget->windowType() == Qt::ToolTip)) {
+                    return false;
+                }
+            }
+            return true;
+        }};
+        Ok(if exit {
+            core::ops::ControlFlow::Break(())
+        } else {
+            core::ops::ControlFlow::Continue(())
+        })
+    }
+
     #[cfg(not(no_qt))]
     fn new_event_loop_proxy(&self) -> Option<Box<dyn i_slint_core::platform::EventLoopProxy>> {
         struct Proxy;
@@ -265,6 +319,134 @@ fn clipboard_text(&self, _clipboard: i_slint_core::platform::Clipboard) -> Optio
         None
     }
 
+    #[cfg(not(no_qt))]
+    fn set_clipboard_content(
+        &self,
+        content: i_slint_core::platform::ClipboardContent,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        use cpp::cpp;
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return,
+        };
+        let mime_data = cpp! {unsafe [] -> *mut std::ffi::c_void as "QMimeData*" {
+            ensure_initialized();
+            return new QMimeData;
+        }};
+        if let Some(text) = content.text {
+            let text: qttypes::QString = text.as_str().into();
+            cpp! {unsafe [mime_data as "QMimeData*", text as "QString"] {
+                mime_data->setText(text);
+            }}
+        }
+        if let Some(html) = content.html {
+            let html: qttypes::QString = html.as_str().into();
+            cpp! {unsafe [mime_data as "QMimeData*", html as "QString"] {
+                mime_data->setHtml(html);
+            }}
+        }
+        if let Some(image) = content.image {
+            let (width, height) = (image.width(), image.height());
+            let data = image.as_bytes().as_ptr();
+            cpp! {unsafe [mime_data as "QMimeData*", width as "int", height as "int", data as "const uchar*"] {
+                // copy() so that the QImage doesn't reference the buffer after it's dropped
+                mime_data->setImageData(QImage(data, width, height, width * 4, QImage::Format_RGBA8888).copy());
+            }}
+        }
+        for (mime_type, data) in content.custom {
+            let mime_type: qttypes::QString = mime_type.as_str().into();
+            let data = qttypes::QByteArray::from(data.as_slice());
+            cpp! {unsafe [mime_data as "QMimeData*", mime_type as "QString", data as "QByteArray"] {
+                mime_data->setData(mime_type, data);
+            }}
+        }
+        cpp! {unsafe [mime_data as "QMimeData*", is_selection as "bool"] {
+            // The clipboard takes ownership of the mime data
+            QGuiApplication::clipboard()->setMimeData(mime_data, is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+        }}
+    }
+
+    #[cfg(not(no_qt))]
+    fn clipboard_content(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<i_slint_core::platform::ClipboardContent> {
+        use cpp::cpp;
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return None,
+        };
+        let mime_data = cpp! {unsafe [is_selection as "bool"] -> *const std::ffi::c_void as "const QMimeData*" {
+            ensure_initialized();
+            return QGuiApplication::clipboard()->mimeData(is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+        }};
+        if mime_data.is_null() {
+            return None;
+        }
+        let mut content = i_slint_core::platform::ClipboardContent::default();
+        let has_text = cpp! {unsafe [mime_data as "const QMimeData*"] -> bool as "bool" {
+            return mime_data->hasText();
+        }};
+        if has_text {
+            let text = cpp! {unsafe [mime_data as "const QMimeData*"] -> qttypes::QString as "QString" {
+                return mime_data->text();
+            }};
+            content.text = Some(String::from(text).into());
+        }
+        let has_html = cpp! {unsafe [mime_data as "const QMimeData*"] -> bool as "bool" {
+            return mime_data->hasHtml();
+        }};
+        if has_html {
+            let html = cpp! {unsafe [mime_data as "const QMimeData*"] -> qttypes::QString as "QString" {
+                return mime_data->html();
+            }};
+            content.html = Some(String::from(html).into());
+        }
+        let has_image = cpp! {unsafe [mime_data as "const QMimeData*"] -> bool as "bool" {
+            return mime_data->hasImage();
+        }};
+        if has_image {
+            let size = cpp! {unsafe [mime_data as "const QMimeData*"] -> qttypes::QSize as "QSize" {
+                return qvariant_cast<QImage>(mime_data->imageData()).size();
+            }};
+            let mut buffer = i_slint_core::graphics::SharedPixelBuffer::<
+                i_slint_core::graphics::Rgba8Pixel,
+            >::new(size.width, size.height);
+            let data = buffer.make_mut_bytes().as_mut_ptr();
+            cpp! {unsafe [mime_data as "const QMimeData*", data as "uchar*"] {
+                QImage image = qvariant_cast<QImage>(mime_data->imageData()).convertToFormat(QImage::Format_RGBA8888);
+                for (int y = 0; y < image.height(); ++y)
+                    memcpy(data + y * image.width() * 4, image.constScanLine(y), image.width() * 4);
+            }};
+            content.image = Some(buffer);
+        }
+        let format_count = cpp! {unsafe [mime_data as "const QMimeData*"] -> i32 as "int" {
+            return mime_data->formats().size();
+        }};
+        for index in 0..format_count {
+            let mime_type: String = cpp! {unsafe [mime_data as "const QMimeData*", index as "int"] -> qttypes::QString as "QString" {
+                return mime_data->formats().at(index);
+            }}
+            .into();
+            // The text, the HTML, and the image are already in the content, and Qt adds private formats
+            if mime_type.starts_with("text/plain")
+                || mime_type == "text/html"
+                || mime_type.starts_with("image/")
+                || mime_type.starts_with("application/x-qt")
+            {
+                continue;
+            }
+            let data = cpp! {unsafe [mime_data as "const QMimeData*", index as "int"] -> qttypes::QByteArray as "QByteArray" {
+                return mime_data->data(mime_data->formats().at(index));
+            }};
+            content.custom.push((mime_type.into(), data.to_slice().to_vec()));
+        }
+        Some(content)
+    }
+
     #[cfg(not(no_qt))]
     fn click_interval(&self) -> core::time::Duration {
         let duration_ms = unsafe {
@@ -209,3 +209,6 @@ pub fn draw(&self, callback: &mut dyn FnMut(u32, u32, &[u8])) {
 
 mod tableheadersection;
 pub use tableheadersection::*;
+
+mod menu;
+pub use menu::*;
@@ -269,6 +269,9 @@ fn input_event(
                 }
             }
             MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                return InputEventResult::EventIgnored
+            }
         });
         if let MouseEvent::Released { position, .. } = event {
             if LogicalRect::new(
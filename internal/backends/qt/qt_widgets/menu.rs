@@ -0,0 +1,426 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+use i_slint_core::input::FocusEventResult;
+
+use super::*;
+
+cpp! {{
+    void init_menu_item_option(QStyleOptionMenuItem *option, QString &&text, QString &&shortcut,
+                               bool is_separator, bool checkable, bool checked, bool has_submenu) {
+        option->menuHasCheckableItems = true;
+        option->maxIconWidth = 0;
+        if (is_separator) {
+            option->menuItemType = QStyleOptionMenuItem::Separator;
+        } else if (has_submenu) {
+            option->menuItemType = QStyleOptionMenuItem::SubMenu;
+        } else {
+            option->menuItemType = QStyleOptionMenuItem::Normal;
+        }
+        option->checkType = checkable ? QStyleOptionMenuItem::NonExclusive : QStyleOptionMenuItem::NotCheckable;
+        option->checked = checked;
+        option->text = std::move(text);
+        if (!shortcut.isEmpty()) {
+            option->text += QLatin1Char('\t') + shortcut;
+            option->reservedShortcutWidth = option->fontMetrics.horizontalAdvance(shortcut);
+        }
+    }
+}}
+
+/// An entry in a menu, drawn with `CE_MenuItem`
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuItem {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub shortcut: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub checkable: Property<bool>,
+    pub checked: Property<bool>,
+    pub has_hover: Property<bool>,
+    pub has_submenu: Property<bool>,
+    pub is_separator: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeMenuItem {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let text: qttypes::QString = self.text().as_str().into();
+        let shortcut: qttypes::QString = self.shortcut().as_str().into();
+        let is_separator = self.is_separator();
+        let checkable = self.checkable();
+        let has_submenu = self.has_submenu();
+
+        let s = cpp!(unsafe [
+            text as "QString",
+            shortcut as "QString",
+            is_separator as "bool",
+            checkable as "bool",
+            has_submenu as "bool"
+        ] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            init_menu_item_option(&option, std::move(text), std::move(shortcut), is_separator, checkable, false, has_submenu);
+            QSize contents = is_separator ? QSize(0, 0) : option.fontMetrics.size(Qt::TextShowMnemonic, option.text);
+            return qApp->style()->sizeFromContents(QStyle::CT_MenuItem, &option, contents, nullptr);
+        });
+        let min = match orientation {
+            Orientation::Horizontal => s.width,
+            Orientation::Vertical => s.height,
+        } as f32;
+        LayoutInfo {
+            min,
+            preferred: min,
+            max: match orientation {
+                Orientation::Horizontal => f32::MAX,
+                Orientation::Vertical => min,
+            },
+            ..LayoutInfo::default()
+        }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { this dpr size painter widget initial_state =>
+        let text: qttypes::QString = this.text().as_str().into();
+        let shortcut: qttypes::QString = this.shortcut().as_str().into();
+        let enabled = this.enabled();
+        let checkable = this.checkable();
+        let checked = this.checked();
+        let has_hover = this.has_hover();
+        let has_submenu = this.has_submenu();
+        let is_separator = this.is_separator();
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            text as "QString",
+            shortcut as "QString",
+            enabled as "bool",
+            checkable as "bool",
+            checked as "bool",
+            has_hover as "bool",
+            has_submenu as "bool",
+            is_separator as "bool",
+            initial_state as "int"
+        ] {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.menuRect = option.rect;
+            init_menu_item_option(&option, std::move(text), std::move(shortcut), is_separator, checkable, checked, has_submenu);
+            if (enabled) {
+                option.state |= QStyle::State_Enabled;
+                if (has_hover && !is_separator) {
+                    option.state |= QStyle::State_Selected;
+                }
+            } else {
+                option.palette.setCurrentColorGroup(QPalette::Disabled);
+            }
+            qApp->style()->drawControl(QStyle::CE_MenuItem, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuItemVTable() -> NativeMenuItemVTable for NativeMenuItem
+}
+
+/// The background and frame of the popup of a menu
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuFrame {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub native_padding: Property<LogicalLength>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeMenuFrame {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {
+        let padding = cpp!(unsafe [] -> f32 as "float" {
+            ensure_initialized();
+            QStyleOption option;
+            auto style = qApp->style();
+            return style->pixelMetric(QStyle::PM_MenuPanelWidth, &option, nullptr)
+                + style->pixelMetric(QStyle::PM_MenuVMargin, &option, nullptr);
+        });
+        self.native_padding.set(LogicalLength::new(padding));
+    }
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        Default::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { _this dpr size painter widget initial_state =>
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            initial_state as "int"
+        ] {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.state |= QStyle::State_Enabled;
+            auto style = qApp->style();
+            style->drawPrimitive(QStyle::PE_PanelMenu, &option, painter->get(), widget);
+            QStyleOptionFrame frame;
+            frame.rect = option.rect;
+            frame.palette = option.palette;
+            frame.state = QStyle::State_None;
+            frame.lineWidth = style->pixelMetric(QStyle::PM_MenuPanelWidth, &option, widget);
+            frame.midLineWidth = 0;
+            style->drawPrimitive(QStyle::PE_FrameMenu, &frame, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuFrame {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuFrameVTable() -> NativeMenuFrameVTable for NativeMenuFrame
+}
+
+/// An entry of a menu bar, drawn with `CE_MenuBarItem`
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuBarItem {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub has_hover: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeMenuBarItem {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let text: qttypes::QString = self.text().as_str().into();
+        let s = cpp!(unsafe [text as "QString"] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.menuItemType = QStyleOptionMenuItem::Normal;
+            option.text = std::move(text);
+            auto style = qApp->style();
+            QSize contents = option.fontMetrics.size(Qt::TextShowMnemonic, option.text);
+            int margin = 2 * style->pixelMetric(QStyle::PM_MenuBarItemSpacing, &option, nullptr);
+            contents += QSize(margin, margin);
+            return style->sizeFromContents(QStyle::CT_MenuBarItem, &option, contents, nullptr);
+        });
+        let min = match orientation {
+            Orientation::Horizontal => s.width,
+            Orientation::Vertical => s.height,
+        } as f32;
+        LayoutInfo { min, preferred: min, max: min, ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { this dpr size painter widget initial_state =>
+        let text: qttypes::QString = this.text().as_str().into();
+        let enabled = this.enabled();
+        let has_hover = this.has_hover();
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            text as "QString",
+            enabled as "bool",
+            has_hover as "bool",
+            initial_state as "int"
+        ] {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.menuRect = option.rect;
+            option.menuItemType = QStyleOptionMenuItem::Normal;
+            option.text = std::move(text);
+            if (enabled) {
+                option.state |= QStyle::State_Enabled;
+                if (has_hover) {
+                    option.state |= QStyle::State_Selected;
+                }
+            } else {
+                option.palette.setCurrentColorGroup(QPalette::Disabled);
+            }
+            auto style = qApp->style();
+            style->drawControl(QStyle::CE_MenuBarEmptyArea, &option, painter->get(), widget);
+            style->drawControl(QStyle::CE_MenuBarItem, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuBarItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuBarItemVTable() -> NativeMenuBarItemVTable for NativeMenuBarItem
+}
@@ -218,6 +218,9 @@ fn input_event(
                     // TODO
                     InputEventResult::EventAccepted
                 }
+                MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                    InputEventResult::EventIgnored
+                }
             };
             self.data.set(data);
             result
@@ -194,6 +194,7 @@ fn input_event(
                 debug_assert_ne!(button, PointerEventButton::Left);
                 InputEventResult::EventIgnored
             }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
         };
         data.active_controls = new_control;
 
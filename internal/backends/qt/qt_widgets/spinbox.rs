@@ -183,6 +183,7 @@ fn input_event(
                 }
                 MouseEvent::Moved { .. } => false,
                 MouseEvent::Wheel { .. } => false, // TODO
+                MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => false,
             };
         data.active_controls = new_control;
         if changed {
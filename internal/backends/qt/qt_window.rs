@@ -11,7 +11,7 @@
     RenderingMetrics, RenderingMetricsCollector,
 };
 use i_slint_core::graphics::{euclid, Brush, Color, FontRequest, Image, Point, SharedImageBuffer};
-use i_slint_core::input::{KeyEventType, KeyInputEvent, MouseEvent};
+use i_slint_core::input::MouseEvent;
 use i_slint_core::item_rendering::{ItemCache, ItemRenderer};
 use i_slint_core::items::{
     self, FillRule, ImageRendering, Item, ItemRc, ItemRef, Layer, MouseCursor, Opacity,
@@ -146,10 +146,9 @@ struct SlintWidget : QWidget {
                 rust_window.mouse_event(MouseEvent::Released{ position, button, click_count: 0 })
             });
             if (auto p = dynamic_cast<const SlintWidget*>(parent())) {
-                // FIXME: better way to close the popup
                 void *parent_window = p->rust_window;
-                rust!(Slint_mouseReleaseEventPopup [parent_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint"] {
-                    parent_window.close_popup();
+                rust!(Slint_mouseReleaseEventPopup [parent_window: &QtWindow as "void*", rust_window: &QtWindow as "void*"] {
+                    parent_window.close_popup_on_release(&rust_window.window);
                 });
             }
         }
@@ -206,7 +205,15 @@ struct SlintWidget : QWidget {
             if (event->type() == QEvent::ActivationChange) {
                 bool active = isActiveWindow();
                 rust!(Slint_updateWindowActivation [rust_window: &QtWindow as "void*", active: bool as "bool"] {
-                    WindowInner::from_pub(&rust_window.window).set_active(active)
+                    rust_window.window.dispatch_event(WindowEvent::WindowActiveChanged(active))
+                });
+            } else if (event->type() == QEvent::WindowStateChange) {
+                auto state = windowState();
+                bool minimized = state & Qt::WindowMinimized;
+                bool maximized = state & Qt::WindowMaximized;
+                bool full_screen = state & Qt::WindowFullScreen;
+                rust!(Slint_updateWindowState [rust_window: &QtWindow as "void*", minimized: bool as "bool", maximized: bool as "bool", full_screen: bool as "bool"] {
+                    rust_window.window.dispatch_event(WindowEvent::WindowStateChanged { minimized, maximized, full_screen })
                 });
             } else if (event->type() == QEvent::PaletteChange || event->type() == QEvent::StyleChange) {
                 bool dark_color_scheme = qApp->palette().color(QPalette::Window).valueF() < 0.5;
@@ -281,24 +288,16 @@ struct SlintWidget : QWidget {
             rust!(Slint_inputMethodEvent [rust_window: &QtWindow as "void*", commit_string: qttypes::QString as "QString",
                 preedit_string: qttypes::QString as "QString", replacement_start: i32 as "int", replacement_length: i32 as "int",
                 preedit_cursor: i32 as "int"] {
-                    let runtime_window = WindowInner::from_pub(&rust_window.window);
-
-                    let event = KeyInputEvent {
-                        event_type: KeyEventType::UpdateComposition,
+                    rust_window.window.dispatch_event(WindowEvent::Preedit {
                         text: preedit_string.to_string().into(),
-                        preedit_selection_start: replacement_start as usize,
-                        preedit_selection_end: replacement_start as usize + replacement_length as usize,
-                        ..Default::default()
-                    };
-                    runtime_window.process_key_input(event);
+                        selection_start: replacement_start as usize,
+                        selection_end: replacement_start as usize + replacement_length as usize,
+                    });
 
                     if !commit_string.is_empty() {
-                        let event = KeyInputEvent {
-                            event_type: KeyEventType::CommitComposition,
+                        rust_window.window.dispatch_event(WindowEvent::Commit {
                             text: commit_string.to_string().into(),
-                            ..Default::default()
-                        };
-                        runtime_window.process_key_input(event);
+                        });
                     }
                 });
         }
@@ -1467,7 +1466,10 @@ fn paint_event(&self, painter: QPainterPtr) {
     }
 
     fn resize_event(&self, size: qttypes::QSize) {
-        self.window.set_size(i_slint_core::api::PhysicalSize::new(size.width, size.height));
+        let size = i_slint_core::api::PhysicalSize::new(size.width, size.height);
+        self.window.dispatch_event(WindowEvent::Resized {
+            size: size.to_logical(self.window.scale_factor()),
+        });
     }
 
     fn mouse_event(&self, event: MouseEvent) {
@@ -1491,8 +1493,8 @@ fn key_event(&self, key: i32, text: qttypes::QString, released: bool) {
         timer_event();
     }
 
-    fn close_popup(&self) {
-        WindowInner::from_pub(&self.window).close_popup();
+    fn close_popup_on_release(&self, popup_window: &i_slint_core::api::Window) {
+        WindowInner::from_pub(&self.window).close_popup_on_release(popup_window);
     }
 }
 
@@ -1568,6 +1570,10 @@ fn apply_window_properties(&self, window_item: Pin<&items::WindowItem>) {
         let title: qttypes::QString = window_item.title().as_str().into();
         let no_frame = window_item.no_frame();
         let always_on_top = window_item.always_on_top();
+        let minimized = window_item.minimized();
+        let maximized = window_item.maximized();
+        let full_screen = window_item.full_screen();
+        let resizable = window_item.resizable();
         let mut size = qttypes::QSize {
             width: window_item.width().get().ceil() as _,
             height: window_item.height().get().ceil() as _,
@@ -1600,12 +1606,24 @@ fn apply_window_properties(&self, window_item: Pin<&items::WindowItem>) {
             }
         };
 
-        cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool", always_on_top as "bool"] {
+        cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool", always_on_top as "bool",
+                minimized as "bool", maximized as "bool", full_screen as "bool", resizable as "bool"] {
             if (size != widget_ptr->size()) {
                 widget_ptr->resize(size.expandedTo({1, 1}));
             }
+            if (!resizable) {
+                // Overrides the constraints set in apply_geometry_constraint, which is called before
+                widget_ptr->setFixedSize(size.expandedTo({1, 1}));
+            }
             widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
             widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
+            auto state = widget_ptr->windowState() & ~(Qt::WindowMinimized | Qt::WindowMaximized | Qt::WindowFullScreen);
+            state.setFlag(Qt::WindowMinimized, minimized);
+            state.setFlag(Qt::WindowMaximized, maximized);
+            state.setFlag(Qt::WindowFullScreen, full_screen);
+            if (state != widget_ptr->windowState()) {
+                widget_ptr->setWindowState(state);
+            }
             widget_ptr->setWindowTitle(title);
             auto pal = widget_ptr->palette();
 
@@ -6,6 +6,7 @@
 
 use i_slint_core::graphics::euclid::{Point2D, Size2D};
 use i_slint_core::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
+use i_slint_core::platform::ClipboardContent;
 use i_slint_core::renderer::Renderer;
 use i_slint_core::window::WindowAdapter;
 use i_slint_core::window::WindowAdapterSealed;
@@ -16,7 +17,7 @@
 
 #[derive(Default)]
 pub struct TestingBackend {
-    clipboard: Mutex<Option<String>>,
+    clipboard: Mutex<Option<ClipboardContent>>,
 }
 
 impl i_slint_core::platform::Platform for TestingBackend {
@@ -35,12 +36,27 @@ fn duration_since_start(&self) -> core::time::Duration {
     }
 
     fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
+        self.set_clipboard_content(ClipboardContent::from_text(text), clipboard)
+    }
+
+    fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        self.clipboard_content(clipboard)?.text.map(Into::into)
+    }
+
+    fn set_clipboard_content(
+        &self,
+        content: ClipboardContent,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
         if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
-            *self.clipboard.lock().unwrap() = Some(text.into());
+            *self.clipboard.lock().unwrap() = Some(content);
         }
     }
 
-    fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+    fn clipboard_content(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<ClipboardContent> {
         if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
             self.clipboard.lock().unwrap().clone()
         } else {
@@ -0,0 +1,420 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+#![doc = include_str!("README.md")]
+#![doc(html_logo_url = "https://slint-ui.com/logo/slint-logo-square-light.svg")]
+
+mod rfb;
+
+use i_slint_core::api::{EventLoopError, PhysicalPosition, PhysicalSize};
+use i_slint_core::graphics::Rgb8Pixel;
+use i_slint_core::platform::{
+    Clipboard, EventLoopProxy, PlatformError, PointerEventButton, WindowAdapter, WindowEvent,
+};
+use i_slint_core::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use std::cell::{Cell, RefCell};
+use std::io::BufReader;
+use std::net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::rc::{Rc, Weak};
+use std::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The amount of logical pixels to scroll for a step of the mouse wheel
+const SCROLL_STEP: f32 = 60.;
+
+/// How long the thread writing to a client may block on a message before the client is
+/// disconnected, for example when it stopped reading from the connection.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The number of messages queued for the thread writing to a client. The framebuffer updates are
+/// only sent when the client requests them, so a client with a full queue is disconnected.
+const MAX_QUEUED_MESSAGES: usize = 4;
+
+/// The events sent to the event loop by the threads of the clients, and by the [`EventLoopProxy`].
+enum Event {
+    Connected { id: usize, stream: TcpStream, writer: mpsc::SyncSender<Vec<u8>> },
+    Message { id: usize, message: rfb::ClientMessage },
+    Disconnected { id: usize },
+    Invoke(Box<dyn FnOnce() + Send>),
+    Quit,
+}
+
+struct Client {
+    id: usize,
+    /// Only used to close the connection: the messages are written by the thread of the `writer`.
+    stream: TcpStream,
+    writer: mpsc::SyncSender<Vec<u8>>,
+    pixel_format: rfb::PixelFormat,
+    /// The part of the framebuffer that changed since the last update sent to the client.
+    dirty_rect: rfb::Rect,
+    /// Whether the client is waiting for an update.
+    update_requested: bool,
+    button_mask: u8,
+}
+
+/// A platform that serves the first window with a VNC server. See the [crate documentation](crate).
+pub struct Backend {
+    size: PhysicalSize,
+    sender: mpsc::Sender<Event>,
+    receiver: mpsc::Receiver<Event>,
+    window: RefCell<Weak<MinimalSoftwareWindow>>,
+    framebuffer: RefCell<Vec<Rgb8Pixel>>,
+    clients: RefCell<Vec<Client>>,
+    clipboard: RefCell<String>,
+    quit_requested: Cell<bool>,
+}
+
+impl Backend {
+    /// Creates a backend with a framebuffer of the given `size`, and starts listening for VNC
+    /// clients on the `address`, such as `127.0.0.1:5900`.
+    pub fn new(address: impl ToSocketAddrs, size: PhysicalSize) -> Result<Self, PlatformError> {
+        if size.width == 0 || size.height == 0 || size.width > 8192 || size.height > 8192 {
+            return Err(
+                format!("Invalid VNC framebuffer size {}x{}", size.width, size.height).into()
+            );
+        }
+        let listener = TcpListener::bind(address)
+            .map_err(|err| format!("Could not start the VNC server: {err}"))?;
+        let (sender, receiver) = mpsc::channel();
+
+        let listener_sender = sender.clone();
+        std::thread::spawn(move || {
+            for (id, stream) in listener.incoming().enumerate() {
+                match stream {
+                    Ok(stream) => {
+                        let sender = listener_sender.clone();
+                        std::thread::spawn(move || serve_client(id, stream, size, sender));
+                    }
+                    Err(err) => i_slint_core::debug_log!("Error accepting a VNC client: {err}"),
+                }
+            }
+        });
+
+        Ok(Self {
+            size,
+            sender,
+            receiver,
+            window: Default::default(),
+            framebuffer: RefCell::new(vec![
+                Rgb8Pixel::default();
+                size.width as usize * size.height as usize
+            ]),
+            clients: Default::default(),
+            clipboard: Default::default(),
+            quit_requested: Default::default(),
+        })
+    }
+
+    fn handle_event(&self, event: Event) {
+        match event {
+            Event::Connected { id, stream, writer } => {
+                let client = Client {
+                    id,
+                    stream,
+                    writer,
+                    pixel_format: Default::default(),
+                    dirty_rect: Default::default(),
+                    update_requested: false,
+                    button_mask: 0,
+                };
+                let clipboard = self.clipboard.borrow();
+                if !clipboard.is_empty() {
+                    let mut message = Vec::new();
+                    rfb::write_server_cut_text(&mut message, &clipboard);
+                    if !client.send(message) {
+                        return;
+                    }
+                }
+                self.clients.borrow_mut().push(client);
+            }
+            Event::Message { id, message } => self.handle_message(id, message),
+            Event::Disconnected { id } => {
+                self.clients.borrow_mut().retain(|client| client.id != id)
+            }
+            Event::Invoke(f) => f(),
+            Event::Quit => self.quit_requested.set(true),
+        }
+    }
+
+    fn handle_message(&self, id: usize, message: rfb::ClientMessage) {
+        let window = self.window.borrow().upgrade();
+        let mut clients = self.clients.borrow_mut();
+        let client = match clients.iter_mut().find(|client| client.id == id) {
+            Some(client) => client,
+            None => return,
+        };
+        match message {
+            rfb::ClientMessage::SetPixelFormat(pixel_format) => client.pixel_format = pixel_format,
+            rfb::ClientMessage::FramebufferUpdateRequest { incremental, rect } => {
+                if !incremental {
+                    let rect = rect.clamped(self.size.width as u16, self.size.height as u16);
+                    client.dirty_rect = client.dirty_rect.union(&rect);
+                }
+                client.update_requested = true;
+            }
+            rfb::ClientMessage::KeyEvent { down, keysym } => {
+                if let (Some(window), Some(text)) = (window, rfb::keysym_to_text(keysym)) {
+                    drop(clients);
+                    window.dispatch_event(if down {
+                        WindowEvent::KeyPressed { text }
+                    } else {
+                        WindowEvent::KeyReleased { text }
+                    });
+                }
+            }
+            rfb::ClientMessage::PointerEvent { button_mask, x, y } => {
+                let previous_mask = std::mem::replace(&mut client.button_mask, button_mask);
+                drop(clients);
+                if let Some(window) = window {
+                    dispatch_pointer_event(&window, previous_mask, button_mask, x, y);
+                }
+            }
+            rfb::ClientMessage::ClientCutText(text) => *self.clipboard.borrow_mut() = text,
+        }
+    }
+
+    fn send_clipboard(&self, text: &str) {
+        let mut message = Vec::new();
+        rfb::write_server_cut_text(&mut message, text);
+        self.clients.borrow_mut().retain(|client| client.send(message.clone()));
+    }
+
+    /// Renders the window if needed, and sends the changes to the clients waiting for them.
+    fn render(&self) {
+        let window = match self.window.borrow().upgrade() {
+            Some(window) => window,
+            None => return,
+        };
+        let mut framebuffer = self.framebuffer.borrow_mut();
+        let mut rendered_rect = None;
+        window.draw_if_needed(|renderer| {
+            let region = renderer.render(framebuffer.as_mut_slice(), self.size.width as usize);
+            let (origin, size) = (region.bounding_box_origin(), region.bounding_box_size());
+            rendered_rect = Some(rfb::Rect {
+                x: origin.x as u16,
+                y: origin.y as u16,
+                width: size.width as u16,
+                height: size.height as u16,
+            });
+        });
+
+        // Borrowed after drawing, as the bindings evaluated while drawing may set the clipboard,
+        // which is sent to the clients
+        let mut clients = self.clients.borrow_mut();
+        clients.retain_mut(|client| {
+            if let Some(rect) = &rendered_rect {
+                client.dirty_rect = client.dirty_rect.union(rect);
+            }
+            if !client.update_requested || client.dirty_rect.is_empty() {
+                return true;
+            }
+            let rect = client.dirty_rect;
+            let mut message = Vec::new();
+            rfb::write_framebuffer_update(
+                &mut message,
+                &client.pixel_format,
+                &framebuffer,
+                self.size.width as usize,
+                rect,
+            );
+            client.dirty_rect = Default::default();
+            client.update_requested = false;
+            client.send(message)
+        });
+    }
+}
+
+impl Client {
+    /// Queues the message for the thread writing to the client, without blocking. Returns false
+    /// if the client was disconnected, because writing failed or because its queue is full.
+    fn send(&self, message: Vec<u8>) -> bool {
+        match self.writer.try_send(message) {
+            Ok(()) => true,
+            Err(mpsc::TrySendError::Full(_)) => {
+                i_slint_core::debug_log!(
+                    "Disconnecting the VNC client {}: it doesn't read the messages",
+                    self.id
+                );
+                // The threads reading from and writing to the client then stop.
+                self.stream.shutdown(Shutdown::Both).ok();
+                false
+            }
+            Err(mpsc::TrySendError::Disconnected(_)) => false,
+        }
+    }
+}
+
+impl i_slint_core::platform::Platform for Backend {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        let window = MinimalSoftwareWindow::new(RepaintBufferType::ReusedBuffer);
+        window.set_size(self.size);
+        let mut served_window = self.window.borrow_mut();
+        if served_window.upgrade().is_none() {
+            *served_window = Rc::downgrade(&window);
+        }
+        Ok(window)
+    }
+
+    fn run_event_loop(&self) -> Result<(), PlatformError> {
+        while self.process_events(Duration::from_secs(60 * 60))?.is_continue() {}
+        Ok(())
+    }
+
+    /// Returns `Break` only when `quit_event_loop()` was called, as the window is never closed.
+    fn process_events(
+        &self,
+        timeout: Duration,
+    ) -> Result<std::ops::ControlFlow<()>, PlatformError> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut wait = true;
+        loop {
+            i_slint_core::platform::update_timers_and_animations();
+            self.render();
+            if self.quit_requested.take() {
+                return Ok(std::ops::ControlFlow::Break(()));
+            }
+
+            // Wait for a first event, and then process the pending ones.
+            let event = if wait {
+                wait = false;
+                let mut wait_duration = deadline
+                    .map_or(timeout, |deadline| deadline.saturating_duration_since(Instant::now()));
+                if let Some(next_timer) = i_slint_core::platform::duration_until_next_timer_update()
+                {
+                    wait_duration = wait_duration.min(next_timer);
+                }
+                if self.window.borrow().upgrade().map_or(false, |w| w.has_active_animations()) {
+                    wait_duration = wait_duration.min(Duration::from_millis(16));
+                }
+                self.receiver.recv_timeout(wait_duration).ok()
+            } else {
+                self.receiver.try_recv().ok()
+            };
+            match event {
+                Some(event) => self.handle_event(event),
+                None => return Ok(std::ops::ControlFlow::Continue(())),
+            }
+        }
+    }
+
+    fn new_event_loop_proxy(&self) -> Option<Box<dyn EventLoopProxy>> {
+        struct Proxy(Mutex<mpsc::Sender<Event>>);
+        impl EventLoopProxy for Proxy {
+            fn quit_event_loop(&self) -> Result<(), EventLoopError> {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .send(Event::Quit)
+                    .map_err(|_| EventLoopError::EventLoopTerminated)
+            }
+
+            fn invoke_from_event_loop(
+                &self,
+                event: Box<dyn FnOnce() + Send>,
+            ) -> Result<(), EventLoopError> {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .send(Event::Invoke(event))
+                    .map_err(|_| EventLoopError::EventLoopTerminated)
+            }
+        }
+        Some(Box::new(Proxy(Mutex::new(self.sender.clone()))))
+    }
+
+    fn set_clipboard_text(&self, text: &str, clipboard: Clipboard) {
+        if clipboard == Clipboard::DefaultClipboard {
+            *self.clipboard.borrow_mut() = text.into();
+            self.send_clipboard(text);
+        }
+    }
+
+    fn clipboard_text(&self, clipboard: Clipboard) -> Option<String> {
+        let text = self.clipboard.borrow();
+        (clipboard == Clipboard::DefaultClipboard && !text.is_empty()).then(|| text.clone())
+    }
+}
+
+/// Performs the handshake with a new client, and forwards its messages to the event loop
+/// until it disconnects.
+fn serve_client(id: usize, mut stream: TcpStream, size: PhysicalSize, sender: mpsc::Sender<Event>) {
+    stream.set_nodelay(true).ok();
+    let (client_stream, mut write_stream) =
+        match rfb::handshake(&mut stream, size.width as u16, size.height as u16, "Slint")
+            .and_then(|_| stream.set_write_timeout(Some(WRITE_TIMEOUT)))
+            .and_then(|_| Ok((stream.try_clone()?, stream.try_clone()?)))
+        {
+            Ok(streams) => streams,
+            Err(err) => {
+                i_slint_core::debug_log!("Error connecting a VNC client: {err}");
+                return;
+            }
+        };
+
+    // The messages are written in another thread, so that a slow client doesn't block the event loop.
+    // The thread stops when the client is removed from the backend, which drops the `writer`.
+    let (writer, messages) = mpsc::sync_channel::<Vec<u8>>(MAX_QUEUED_MESSAGES);
+    std::thread::spawn(move || {
+        for message in messages {
+            if let Err(err) = rfb::send(&mut write_stream, &message) {
+                i_slint_core::debug_log!("Disconnecting the VNC client {id}: {err}");
+                // The thread reading from the client then stops.
+                write_stream.shutdown(Shutdown::Both).ok();
+                break;
+            }
+        }
+    });
+
+    if sender.send(Event::Connected { id, stream: client_stream, writer }).is_err() {
+        return;
+    }
+    let mut reader = BufReader::new(stream);
+    loop {
+        match rfb::read_client_message(&mut reader) {
+            Ok(Some(message)) => {
+                if sender.send(Event::Message { id, message }).is_err() {
+                    return;
+                }
+            }
+            Ok(None) => {}
+            Err(_) => break,
+        }
+    }
+    sender.send(Event::Disconnected { id }).ok();
+}
+
+/// Dispatches the events for a PointerEvent message, given the mask of the pressed buttons.
+fn dispatch_pointer_event(
+    window: &MinimalSoftwareWindow,
+    previous_mask: u8,
+    button_mask: u8,
+    x: u16,
+    y: u16,
+) {
+    let position = PhysicalPosition::new(x as i32, y as i32).to_logical(window.scale_factor());
+    window.dispatch_event(WindowEvent::PointerMoved { position });
+
+    let pressed = button_mask & !previous_mask;
+    let released = previous_mask & !button_mask;
+    for (bit, button) in [
+        (1, PointerEventButton::Left),
+        (2, PointerEventButton::Middle),
+        (4, PointerEventButton::Right),
+    ] {
+        if pressed & bit != 0 {
+            window.dispatch_event(WindowEvent::PointerPressed { position, button });
+        } else if released & bit != 0 {
+            window.dispatch_event(WindowEvent::PointerReleased { position, button });
+        }
+    }
+    // The buttons 4 to 7 are the mouse wheel up, down, left, and right
+    for (bit, delta_x, delta_y) in [(8, 0., 1.), (16, 0., -1.), (32, 1., 0.), (64, -1., 0.)] {
+        if pressed & bit != 0 {
+            window.dispatch_event(WindowEvent::PointerScrolled {
+                position,
+                delta_x: delta_x * SCROLL_STEP,
+                delta_y: delta_y * SCROLL_STEP,
+            });
+        }
+    }
+}
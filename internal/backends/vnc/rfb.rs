@@ -0,0 +1,488 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+The server side of the RFB protocol used by VNC, as described in
+<https://github.com/rfbproto/rfbproto/blob/master/rfbproto.rst>.
+
+Only the security type "None" and the "Raw" encoding are supported.
+*/
+
+use i_slint_core::graphics::Rgb8Pixel;
+use i_slint_core::platform::Key;
+use i_slint_core::SharedString;
+use std::io::{self, Read, Write};
+
+/// The maximum length of the text of a ClientCutText message that is kept. The rest is skipped.
+const MAX_CUT_TEXT_LENGTH: u32 = 1024 * 1024;
+
+/// The format of the pixels sent to a client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PixelFormat {
+    bits_per_pixel: u8,
+    depth: u8,
+    big_endian: bool,
+    true_color: bool,
+    red_max: u16,
+    green_max: u16,
+    blue_max: u16,
+    red_shift: u8,
+    green_shift: u8,
+    blue_shift: u8,
+}
+
+impl Default for PixelFormat {
+    /// 32 bits per pixel, with 8 bits per color component
+    fn default() -> Self {
+        Self {
+            bits_per_pixel: 32,
+            depth: 24,
+            big_endian: false,
+            true_color: true,
+            red_max: 255,
+            green_max: 255,
+            blue_max: 255,
+            red_shift: 16,
+            green_shift: 8,
+            blue_shift: 0,
+        }
+    }
+}
+
+impl PixelFormat {
+    fn from_bytes(bytes: &[u8; 16]) -> Self {
+        Self {
+            bits_per_pixel: bytes[0],
+            depth: bytes[1],
+            big_endian: bytes[2] != 0,
+            true_color: bytes[3] != 0,
+            red_max: u16::from_be_bytes([bytes[4], bytes[5]]),
+            green_max: u16::from_be_bytes([bytes[6], bytes[7]]),
+            blue_max: u16::from_be_bytes([bytes[8], bytes[9]]),
+            red_shift: bytes[10],
+            green_shift: bytes[11],
+            blue_shift: bytes[12],
+        }
+    }
+
+    fn to_bytes(self) -> [u8; 16] {
+        let [red_max_0, red_max_1] = self.red_max.to_be_bytes();
+        let [green_max_0, green_max_1] = self.green_max.to_be_bytes();
+        let [blue_max_0, blue_max_1] = self.blue_max.to_be_bytes();
+        [
+            self.bits_per_pixel,
+            self.depth,
+            self.big_endian as u8,
+            self.true_color as u8,
+            red_max_0,
+            red_max_1,
+            green_max_0,
+            green_max_1,
+            blue_max_0,
+            blue_max_1,
+            self.red_shift,
+            self.green_shift,
+            self.blue_shift,
+            0,
+            0,
+            0,
+        ]
+    }
+
+    /// Appends the pixel, in this format, to `out`.
+    /// Color maps aren't supported, so the pixel is always encoded as true color.
+    pub fn encode(&self, pixel: Rgb8Pixel, out: &mut Vec<u8>) {
+        let component = |value: u8, max: u16, shift: u8| {
+            (value as u32 * max as u32 / 255).checked_shl(shift as u32).unwrap_or_default()
+        };
+        let value = component(pixel.r, self.red_max, self.red_shift)
+            | component(pixel.g, self.green_max, self.green_shift)
+            | component(pixel.b, self.blue_max, self.blue_shift);
+        let bytes_per_pixel = (self.bits_per_pixel as usize / 8).clamp(1, 4);
+        if self.big_endian {
+            out.extend_from_slice(&value.to_be_bytes()[4 - bytes_per_pixel..]);
+        } else {
+            out.extend_from_slice(&value.to_le_bytes()[..bytes_per_pixel]);
+        }
+    }
+}
+
+/// A rectangle of the framebuffer, in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns the part of the rectangle that is within a framebuffer of the given size.
+    pub fn clamped(&self, width: u16, height: u16) -> Rect {
+        let x = self.x.min(width);
+        let y = self.y.min(height);
+        Rect { x, y, width: self.width.min(width - x), height: self.height.min(height - y) }
+    }
+
+    /// Returns the bounding box of both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            return *other;
+        } else if other.is_empty() {
+            return *self;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Rect { x, y, width: right - x, height: bottom - y }
+    }
+}
+
+/// The messages sent by the client to the server.
+#[derive(Debug, PartialEq)]
+pub enum ClientMessage {
+    SetPixelFormat(PixelFormat),
+    FramebufferUpdateRequest { incremental: bool, rect: Rect },
+    KeyEvent { down: bool, keysym: u32 },
+    PointerEvent { button_mask: u8, x: u16, y: u16 },
+    ClientCutText(String),
+}
+
+fn read_u8(stream: &mut impl Read) -> io::Result<u8> {
+    let mut bytes = [0; 1];
+    stream.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+fn read_u16(stream: &mut impl Read) -> io::Result<u16> {
+    let mut bytes = [0; 2];
+    stream.read_exact(&mut bytes)?;
+    Ok(u16::from_be_bytes(bytes))
+}
+
+fn read_u32(stream: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    stream.read_exact(&mut bytes)?;
+    Ok(u32::from_be_bytes(bytes))
+}
+
+fn skip(stream: &mut impl Read, count: u64) -> io::Result<()> {
+    io::copy(&mut stream.take(count), &mut io::sink())?;
+    Ok(())
+}
+
+fn protocol_error(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Performs the handshake with a new client, up to the ServerInit message,
+/// which announces the size of the framebuffer and the default [`PixelFormat`].
+pub fn handshake(
+    stream: &mut (impl Read + Write),
+    width: u16,
+    height: u16,
+    name: &str,
+) -> io::Result<()> {
+    stream.write_all(b"RFB 003.008\n")?;
+    stream.flush()?;
+    let mut version = [0; 12];
+    stream.read_exact(&mut version)?;
+    let minor_version = match &version {
+        [b'R', b'F', b'B', b' ', b'0', b'0', b'3', b'.', minor @ .., b'\n'] => {
+            std::str::from_utf8(minor).ok().and_then(|minor| minor.parse::<u32>().ok())
+        }
+        _ => None,
+    }
+    .ok_or_else(|| protocol_error("Invalid RFB protocol version"))?;
+
+    const SECURITY_TYPE_NONE: u8 = 1;
+    if minor_version >= 7 {
+        stream.write_all(&[1, SECURITY_TYPE_NONE])?;
+        stream.flush()?;
+        if read_u8(stream)? != SECURITY_TYPE_NONE {
+            return Err(protocol_error("Unsupported security type"));
+        }
+        if minor_version >= 8 {
+            // SecurityResult: OK
+            stream.write_all(&0u32.to_be_bytes())?;
+        }
+    } else {
+        stream.write_all(&(SECURITY_TYPE_NONE as u32).to_be_bytes())?;
+    }
+    stream.flush()?;
+
+    // ClientInit, with the shared flag that is ignored as all clients share the framebuffer
+    read_u8(stream)?;
+
+    let mut server_init = Vec::with_capacity(24 + name.len());
+    server_init.extend_from_slice(&width.to_be_bytes());
+    server_init.extend_from_slice(&height.to_be_bytes());
+    server_init.extend_from_slice(&PixelFormat::default().to_bytes());
+    server_init.extend_from_slice(&(name.len() as u32).to_be_bytes());
+    server_init.extend_from_slice(name.as_bytes());
+    stream.write_all(&server_init)?;
+    stream.flush()
+}
+
+/// Reads the next message of the client. Returns `None` for the messages that are ignored.
+pub fn read_client_message(stream: &mut impl Read) -> io::Result<Option<ClientMessage>> {
+    Ok(match read_u8(stream)? {
+        0 => {
+            skip(stream, 3)?;
+            let mut format = [0; 16];
+            stream.read_exact(&mut format)?;
+            Some(ClientMessage::SetPixelFormat(PixelFormat::from_bytes(&format)))
+        }
+        2 => {
+            // SetEncodings: only the raw encoding is used, which all clients support
+            skip(stream, 1)?;
+            let count = read_u16(stream)?;
+            skip(stream, count as u64 * 4)?;
+            None
+        }
+        3 => {
+            let incremental = read_u8(stream)? != 0;
+            let rect = Rect {
+                x: read_u16(stream)?,
+                y: read_u16(stream)?,
+                width: read_u16(stream)?,
+                height: read_u16(stream)?,
+            };
+            Some(ClientMessage::FramebufferUpdateRequest { incremental, rect })
+        }
+        4 => {
+            let down = read_u8(stream)? != 0;
+            skip(stream, 2)?;
+            Some(ClientMessage::KeyEvent { down, keysym: read_u32(stream)? })
+        }
+        5 => {
+            let button_mask = read_u8(stream)?;
+            let x = read_u16(stream)?;
+            let y = read_u16(stream)?;
+            Some(ClientMessage::PointerEvent { button_mask, x, y })
+        }
+        6 => {
+            skip(stream, 3)?;
+            let length = read_u32(stream)?;
+            let kept_length = length.min(MAX_CUT_TEXT_LENGTH);
+            let mut text = Vec::new();
+            stream.take(kept_length as u64).read_to_end(&mut text)?;
+            if text.len() != kept_length as usize {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            skip(stream, (length - kept_length) as u64)?;
+            // The text is encoded in ISO 8859-1
+            Some(ClientMessage::ClientCutText(text.into_iter().map(char::from).collect()))
+        }
+        _ => return Err(protocol_error("Unknown client message")),
+    })
+}
+
+/// Appends a FramebufferUpdate message with the `rect` of the framebuffer to `out`.
+pub fn write_framebuffer_update(
+    out: &mut Vec<u8>,
+    format: &PixelFormat,
+    framebuffer: &[Rgb8Pixel],
+    stride: usize,
+    rect: Rect,
+) {
+    const RAW_ENCODING: i32 = 0;
+    out.extend_from_slice(&[0, 0]);
+    out.extend_from_slice(&1u16.to_be_bytes());
+    for value in [rect.x, rect.y, rect.width, rect.height] {
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+    out.extend_from_slice(&RAW_ENCODING.to_be_bytes());
+    for y in rect.y as usize..(rect.y + rect.height) as usize {
+        let line = &framebuffer[y * stride..][rect.x as usize..(rect.x + rect.width) as usize];
+        for pixel in line {
+            format.encode(*pixel, out);
+        }
+    }
+}
+
+/// Appends a ServerCutText message with the `text` to `out`.
+pub fn write_server_cut_text(out: &mut Vec<u8>, text: &str) {
+    // The text is encoded in ISO 8859-1
+    let text: Vec<u8> =
+        text.chars().map(|c| if (c as u32) < 256 { c as u8 } else { b'?' }).collect();
+    out.extend_from_slice(&[3, 0, 0, 0]);
+    out.extend_from_slice(&(text.len() as u32).to_be_bytes());
+    out.extend_from_slice(&text);
+}
+
+/// Writes the whole message to the stream.
+pub fn send(stream: &mut impl Write, message: &[u8]) -> io::Result<()> {
+    stream.write_all(message)?;
+    stream.flush()
+}
+
+/// Returns the text of the [`WindowEvent::KeyPressed`](i_slint_core::platform::WindowEvent::KeyPressed)
+/// event for the X11 keysym sent in a KeyEvent message.
+pub fn keysym_to_text(keysym: u32) -> Option<SharedString> {
+    let key = match keysym {
+        // Latin-1 characters map to their code point
+        0x20..=0x7e | 0xa0..=0xff => return char::from_u32(keysym).map(Into::into),
+        // Unicode characters
+        0x0100_0000..=0x0110_ffff => return char::from_u32(keysym - 0x0100_0000).map(Into::into),
+        0xff08 => Key::Backspace,
+        0xff09 => Key::Tab,
+        0xfe20 => Key::Backtab,
+        0xff0d | 0xff8d => Key::Return,
+        0xff1b => Key::Escape,
+        0xffff | 0xff9f => Key::Delete,
+        0xff50 | 0xff95 => Key::Home,
+        0xff51 | 0xff96 => Key::LeftArrow,
+        0xff52 | 0xff97 => Key::UpArrow,
+        0xff53 | 0xff98 => Key::RightArrow,
+        0xff54 | 0xff99 => Key::DownArrow,
+        0xff55 | 0xff9a => Key::PageUp,
+        0xff56 | 0xff9b => Key::PageDown,
+        0xff57 | 0xff9c => Key::End,
+        0xff63 | 0xff9e => Key::Insert,
+        0xffbe..=0xffd5 => {
+            const F_KEYS: [Key; 24] = [
+                Key::F1,
+                Key::F2,
+                Key::F3,
+                Key::F4,
+                Key::F5,
+                Key::F6,
+                Key::F7,
+                Key::F8,
+                Key::F9,
+                Key::F10,
+                Key::F11,
+                Key::F12,
+                Key::F13,
+                Key::F14,
+                Key::F15,
+                Key::F16,
+                Key::F17,
+                Key::F18,
+                Key::F19,
+                Key::F20,
+                Key::F21,
+                Key::F22,
+                Key::F23,
+                Key::F24,
+            ];
+            F_KEYS[(keysym - 0xffbe) as usize]
+        }
+        0xffe1 => Key::Shift,
+        0xffe2 => Key::ShiftR,
+        0xffe3 => Key::Control,
+        0xffe4 => Key::ControlR,
+        0xffe5 => Key::CapsLock,
+        0xffe7 | 0xffeb => Key::Meta,
+        0xffe8 | 0xffec => Key::MetaR,
+        0xffe9 => Key::Alt,
+        0xffea | 0xfe03 => Key::AltGr,
+        // The keypad digits and operators
+        0xffaa..=0xffb9 => return char::from_u32(keysym - 0xff80).map(Into::into),
+        _ => return None,
+    };
+    Some(key.into())
+}
+
+#[test]
+fn handshake_and_messages() {
+    let mut client_messages = b"RFB 003.008\n".to_vec();
+    // Security type None, and the shared ClientInit
+    client_messages.extend_from_slice(&[1, 1]);
+    // FramebufferUpdateRequest
+    client_messages.extend_from_slice(&[3, 1, 0, 1, 0, 2, 0, 3, 0, 4]);
+    // SetEncodings, with raw and DesktopSize
+    client_messages.extend_from_slice(&[2, 0, 0, 2, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0x21]);
+    // KeyEvent of the 'a' key
+    client_messages.extend_from_slice(&[4, 1, 0, 0, 0, 0, 0, 0x61]);
+    // PointerEvent
+    client_messages.extend_from_slice(&[5, 1, 0, 10, 0, 20]);
+    // ClientCutText, longer than what is kept
+    client_messages.extend_from_slice(&[6, 0, 0, 0]);
+    client_messages.extend_from_slice(&(MAX_CUT_TEXT_LENGTH + 2).to_be_bytes());
+    client_messages.extend(std::iter::repeat(b'x').take(MAX_CUT_TEXT_LENGTH as usize + 2));
+
+    let mut stream = io::Cursor::new(client_messages);
+    let mut server_messages = Vec::new();
+    struct Stream<'a>(&'a mut io::Cursor<Vec<u8>>, &'a mut Vec<u8>);
+    impl Read for Stream<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.0.read(buf)
+        }
+    }
+    impl Write for Stream<'_> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.1.write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+    handshake(&mut Stream(&mut stream, &mut server_messages), 640, 480, "Slint").unwrap();
+
+    let mut expected = b"RFB 003.008\n".to_vec();
+    expected.extend_from_slice(&[1, 1, 0, 0, 0, 0, 2, 128, 1, 224]);
+    expected.extend_from_slice(&PixelFormat::default().to_bytes());
+    expected.extend_from_slice(&[0, 0, 0, 5]);
+    expected.extend_from_slice(b"Slint");
+    assert_eq!(server_messages, expected);
+
+    assert_eq!(
+        read_client_message(&mut stream).unwrap(),
+        Some(ClientMessage::FramebufferUpdateRequest {
+            incremental: true,
+            rect: Rect { x: 1, y: 2, width: 3, height: 4 }
+        })
+    );
+    assert_eq!(read_client_message(&mut stream).unwrap(), None);
+    assert_eq!(
+        read_client_message(&mut stream).unwrap(),
+        Some(ClientMessage::KeyEvent { down: true, keysym: 0x61 })
+    );
+    assert_eq!(
+        read_client_message(&mut stream).unwrap(),
+        Some(ClientMessage::PointerEvent { button_mask: 1, x: 10, y: 20 })
+    );
+    match read_client_message(&mut stream).unwrap() {
+        Some(ClientMessage::ClientCutText(text)) => {
+            assert_eq!(text.len(), MAX_CUT_TEXT_LENGTH as usize)
+        }
+        message => panic!("Unexpected message {message:?}"),
+    }
+    assert!(read_client_message(&mut stream).is_err());
+
+    assert_eq!(keysym_to_text(0x61).unwrap(), "a");
+    assert_eq!(keysym_to_text(0xff0d).unwrap(), SharedString::from(Key::Return));
+    assert_eq!(keysym_to_text(0xffc9).unwrap(), SharedString::from(Key::F12));
+}
+
+#[test]
+fn encode_pixels() {
+    let pixel = Rgb8Pixel::new(0xff, 0x80, 0x00);
+    let mut out = Vec::new();
+    PixelFormat::default().encode(pixel, &mut out);
+    assert_eq!(out, [0x00, 0x80, 0xff, 0x00]);
+
+    // RGB565 in big endian
+    let rgb565 = PixelFormat {
+        bits_per_pixel: 16,
+        depth: 16,
+        big_endian: true,
+        true_color: true,
+        red_max: 31,
+        green_max: 63,
+        blue_max: 31,
+        red_shift: 11,
+        green_shift: 5,
+        blue_shift: 0,
+    };
+    out.clear();
+    rgb565.encode(pixel, &mut out);
+    assert_eq!(out, (31u16 << 11 | 31 << 5).to_be_bytes());
+}
@@ -7,5 +7,6 @@ fn main() {
     // Setup cfg aliases
     cfg_aliases! {
        enable_skia_renderer: { any(feature = "renderer-winit-skia", feature = "renderer-winit-skia-opengl", feature = "renderer-winit-skia-vulkan")},
+       rich_clipboard: { not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")) },
     }
 }
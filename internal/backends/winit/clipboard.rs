@@ -0,0 +1,85 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! Images and HTML in the system clipboard, using arboard.
+//!
+//! The system clipboard only holds one of the text, the HTML (with the text as fallback), or the
+//! image. The other formats, and the custom formats, are only kept in-process, as long as the
+//! clipboard still contains what the application put in it.
+
+use i_slint_core::graphics::{Rgba8Pixel, SharedPixelBuffer};
+use i_slint_core::platform::ClipboardContent;
+use std::cell::RefCell;
+
+thread_local! {
+    static CLIPBOARD: RefCell<Option<arboard::Clipboard>> = RefCell::new(None);
+    /// The last content set by the application.
+    static LAST_CONTENT: RefCell<Option<ClipboardContent>> = RefCell::new(None);
+}
+
+fn with_clipboard<R>(f: impl FnOnce(&mut arboard::Clipboard) -> Option<R>) -> Option<R> {
+    CLIPBOARD.with(|clipboard| {
+        let mut clipboard = clipboard.borrow_mut();
+        if clipboard.is_none() {
+            *clipboard = arboard::Clipboard::new()
+                .map_err(|err| i_slint_core::debug_log!("Error opening the clipboard: {err}"))
+                .ok();
+        }
+        f(clipboard.as_mut()?)
+    })
+}
+
+/// Puts the HTML, with the text as fallback, or else the image of the content into the clipboard.
+/// The image is only put into the clipboard if there is no text, so that the text isn't lost.
+/// Returns false if there is nothing else than text to put into the clipboard, or if it couldn't
+/// be set.
+pub fn set_rich_content(content: &ClipboardContent) -> bool {
+    with_clipboard(|clipboard| {
+        if let Some(html) = &content.html {
+            clipboard.set_html(html.as_str(), content.text.as_ref().map(|t| t.as_str())).ok()
+        } else if let (Some(image), None) = (&content.image, &content.text) {
+            let image = arboard::ImageData {
+                width: image.width() as usize,
+                height: image.height() as usize,
+                bytes: image.as_bytes().into(),
+            };
+            clipboard.set_image(image).ok()
+        } else {
+            None
+        }
+    })
+    .is_some()
+}
+
+pub fn remember_content(content: ClipboardContent) {
+    LAST_CONTENT.with(|last| *last.borrow_mut() = Some(content));
+}
+
+/// Returns the content of the clipboard, given its current `text`.
+pub fn content(text: Option<String>) -> Option<ClipboardContent> {
+    let image = with_clipboard(|clipboard| clipboard.get_image().ok()).map(|image| {
+        SharedPixelBuffer::<Rgba8Pixel>::clone_from_slice(
+            &image.bytes[..],
+            image.width as u32,
+            image.height as u32,
+        )
+    });
+    // The text tells whether the clipboard still contains what the application put in it. Without
+    // text, the image put by the application can't be told apart from another one.
+    let mut content = LAST_CONTENT
+        .with(|last| {
+            last.borrow().clone().filter(|last| {
+                last.text.as_deref() == text.as_deref()
+                    && (last.text.is_some() || last.image.is_none())
+            })
+        })
+        .unwrap_or_default();
+    content.text = text.map(Into::into);
+    if image.is_some() {
+        content.image = image;
+    }
+    if content.text.is_none() && content.image.is_none() && content.custom.is_empty() {
+        return None;
+    }
+    Some(content)
+}
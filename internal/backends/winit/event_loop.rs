@@ -10,15 +10,14 @@
 use copypasta::ClipboardProvider;
 use corelib::items::PointerEventButton;
 use corelib::lengths::LogicalPoint;
-use corelib::lengths::LogicalSize;
 use corelib::SharedString;
 use i_slint_core as corelib;
 
 use corelib::api::EventLoopError;
 use corelib::graphics::euclid;
-use corelib::input::{KeyEventType, KeyInputEvent, MouseEvent};
+use corelib::input::MouseEvent;
 use corelib::window::*;
-use std::cell::{RefCell, RefMut};
+use std::cell::{Cell, RefCell, RefMut};
 use std::rc::{Rc, Weak};
 
 use crate::winitwindowadapter::WinitWindowAdapter;
@@ -39,6 +38,9 @@ struct NotRunningEventLoop {
     clipboard: RefCell<ClipboardPair>,
     instance: winit::event_loop::EventLoop<SlintUserEvent>,
     event_loop_proxy: winit::event_loop::EventLoopProxy<SlintUserEvent>,
+    /// The last seen cursor position, and whether a mouse button is pressed. It's kept
+    /// between the calls to `process_events()`.
+    pointer_state: (LogicalPoint, bool),
 }
 
 impl NotRunningEventLoop {
@@ -67,7 +69,7 @@ fn new() -> Self {
         let instance = builder.build();
         let event_loop_proxy = instance.create_proxy();
         let clipboard = RefCell::new(create_clipboard(&instance));
-        Self { clipboard, instance, event_loop_proxy }
+        Self { clipboard, instance, event_loop_proxy, pointer_state: Default::default() }
     }
 }
 
@@ -274,10 +276,12 @@ fn process_window_event(
         WindowEvent::Resized(size) => {
             window.resize_event(size)?;
         }
+        WindowEvent::Occluded(_) => {
+            // Minimizing the window may only be reported as the window being occluded
+            window.window_state_changed();
+        }
         WindowEvent::CloseRequested => {
-            if runtime_window.request_close() {
-                window.hide()?;
-            }
+            window.window().dispatch_event(corelib::platform::WindowEvent::CloseRequested);
         }
         WindowEvent::ReceivedCharacter(ch) => {
             // On Windows, X11 and Wayland sequences like Ctrl+C will send a ReceivedCharacter after the pressed keyboard input event,
@@ -311,8 +315,9 @@ fn process_window_event(
             // We don't render popups as separate windows yet, so treat
             // focus to be the same as being active.
             if have_focus != runtime_window.active() {
-                runtime_window.set_active(have_focus);
-                runtime_window.set_focus(have_focus);
+                window.window().dispatch_event(
+                    corelib::platform::WindowEvent::WindowActiveChanged(have_focus),
+                );
             }
         }
         WindowEvent::KeyboardInput { ref input, .. } => {
@@ -345,23 +350,17 @@ fn process_window_event(
             };
         }
         WindowEvent::Ime(winit::event::Ime::Preedit(string, preedit_selection)) => {
-            let preedit_selection = preedit_selection.unwrap_or((0, 0));
-            let event = KeyInputEvent {
-                event_type: KeyEventType::UpdateComposition,
+            let (selection_start, selection_end) = preedit_selection.unwrap_or((0, 0));
+            window.window().dispatch_event(corelib::platform::WindowEvent::Preedit {
                 text: string.into(),
-                preedit_selection_start: preedit_selection.0,
-                preedit_selection_end: preedit_selection.1,
-                ..Default::default()
-            };
-            runtime_window.process_key_input(event);
+                selection_start,
+                selection_end,
+            });
         }
         WindowEvent::Ime(winit::event::Ime::Commit(string)) => {
-            let event = KeyInputEvent {
-                event_type: KeyEventType::CommitComposition,
-                text: string.into(),
-                ..Default::default()
-            };
-            runtime_window.process_key_input(event);
+            window
+                .window()
+                .dispatch_event(corelib::platform::WindowEvent::Commit { text: string.into() });
         }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical(runtime_window.scale_factor() as f64);
@@ -444,25 +443,119 @@ fn process_window_event(
         }
         WindowEvent::ScaleFactorChanged { scale_factor, new_inner_size } => {
             if std::env::var("SLINT_SCALE_FACTOR").is_err() {
-                let size = new_inner_size.to_logical(scale_factor);
-                runtime_window.set_window_item_geometry(LogicalSize::new(size.width, size.height));
-                runtime_window.set_scale_factor(scale_factor as f32);
-                // Resize the underlying graphics surface
+                window.window().dispatch_event(
+                    corelib::platform::WindowEvent::ScaleFactorChanged {
+                        scale_factor: scale_factor as f32,
+                    },
+                );
+                // Resize the window item and the underlying graphics surface
                 window.resize_event(*new_inner_size)?;
             }
         }
         WindowEvent::ThemeChanged(theme) => {
             window.set_dark_color_scheme(theme == winit::window::Theme::Dark)
         }
+        WindowEvent::HoveredFile(path) => {
+            // winit sends one event per file, so accumulate them into one list
+            let mut data = SharedString::from(file_uri(&path));
+            if let Some(drag) =
+                runtime_window.drag_data().filter(|d| d.mime_type == "text/uri-list")
+            {
+                data = format!("{}\r\n{}", drag.data, data).into();
+            }
+            window.window().dispatch_event(corelib::platform::WindowEvent::DragMoved {
+                position: corelib::api::LogicalPosition::new(cursor_pos.x, cursor_pos.y),
+                mime_type: "text/uri-list".into(),
+                data,
+            });
+        }
+        WindowEvent::HoveredFileCancelled => {
+            window.window().dispatch_event(corelib::platform::WindowEvent::DragExited);
+        }
         _ => {}
     }
     Ok(())
 }
 
+/// Dispatch the files collected from the `DroppedFile` events as a single drop, with the same
+/// `text/uri-list` data as the one accumulated from the `HoveredFile` events.
+fn dispatch_dropped_files(
+    dropped_files: &mut Option<(winit::window::WindowId, Vec<std::path::PathBuf>)>,
+    cursor_pos: LogicalPoint,
+) {
+    let Some((window_id, files)) = dropped_files.take() else { return };
+    let Some(window) = window_by_id(window_id) else { return };
+    let data = files.iter().map(|path| file_uri(path)).collect::<Vec<_>>().join("\r\n");
+    window.window().dispatch_event(corelib::platform::WindowEvent::Dropped {
+        position: corelib::api::LogicalPosition::new(cursor_pos.x, cursor_pos.y),
+        mime_type: "text/uri-list".into(),
+        data: data.into(),
+    });
+}
+
+/// Returns the `file://` URI of the path, as used in `text/uri-list` data.
+fn file_uri(path: &std::path::Path) -> String {
+    let path = path.to_string_lossy();
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        // Windows paths such as C:\foo become file:///C:/foo
+        uri.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'\\' if cfg!(windows) => uri.push('/'),
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' | b':' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
 /// Runs the event loop and renders the items in the provided `component` in its
 /// own window.
-#[allow(unused_mut)] // mut need changes for wasm
 pub fn run() -> Result<(), corelib::platform::PlatformError> {
+    run_event_loop(None).map(|_| ())
+}
+
+/// Processes the pending events, waiting up to `timeout` for new events, and returns after
+/// one iteration of the event loop. See [`corelib::platform::Platform::process_events()`].
+///
+/// This calls `run_return()` for each iteration, which winit documents as not meant to pump
+/// the event loop: it works on Windows, X11 and Wayland, but isn't reliable on macOS. This
+/// limitation is documented in `slint::process_events()`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn process_events(
+    timeout: std::time::Duration,
+) -> Result<std::ops::ControlFlow<()>, corelib::platform::PlatformError> {
+    run_event_loop(Some(timeout))
+}
+
+/// Returns the control flow of an iteration of the event loop when pumping it: it waits for new
+/// events only once, not past the `deadline` if any, and doesn't wait when animations are running.
+fn pump_control_flow(
+    control_flow: winit::event_loop::ControlFlow,
+    deadline: Option<instant::Instant>,
+    waited: bool,
+) -> winit::event_loop::ControlFlow {
+    use winit::event_loop::ControlFlow;
+    match (control_flow, deadline) {
+        (ControlFlow::Wait, Some(deadline)) if !waited => ControlFlow::WaitUntil(deadline),
+        (ControlFlow::WaitUntil(instant), Some(deadline)) if !waited => {
+            ControlFlow::WaitUntil(instant.min(deadline))
+        }
+        (ControlFlow::Wait | ControlFlow::WaitUntil(_), None) if !waited => control_flow,
+        _ => ControlFlow::Exit,
+    }
+}
+
+/// Runs the event loop until it exits, or, if `pump_timeout` is set, for one iteration of the event
+/// loop that waits up to `pump_timeout` for events. Returns `Break` if the event loop exited.
+#[allow(unused_mut)] // mut need changes for wasm
+fn run_event_loop(
+    pump_timeout: Option<std::time::Duration>,
+) -> Result<std::ops::ControlFlow<()>, corelib::platform::PlatformError> {
     use winit::event::Event;
     use winit::event_loop::{ControlFlow, EventLoopWindowTarget};
 
@@ -483,6 +576,8 @@ pub fn run() -> Result<(), corelib::platform::PlatformError> {
 
     let mut winit_loop = not_running_loop_instance.instance;
     let clipboard = not_running_loop_instance.clipboard;
+    let outer_pointer_state = Rc::new(Cell::new(not_running_loop_instance.pointer_state));
+    let inner_pointer_state = outer_pointer_state.clone();
 
     // Applying the property values of the `Window` item in .slint files to the underlying winit Window
     // may for example set the width but preserve the height of the window. Winit only knows the entire
@@ -501,19 +596,41 @@ pub fn run() -> Result<(), corelib::platform::PlatformError> {
     // supplied Event::RedrawRequested, and drains them for drawing at RedrawEventsCleared.
     let mut windows_with_pending_redraw_requests = Vec::new();
 
-    // last seen cursor position
-    let mut cursor_pos = LogicalPoint::default();
-    let mut pressed = false;
-
     let outer_event_loop_error = Rc::new(RefCell::new(None));
     let inner_event_loop_error = outer_event_loop_error.clone();
 
+    // Set when the event loop exits because it was quit or because the last window was closed.
+    let outer_quit_requested = Rc::new(Cell::new(false));
+    let inner_quit_requested = outer_quit_requested.clone();
+
+    // When pumping, the event loop waits for events until the deadline, and exits after processing them.
+    // There is no deadline when the timeout is too large to be represented, such as Duration::MAX.
+    let pump_deadline = pump_timeout.map(|timeout| instant::Instant::now().checked_add(timeout));
+    let mut pump_waited = false;
+
+    // winit sends one DroppedFile event per file, without telling which one is the last. They are
+    // collected here and dropped all at once when the next event arrives.
+    let mut dropped_files = None;
+
     let mut run_fn = move |event: Event<SlintUserEvent>, control_flow: &mut ControlFlow| {
+        match event {
+            Event::WindowEvent { event: WindowEvent::DroppedFile(path), window_id } => {
+                if dropped_files.as_ref().map_or(false, |(id, _)| *id != window_id) {
+                    dispatch_dropped_files(&mut dropped_files, inner_pointer_state.get().0);
+                }
+                dropped_files.get_or_insert_with(|| (window_id, Vec::new())).1.push(path);
+                return;
+            }
+            _ => dispatch_dropped_files(&mut dropped_files, inner_pointer_state.get().0),
+        }
+
         match event {
             Event::WindowEvent { event, window_id } => {
                 if let Some(window) = window_by_id(window_id) {
+                    let (mut cursor_pos, mut pressed) = inner_pointer_state.get();
                     *inner_event_loop_error.borrow_mut() =
                         process_window_event(window, event, &mut cursor_pos, &mut pressed).err();
+                    inner_pointer_state.set((cursor_pos, pressed));
                 };
             }
 
@@ -557,12 +674,14 @@ pub fn run() -> Result<(), corelib::platform::PlatformError> {
                             .count()
                     });
                     if window_count == 0 {
+                        inner_quit_requested.set(true);
                         *control_flow = ControlFlow::Exit;
                     }
                 }
             }
 
             Event::UserEvent(SlintUserEvent::CustomEvent { event: CustomEvent::Exit }) => {
+                inner_quit_requested.set(true);
                 *control_flow = ControlFlow::Exit;
             }
 
@@ -645,6 +764,11 @@ pub fn run() -> Result<(), corelib::platform::PlatformError> {
                             ControlFlow::WaitUntil(instant::Instant::now() + next_timer);
                     }
                 }
+
+                if let Some(deadline) = pump_deadline {
+                    *control_flow = pump_control_flow(*control_flow, deadline, pump_waited);
+                    pump_waited = true;
+                }
             }
 
             _ => (),
@@ -670,17 +794,36 @@ pub fn run() -> Result<(), corelib::platform::PlatformError> {
             },
         );
 
-        *GLOBAL_PROXY.get_or_init(Default::default).lock().unwrap() = Default::default();
+        // When pumping, events sent in between the calls to process_events() are queued by winit.
+        if pump_timeout.is_none() || outer_quit_requested.get() {
+            *GLOBAL_PROXY.get_or_init(Default::default).lock().unwrap() = Default::default();
+        }
 
         // Keep the EventLoop instance alive and re-use it in future invocations of run_event_loop().
         // Winit does not support creating multiple instances of the event loop.
-        let nre = NotRunningEventLoop { clipboard, instance: winit_loop, event_loop_proxy };
+        let nre = NotRunningEventLoop {
+            clipboard,
+            instance: winit_loop,
+            event_loop_proxy,
+            pointer_state: outer_pointer_state.get(),
+        };
         MAYBE_LOOP_INSTANCE.with(|loop_instance| *loop_instance.borrow_mut() = Some(nre));
 
         if let Some(error) = outer_event_loop_error.borrow_mut().take() {
             return Err(error);
         }
-        Ok(())
+
+        let windows_remain = ALL_WINDOWS.with(|windows| {
+            windows.borrow().values().any(|window| window.upgrade().map_or(false, |w| w.is_shown()))
+        });
+        if outer_quit_requested.get()
+            || (!windows_remain
+                && QUIT_ON_LAST_WINDOW_CLOSED.load(std::sync::atomic::Ordering::Relaxed))
+        {
+            Ok(std::ops::ControlFlow::Break(()))
+        } else {
+            Ok(std::ops::ControlFlow::Continue(()))
+        }
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -829,3 +972,25 @@ fn set_contents(
         }
     }
 }
+
+#[test]
+fn pump_with_max_timeout() {
+    use winit::event_loop::ControlFlow;
+
+    // Waits for events without a deadline, instead of overflowing
+    let deadline = instant::Instant::now().checked_add(std::time::Duration::MAX);
+    assert_eq!(pump_control_flow(ControlFlow::Wait, deadline, false), ControlFlow::Wait);
+    let next_timer = instant::Instant::now() + std::time::Duration::from_secs(1);
+    assert_eq!(
+        pump_control_flow(ControlFlow::WaitUntil(next_timer), deadline, false),
+        ControlFlow::WaitUntil(next_timer)
+    );
+    assert_eq!(pump_control_flow(ControlFlow::Wait, deadline, true), ControlFlow::Exit);
+
+    let deadline = instant::Instant::now() + std::time::Duration::from_millis(16);
+    assert_eq!(
+        pump_control_flow(ControlFlow::Wait, Some(deadline), false),
+        ControlFlow::WaitUntil(deadline)
+    );
+    assert_eq!(pump_control_flow(ControlFlow::Poll, Some(deadline), false), ControlFlow::Exit);
+}
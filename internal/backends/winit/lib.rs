@@ -12,6 +12,8 @@
 use renderer::WinitCompatibleRenderer;
 use std::rc::Rc;
 
+#[cfg(rich_clipboard)]
+mod clipboard;
 mod winitwindowadapter;
 use i_slint_core::platform::PlatformError;
 use winitwindowadapter::*;
@@ -169,6 +171,14 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
         crate::event_loop::run()
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn process_events(
+        &self,
+        timeout: core::time::Duration,
+    ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+        crate::event_loop::process_events(timeout)
+    }
+
     fn new_event_loop_proxy(&self) -> Option<Box<dyn EventLoopProxy>> {
         struct Proxy;
         impl EventLoopProxy for Proxy {
@@ -230,6 +240,38 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
             event_loop_target.clipboard(clipboard)?.get_contents().ok()
         })
     }
+
+    #[cfg(rich_clipboard)]
+    fn set_clipboard_content(
+        &self,
+        content: i_slint_core::platform::ClipboardContent,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard != i_slint_core::platform::Clipboard::DefaultClipboard {
+            if let Some(text) = content.text {
+                self.set_clipboard_text(&text, clipboard);
+            }
+            return;
+        }
+        if !clipboard::set_rich_content(&content) {
+            if let Some(text) = &content.text {
+                self.set_clipboard_text(text, clipboard);
+            }
+        }
+        clipboard::remember_content(content);
+    }
+
+    #[cfg(rich_clipboard)]
+    fn clipboard_content(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<i_slint_core::platform::ClipboardContent> {
+        let text = self.clipboard_text(clipboard.clone());
+        if clipboard != i_slint_core::platform::Clipboard::DefaultClipboard {
+            return text.map(i_slint_core::platform::ClipboardContent::from_text);
+        }
+        clipboard::content(text)
+    }
 }
 
 /// Invokes the specified callback with a reference to the [`winit::event_loop::EventLoopWindowTarget`].
@@ -20,7 +20,6 @@
 use std::cell::RefCell;
 use std::rc::{Rc, Weak};
 
-use i_slint_core::input::{KeyEventType, KeyInputEvent};
 use i_slint_core::platform::WindowEvent;
 use i_slint_core::window::{WindowAdapter, WindowInner};
 use i_slint_core::SharedString;
@@ -135,10 +134,8 @@ pub fn new(
         h.add_event_listener("blur", move |_: web_sys::Event| {
             // Make sure that the window gets marked as unfocused when the focus leaves the input
             if let Some(window_adapter) = win.upgrade() {
-                let window_inner = WindowInner::from_pub(window_adapter.window());
                 if !canvas.matches(":focus").unwrap_or(false) {
-                    window_inner.set_active(false);
-                    window_inner.set_focus(false);
+                    window_adapter.window().dispatch_event(WindowEvent::WindowActiveChanged(false));
                 }
             }
         });
@@ -185,12 +182,7 @@ pub fn new(
         let input = h.input.clone();
         h.add_event_listener("compositionend", move |e: web_sys::CompositionEvent| {
             if let (Some(window_adapter), Some(data)) = (win.upgrade(), e.data()) {
-                let window_inner = WindowInner::from_pub(window_adapter.window());
-                window_inner.process_key_input(KeyInputEvent {
-                    text: data.into(),
-                    event_type: KeyEventType::CommitComposition,
-                    ..Default::default()
-                });
+                window_adapter.window().dispatch_event(WindowEvent::Commit { text: data.into() });
                 input.set_value("");
             }
         });
@@ -198,15 +190,12 @@ pub fn new(
         let win = window_adapter.clone();
         h.add_event_listener("compositionupdate", move |e: web_sys::CompositionEvent| {
             if let (Some(window_adapter), Some(data)) = (win.upgrade(), e.data()) {
-                let window_inner = WindowInner::from_pub(window_adapter.window());
                 let text: SharedString = data.into();
                 let preedit_cursor_pos = text.len();
-                window_inner.process_key_input(KeyInputEvent {
+                window_adapter.window().dispatch_event(WindowEvent::Preedit {
                     text,
-                    event_type: KeyEventType::UpdateComposition,
-                    preedit_selection_start: preedit_cursor_pos,
-                    preedit_selection_end: preedit_cursor_pos,
-                    ..Default::default()
+                    selection_start: preedit_cursor_pos,
+                    selection_end: preedit_cursor_pos,
                 });
             }
         });
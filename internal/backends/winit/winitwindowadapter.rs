@@ -98,6 +98,14 @@ fn window_is_resizable(min_size: Option<LogicalSize>, max_size: Option<LogicalSi
     }
 }
 
+/// The minimized, maximized, and full screen state of the window
+#[derive(Clone, Copy, Default, PartialEq)]
+struct WindowState {
+    minimized: bool,
+    maximized: bool,
+    full_screen: bool,
+}
+
 /// GraphicsWindow is an implementation of the [WindowAdapter][`crate::eventloop::WindowAdapter`] trait. This is
 /// typically instantiated by entry factory functions of the different graphics back ends.
 pub struct WinitWindowAdapter {
@@ -109,6 +117,9 @@ pub struct WinitWindowAdapter {
     in_resize_event: Cell<bool>,
     dark_color_scheme: OnceCell<Pin<Box<Property<bool>>>>,
     constraints: Cell<(corelib::layout::LayoutInfo, corelib::layout::LayoutInfo)>,
+    /// The state of the winit window as last applied from, or reported to, the window item.
+    /// It's only applied again when the properties of the window item change.
+    window_state: Cell<WindowState>,
     shown: Cell<bool>,
 
     winit_window: Option<Rc<winit::window::Window>>,
@@ -129,6 +140,7 @@ fn default() -> Self {
             in_resize_event: Default::default(),
             dark_color_scheme: Default::default(),
             constraints: Default::default(),
+            window_state: Default::default(),
             shown: Default::default(),
             winit_window: Default::default(),
             renderer: Default::default(),
@@ -314,18 +326,44 @@ pub fn resize_event(&self, size: winit::dpi::PhysicalSize<u32>) -> Result<(), Pl
         self.in_resize_event.set(true);
         scopeguard::defer! { self.in_resize_event.set(false); }
 
+        // Maximizing, minimizing, or going full screen come with a resize event
+        self.window_state_changed();
+
         // When a window is minimized on Windows, we get a move event to an off-screen position
         // and a resize even with a zero size. Don't forward that, especially not to the renderer,
         // which might panic when trying to create a zero-sized surface.
         if size.width > 0 && size.height > 0 {
             let physical_size = physical_size_to_slint(&size);
-            self.window().set_size(physical_size);
+            let scale_factor = WindowInner::from_pub(self.window()).scale_factor();
+            self.window().dispatch_event(corelib::platform::WindowEvent::Resized {
+                size: physical_size.to_logical(scale_factor),
+            });
             self.renderer().resize_event(physical_size)
         } else {
             Ok(())
         }
     }
 
+    /// Reports the state of the winit window to the window item, when it was changed by the user.
+    pub fn window_state_changed(&self) {
+        let winit_window = self.winit_window();
+        let previous_state = self.window_state.get();
+        let state = WindowState {
+            // Not all platforms can tell if the window is minimized
+            minimized: winit_window.is_minimized().unwrap_or(previous_state.minimized),
+            maximized: winit_window.is_maximized(),
+            full_screen: winit_window.fullscreen().is_some(),
+        };
+        if state != previous_state {
+            self.window_state.set(state);
+            self.window().dispatch_event(corelib::platform::WindowEvent::WindowStateChanged {
+                minimized: state.minimized,
+                maximized: state.maximized,
+                full_screen: state.full_screen,
+            });
+        }
+    }
+
     pub fn set_dark_color_scheme(&self, dark_mode: bool) {
         self.dark_color_scheme
             .get_or_init(|| Box::pin(Property::new(false)))
@@ -380,6 +418,32 @@ fn apply_window_properties(&self, window_item: Pin<&i_slint_core::items::WindowI
             winit::window::WindowLevel::Normal
         });
 
+        let (constraints_horizontal, constraints_vertical) = self.constraints.get();
+        let (min_size, max_size) = i_slint_core::layout::min_max_size_for_layout_constraints(
+            constraints_horizontal,
+            constraints_vertical,
+        );
+        winit_window
+            .set_resizable(window_item.resizable() && window_is_resizable(min_size, max_size));
+
+        let state = WindowState {
+            minimized: window_item.minimized(),
+            maximized: window_item.maximized(),
+            full_screen: window_item.full_screen(),
+        };
+        let previous_state = self.window_state.replace(state);
+        if state.full_screen != previous_state.full_screen {
+            winit_window.set_fullscreen(
+                state.full_screen.then(|| winit::window::Fullscreen::Borderless(None)),
+            );
+        }
+        if state.maximized != previous_state.maximized {
+            winit_window.set_maximized(state.maximized);
+        }
+        if state.minimized != previous_state.minimized {
+            winit_window.set_minimized(state.minimized);
+        }
+
         if width <= 0. || height <= 0. {
             must_resize = true;
 
@@ -444,13 +508,11 @@ fn apply_geometry_constraint(
                 constraints_vertical,
             );
 
-            let resizable = window_is_resizable(min_size, max_size);
-
+            // The resizable flag is set in apply_window_properties, as it also depends on the window item
             let winit_min_inner = min_size.map(into_size);
             winit_window.set_min_inner_size(winit_min_inner);
             let winit_max_inner = max_size.map(into_size);
             winit_window.set_max_inner_size(winit_max_inner);
-            winit_window.set_resizable(resizable);
 
             adjust_window_size_to_satisfy_constraints(
                 winit_window,
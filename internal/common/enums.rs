@@ -308,6 +308,33 @@ enum AccessibleRole {
                 Text,
             }
 
+            /// This enum describes the direction in which an [animation](../reference/animations.md) plays
+            /// its iterations, set with its `direction` property.
+            enum AnimationDirection {
+                /// Every iteration goes from the start value to the end value.
+                Normal,
+                /// Every iteration goes from the end value to the start value.
+                Reverse,
+                /// The direction changes after each iteration, starting with a normal iteration.
+                Alternate,
+                /// The direction changes after each iteration, starting with a reversed iteration.
+                AlternateReverse,
+            }
+
+            /// This enum describes where a row ends up in the visible area when calling the `scroll-to()`
+            /// function of a [`ListView`](widgets.md#listview).
+            enum ScrollAlignment {
+                /// The row is placed at the top of the visible area.
+                Start,
+                /// The row is vertically centered within the visible area.
+                Center,
+                /// The row is placed at the bottom of the visible area.
+                End,
+                /// The view is scrolled as little as possible to make the row fully visible.
+                /// Nothing happens if the row is already visible.
+                Nearest,
+            }
+
             /// This enum represents the different values of the `sort-order` property.
             /// It's used to sort a [`StandardTableView`](widgets.md#standardtableview) by a column.
             enum SortOrder {
@@ -320,6 +347,19 @@ enum SortOrder {
                 /// The column is sorted in descending order.
                 Descending,
             }
+
+            /// This enum describes when a [`PopupWindow`](elements.md#popupwindow) closes automatically.
+            /// Popups that close automatically are also closed when the escape key is pressed.
+            enum PopupClosePolicy {
+                /// The popup closes when the mouse is pressed outside of it, or when something is
+                /// clicked or activated with the return key within it, unless that opens another popup.
+                /// The popups with this policy it was opened from close along with it.
+                CloseOnClick,
+                /// The popup closes only when the mouse is pressed outside of it.
+                CloseOnClickOutside,
+                /// The popup never closes automatically.
+                NoAutoClose,
+            }
         ];
     };
 }
@@ -26,6 +26,22 @@ pub fn lower_macro(
         BuiltinMacroFunction::Max => min_max_macro(n, '>', sub_expr.collect(), diag),
         BuiltinMacroFunction::Mod => mod_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Debug => debug_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArrayFilter
+        | BuiltinMacroFunction::ArrayMap
+        | BuiltinMacroFunction::ArrayAny
+        | BuiltinMacroFunction::ArrayAll
+        | BuiltinMacroFunction::ArraySum
+        | BuiltinMacroFunction::ArrayIndexOf
+        | BuiltinMacroFunction::ArraySlice => {
+            // These are member functions of arrays, resolved by `Expression::from_array_function_call`
+            diag.push_error("Array functions must be called on an array".into(), &n);
+            Expression::Invalid
+        }
+        BuiltinMacroFunction::StructFunction(_) => {
+            // Resolved by `Expression::from_struct_function_call`
+            diag.push_error("Struct functions must be called on a struct".into(), &n);
+            Expression::Invalid
+        }
         BuiltinMacroFunction::CubicBezier => {
             let mut has_error = None;
             let expected_argument_type_error =
@@ -61,8 +77,91 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Keyframes => keyframes_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::DateTime => date_time_macro(n, sub_expr.collect(), diag),
+    }
+}
+
+/// Returns the value of a (possibly negative) number literal without unit
+fn number_literal(expr: &Expression) -> Option<f32> {
+    match expr {
+        Expression::NumberLiteral(val, Unit::None) => Some(*val as f32),
+        Expression::UnaryOp { sub, op: '-' } => number_literal(sub).map(|v| -v),
+        _ => None,
+    }
+}
+
+fn spring_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 2 && args.len() != 3 {
+        diag.push_error(
+            "spring() needs 2 or 3 arguments: stiffness, damping, and optionally mass".into(),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut values = [0., 0., 1.];
+    for ((expr, n), value) in args.iter().zip(values.iter_mut()) {
+        match number_literal(expr) {
+            Some(v) if v > 0. => *value = v,
+            Some(_) => {
+                diag.push_error("Arguments to spring must be positive".into(), n);
+                return Expression::Invalid;
+            }
+            None => {
+                diag.push_error("Arguments to spring must be number literal".into(), n);
+                return Expression::Invalid;
+            }
+        }
+    }
+    let [stiffness, damping, mass] = values;
+    Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass))
+}
+
+fn keyframes_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.is_empty() || args.len() % 2 != 0 {
+        diag.push_error(
+            "keyframes() needs pairs of arguments: a position and the progress at that position"
+                .into(),
+            &node,
+        );
+        return Expression::Invalid;
     }
+    let mut keyframes = Vec::with_capacity(args.len() / 2);
+    let mut last_position = 0.;
+    for pair in args.chunks(2) {
+        let mut values = [0.; 2];
+        for ((expr, n), value) in pair.iter().zip(values.iter_mut()) {
+            let Some(v) = number_literal(expr).or_else(|| match expr {
+                Expression::NumberLiteral(val, Unit::Percent) => Some(*val as f32 / 100.),
+                _ => None,
+            }) else {
+                diag.push_error("Arguments to keyframes must be number literal".into(), n);
+                return Expression::Invalid;
+            };
+            *value = v;
+        }
+        let [position, progress] = values;
+        if !(last_position..=1.).contains(&position) {
+            diag.push_error(
+                "Keyframe positions must be between 0 and 1, in increasing order".into(),
+                &pair[0].1,
+            );
+            return Expression::Invalid;
+        }
+        last_position = position;
+        keyframes.push((position, progress));
+    }
+    Expression::EasingCurve(EasingCurve::Keyframes(keyframes))
 }
 
 fn min_max_macro(
@@ -184,6 +283,31 @@ fn rgb_macro(
     }
 }
 
+fn date_time_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 3 || args.len() > 6 {
+        diag.push_error(
+            "date-time() needs between 3 and 6 arguments: year, month, day, and optionally hour, minute, and second".into(),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut arguments: Vec<_> =
+        args.into_iter().map(|(expr, n)| expr.maybe_convert_to(Type::Int32, &n, diag)).collect();
+    arguments.resize(6, Expression::NumberLiteral(0., Unit::None));
+    Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(
+            BuiltinFunction::DateTimeFromComponents,
+            node.as_ref().map(|t| t.to_source_location()),
+        )),
+        arguments,
+        source_location: Some(node.to_source_location()),
+    }
+}
+
 fn debug_macro(
     node: Option<NodeOrToken>,
     args: Vec<(Expression, Option<NodeOrToken>)>,
@@ -238,6 +362,14 @@ fn to_debug_string(
         }
         Type::Float32 | Type::Int32 => expr.maybe_convert_to(Type::String, &node, diag),
         Type::String => expr,
+        Type::DateTime => Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::DateTimeToIsoString,
+                node.as_ref().map(|t| t.to_source_location()),
+            )),
+            arguments: vec![expr],
+            source_location: node.as_ref().map(|t| t.to_source_location()),
+        },
         // TODO
         Type::Color | Type::Brush | Type::Image | Type::Easing | Type::Array(_) => {
             Expression::StringLiteral("<debug-of-this-type-not-yet-implemented>".into())
@@ -253,6 +253,15 @@ pub fn source_file(&self) -> Option<&Path> {
     }
 }
 
+impl Spanned for Diagnostic {
+    fn span(&self) -> Span {
+        self.span.span()
+    }
+    fn source_file(&self) -> Option<&SourceFile> {
+        self.span.source_file()
+    }
+}
+
 impl std::fmt::Display for Diagnostic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(sf) = self.span.source_file() {
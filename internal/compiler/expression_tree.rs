@@ -39,10 +39,38 @@ pub enum BuiltinFunction {
     Pow,
     SetFocusItem,
     ShowPopupWindow,
+    ShowChildWindow,
+    HideChildWindow,
     /// the "42".to_float()
     StringToFloat,
     /// the "42".is_float()
     StringIsFloat,
+    /// the "abc".to-uppercase()
+    StringToUppercase,
+    /// the "ABC".to-lowercase()
+    StringToLowercase,
+    /// the " abc ".trim()
+    StringTrim,
+    /// the "abc".contains("b")
+    StringContains,
+    /// the "abc".starts-with("a")
+    StringStartsWith,
+    /// the "a,b".split(",")
+    StringSplit,
+    /// the "abc".character-count()
+    StringCharacterCount,
+    /// `format-number(value, decimals, grouping)`
+    FormatNumber,
+    /// `date-time-from-timestamp(seconds)`
+    DateTimeFromTimestamp,
+    /// `date-time(year, month, day, hour, minute, second)`
+    DateTimeFromComponents,
+    /// the `date.year`, `date.month`, ...
+    DateTimeComponent(DateTimeComponent),
+    /// the `date.timestamp`
+    DateTimeTimestamp,
+    /// the `date.to-iso-string()`
+    DateTimeToIsoString,
     ColorBrighter,
     ColorDarker,
     ImageSize,
@@ -55,6 +83,17 @@ pub enum BuiltinFunction {
     RegisterCustomFontByPath,
     RegisterCustomFontByMemory,
     RegisterBitmapFont,
+    /// `@tr(...)`: the arguments are the string, the context, the domain, the array of
+    /// formatting arguments, the count for the plural and the plural form
+    Translate,
+    /// `Clipboard.text()`
+    ClipboardText,
+    /// `Clipboard.set-text(text)`
+    SetClipboardText,
+    /// `Clipboard.image()`
+    ClipboardImage,
+    /// `Clipboard.set-image(image)`
+    SetClipboardImage,
 }
 
 #[derive(Debug, Clone)]
@@ -71,11 +110,78 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Mod,
     CubicBezier,
+    /// `spring(stiffness, damping)` or `spring(stiffness, damping, mass)` with number literals
+    Spring,
+    /// `keyframes(position1, progress1, position2, progress2, ...)` with number literals
+    Keyframes,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
+    /// `date-time(year, month, day)`, with optional hour, minute and second
+    DateTime,
+    /// `array.filter(x => condition)`
+    ArrayFilter,
+    /// `array.map(x => value)`
+    ArrayMap,
+    /// `array.any(x => condition)`
+    ArrayAny,
+    /// `array.all(x => condition)`
+    ArrayAll,
+    /// `array.sum()` or `array.sum(x => value)`
+    ArraySum,
+    /// `array.index-of(value)`
+    ArrayIndexOf,
+    /// `array.slice(start, end)`
+    ArraySlice,
+    /// `value.name(args)` where `name` is a function declared in the struct of `value`.
+    /// The call is inlined by `Expression::from_struct_function_call`
+    StructFunction(String),
+}
+
+/// A calendar component of a `date-time`, see [`BuiltinFunction::DateTimeComponent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeComponent {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    DayOfWeek,
+}
+
+impl DateTimeComponent {
+    /// The name of the corresponding member in .slint
+    pub fn name(self) -> &'static str {
+        match self {
+            DateTimeComponent::Year => "year",
+            DateTimeComponent::Month => "month",
+            DateTimeComponent::Day => "day",
+            DateTimeComponent::Hour => "hour",
+            DateTimeComponent::Minute => "minute",
+            DateTimeComponent::Second => "second",
+            DateTimeComponent::DayOfWeek => "day-of-week",
+        }
+    }
+}
+
+/// The operation of an [`Expression::ArrayOperation`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayOperation {
+    /// A new array with the elements for which the body is true
+    Filter,
+    /// A new array with the value of the body for each element
+    Map,
+    /// True if the body is true for at least one element
+    Any,
+    /// True if the body is true for all the elements
+    All,
+    /// The sum of the value of the body for each element
+    Sum,
+    /// The index of the first element for which the body is true, or -1
+    IndexOf,
 }
 
 impl BuiltinFunction {
@@ -118,7 +224,9 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
             },
-            BuiltinFunction::ShowPopupWindow => Type::Function {
+            BuiltinFunction::ShowPopupWindow
+            | BuiltinFunction::ShowChildWindow
+            | BuiltinFunction::HideChildWindow => Type::Function {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
             },
@@ -128,6 +236,41 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::StringIsFloat => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::String] }
             }
+            BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::String] }
+            }
+            BuiltinFunction::StringContains | BuiltinFunction::StringStartsWith => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::StringSplit => Type::Function {
+                return_type: Box::new(Type::Array(Box::new(Type::String))),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::StringCharacterCount => {
+                Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::String] }
+            }
+            BuiltinFunction::FormatNumber => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Float32, Type::Int32, Type::Bool],
+            },
+            BuiltinFunction::DateTimeFromTimestamp => {
+                Type::Function { return_type: Box::new(Type::DateTime), args: vec![Type::Duration] }
+            }
+            BuiltinFunction::DateTimeFromComponents => {
+                Type::Function { return_type: Box::new(Type::DateTime), args: vec![Type::Int32; 6] }
+            }
+            BuiltinFunction::DateTimeComponent(_) => {
+                Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::DateTime] }
+            }
+            BuiltinFunction::DateTimeTimestamp => {
+                Type::Function { return_type: Box::new(Type::Duration), args: vec![Type::DateTime] }
+            }
+            BuiltinFunction::DateTimeToIsoString => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::DateTime] }
+            }
             BuiltinFunction::ImplicitLayoutInfo(_) => Type::Function {
                 return_type: Box::new(crate::layout::layout_info_type()),
                 args: vec![Type::ElementReference],
@@ -149,6 +292,7 @@ pub fn ty(&self) -> Type {
                     .collect(),
                     name: Some("Size".to_string()),
                     node: None,
+                    definition: None,
                 }),
                 args: vec![Type::Image],
             },
@@ -177,6 +321,29 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::RegisterBitmapFont => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::Int32] }
             }
+            BuiltinFunction::Translate => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![
+                    Type::String,
+                    Type::String,
+                    Type::String,
+                    Type::Array(Type::String.into()),
+                    Type::Int32,
+                    Type::String,
+                ],
+            },
+            BuiltinFunction::ClipboardText => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![] }
+            }
+            BuiltinFunction::SetClipboardText => {
+                Type::Function { return_type: Box::new(Type::Void), args: vec![Type::String] }
+            }
+            BuiltinFunction::ClipboardImage => {
+                Type::Function { return_type: Box::new(Type::Image), args: vec![] }
+            }
+            BuiltinFunction::SetClipboardImage => {
+                Type::Function { return_type: Box::new(Type::Void), args: vec![Type::Image] }
+            }
         }
     }
 
@@ -204,8 +371,23 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::Pow
             | BuiltinFunction::ATan => true,
             BuiltinFunction::SetFocusItem => false,
-            BuiltinFunction::ShowPopupWindow => false,
+            BuiltinFunction::ShowPopupWindow
+            | BuiltinFunction::ShowChildWindow
+            | BuiltinFunction::HideChildWindow => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringCharacterCount
+            | BuiltinFunction::FormatNumber => true,
+            BuiltinFunction::DateTimeFromTimestamp
+            | BuiltinFunction::DateTimeFromComponents
+            | BuiltinFunction::DateTimeComponent(_)
+            | BuiltinFunction::DateTimeTimestamp
+            | BuiltinFunction::DateTimeToIsoString => true,
             BuiltinFunction::ColorBrighter | BuiltinFunction::ColorDarker => true,
             // ImageSize is pure, except when loading images via the network. Then the initial size will be 0/0 and
             // we need to make sure that calls to this function stay within a binding, so that the property
@@ -223,6 +405,12 @@ fn is_const(&self) -> bool {
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
+            // The translation can change at run-time when the language changes
+            BuiltinFunction::Translate => false,
+            BuiltinFunction::ClipboardText
+            | BuiltinFunction::SetClipboardText
+            | BuiltinFunction::ClipboardImage
+            | BuiltinFunction::SetClipboardImage => false,
         }
     }
 
@@ -250,8 +438,23 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::Pow
             | BuiltinFunction::ATan => true,
             BuiltinFunction::SetFocusItem => false,
-            BuiltinFunction::ShowPopupWindow => false,
+            BuiltinFunction::ShowPopupWindow
+            | BuiltinFunction::ShowChildWindow
+            | BuiltinFunction::HideChildWindow => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringCharacterCount
+            | BuiltinFunction::FormatNumber => true,
+            BuiltinFunction::DateTimeFromTimestamp
+            | BuiltinFunction::DateTimeFromComponents
+            | BuiltinFunction::DateTimeComponent(_)
+            | BuiltinFunction::DateTimeTimestamp
+            | BuiltinFunction::DateTimeToIsoString => true,
             BuiltinFunction::ColorBrighter | BuiltinFunction::ColorDarker => true,
             BuiltinFunction::ImageSize => true,
             BuiltinFunction::ArrayLength => true,
@@ -262,6 +465,9 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
+            BuiltinFunction::Translate => true,
+            BuiltinFunction::ClipboardText | BuiltinFunction::ClipboardImage => true,
+            BuiltinFunction::SetClipboardText | BuiltinFunction::SetClipboardImage => false,
         }
     }
 }
@@ -469,6 +675,19 @@ pub enum Expression {
         index: Box<Expression>,
     },
 
+    /// Evaluate `body` for each element of the array, and combine the results according to `op`.
+    /// The body can read the current element and its index with [`Expression::ReadLocalVariable`].
+    ArrayOperation {
+        op: ArrayOperation,
+        /// This expression should have [`Type::Array`] type
+        array: Box<Expression>,
+        /// The name of the local variable holding the current element
+        element: String,
+        /// The name of the local variable holding the index of the current element (as int)
+        index: String,
+        body: Box<Expression>,
+    },
+
     /// Cast an expression to the given type
     Cast {
         from: Box<Expression>,
@@ -605,6 +824,13 @@ pub fn ty(&self) -> Type {
                 Type::Array(ty) => (*ty).clone(),
                 _ => Type::Invalid,
             },
+            Expression::ArrayOperation { op, array, body, .. } => match op {
+                ArrayOperation::Filter => array.ty(),
+                ArrayOperation::Map => Type::Array(Box::new(body.ty())),
+                ArrayOperation::Any | ArrayOperation::All => Type::Bool,
+                ArrayOperation::Sum => body.ty(),
+                ArrayOperation::IndexOf => Type::Int32,
+            },
             Expression::Cast { to, .. } => to.clone(),
             Expression::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty()),
             Expression::FunctionCall { function, .. } => match function.ty() {
@@ -721,6 +947,10 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                 visitor(&**array);
                 visitor(&**index);
             }
+            Expression::ArrayOperation { array, body, .. } => {
+                visitor(&**array);
+                visitor(&**body);
+            }
             Expression::RepeaterIndexReference { .. } => {}
             Expression::RepeaterModelReference { .. } => {}
             Expression::Cast { from, .. } => visitor(&**from),
@@ -818,6 +1048,10 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                 visitor(&mut **array);
                 visitor(&mut **index);
             }
+            Expression::ArrayOperation { array, body, .. } => {
+                visitor(&mut **array);
+                visitor(&mut **body);
+            }
             Expression::RepeaterIndexReference { .. } => {}
             Expression::RepeaterModelReference { .. } => {}
             Expression::Cast { from, .. } => visitor(&mut **from),
@@ -926,6 +1160,7 @@ pub fn is_constant(&self) -> bool {
             Expression::BuiltinMacroReference { .. } => true,
             Expression::StructFieldAccess { base, .. } => base.is_constant(),
             Expression::ArrayIndex { array, index } => array.is_constant() && index.is_constant(),
+            Expression::ArrayOperation { .. } => false,
             Expression::Cast { from, .. } => from.is_constant(),
             Expression::CodeBlock(sub) => sub.len() == 1 && sub.first().unwrap().is_constant(),
             Expression::FunctionCall { function, arguments, .. } => {
@@ -993,13 +1228,18 @@ pub fn maybe_convert_to(
                 },
                 (
                     Type::Struct { fields: ref left, .. },
-                    Type::Struct { fields: right, name, node: n },
+                    Type::Struct { fields: right, name, node: n, definition },
                 ) if left != right => {
                     if let Expression::Struct { mut values, .. } = self {
                         let mut new_values = HashMap::new();
                         for (key, ty) in right {
                             let (key, expression) = values.remove_entry(key).map_or_else(
-                                || (key.clone(), Expression::default_value_for_type(ty)),
+                                || {
+                                    (
+                                        key.clone(),
+                                        Expression::default_value_for_field(&target_type, key, ty),
+                                    )
+                                },
                                 |(k, e)| (k, e.maybe_convert_to(ty.clone(), node, diag)),
                             );
                             new_values.insert(key, expression);
@@ -1017,13 +1257,14 @@ pub fn maybe_convert_to(
                                         fields: left.clone(),
                                         name: name.clone(),
                                         node: n.clone(),
+                                        definition: definition.clone(),
                                     },
                                 }),
                                 name: key.clone(),
                             }
                             .maybe_convert_to(ty.clone(), node, diag)
                         } else {
-                            Expression::default_value_for_type(ty)
+                            Expression::default_value_for_field(&target_type, key, ty)
                         };
                         new_values.insert(key.clone(), expression);
                     }
@@ -1163,7 +1404,7 @@ pub fn default_value_for_type(ty: &Type) -> Expression {
             | Type::LayoutCache => Expression::Invalid,
             Type::Float32 => Expression::NumberLiteral(0., Unit::None),
             Type::String => Expression::StringLiteral(String::new()),
-            Type::Int32 | Type::Color | Type::UnitProduct(_) => Expression::Cast {
+            Type::Int32 | Type::Color | Type::DateTime | Type::UnitProduct(_) => Expression::Cast {
                 from: Box::new(Expression::NumberLiteral(0., Unit::None)),
                 to: ty.clone(),
             },
@@ -1187,7 +1428,7 @@ pub fn default_value_for_type(ty: &Type) -> Expression {
                 ty: ty.clone(),
                 values: fields
                     .iter()
-                    .map(|(k, v)| (k.clone(), Expression::default_value_for_type(v)))
+                    .map(|(k, v)| (k.clone(), Expression::default_value_for_field(ty, k, v)))
                     .collect(),
             },
             Type::Easing => Expression::EasingCurve(EasingCurve::default()),
@@ -1201,6 +1442,14 @@ pub fn default_value_for_type(ty: &Type) -> Expression {
         }
     }
 
+    /// The default value of the field `name` of type `field_ty` in the struct `struct_ty`:
+    /// the one declared in .slint if there is one, or the default value of its type
+    fn default_value_for_field(struct_ty: &Type, name: &str, field_ty: &Type) -> Expression {
+        struct_ty
+            .struct_field_default(name)
+            .unwrap_or_else(|| Expression::default_value_for_type(field_ty))
+    }
+
     /// Try to mark this expression to a lvalue that can be assigned to.
     ///
     /// Return true if the expression is a "lvalue" that can be used as the left hand side of a `=` or `+=` or similar
@@ -1399,6 +1648,16 @@ pub enum EasingCurve {
     #[default]
     Linear,
     CubicBezier(f32, f32, f32, f32),
+    EaseInElastic,
+    EaseOutElastic,
+    EaseInOutElastic,
+    EaseInBounce,
+    EaseOutBounce,
+    EaseInOutBounce,
+    /// stiffness, damping, mass
+    Spring(f32, f32, f32),
+    /// (position, progress) pairs, sorted by position
+    Keyframes(Vec<(f32, f32)>),
     // CubicBezierNonConst([Box<Expression>; 4]),
     // Custom(Box<dyn Fn(f32)->f32>),
 }
@@ -1455,6 +1714,12 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             pretty_print(f, index)?;
             write!(f, "]")
         }
+        Expression::ArrayOperation { op, array, element, index, body } => {
+            pretty_print(f, array)?;
+            write!(f, ".{:?}(({}, {}) => ", op, element, index)?;
+            pretty_print(f, body)?;
+            write!(f, ")")
+        }
         Expression::Cast { from, to } => {
             write!(f, "(")?;
             pretty_print(f, from)?;
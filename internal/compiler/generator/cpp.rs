@@ -104,6 +104,7 @@ pub enum Declaration {
         Function(Function),
         Var(Var),
         TypeAlias(TypeAlias),
+        Enum(Enum),
     }
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -262,6 +263,25 @@ fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         }
     }
 
+    #[derive(Default, Debug)]
+    pub struct Enum {
+        pub name: String,
+        pub values: Vec<String>,
+    }
+
+    impl Display for Enum {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            indent(f)?;
+            writeln!(f, "enum class {} {{", self.name)?;
+            for value in &self.values {
+                indent(f)?;
+                writeln!(f, "    {},", value)?;
+            }
+            indent(f)?;
+            writeln!(f, "}};")
+        }
+    }
+
     pub trait CppType {
         fn cpp_type(&self) -> Option<String>;
     }
@@ -286,8 +306,9 @@ pub fn escape_string(str: &str) -> String {
     }
 }
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve};
-use crate::langtype::{ElementType, NativeClass, Type};
+use crate::expression_tree::Expression as tree_Expression;
+use crate::expression_tree::{ArrayOperation, BuiltinFunction, EasingCurve};
+use crate::langtype::{ElementType, Enumeration, NativeClass, StructDefinition, Type};
 use crate::layout::Orientation;
 use crate::llr::{
     self, EvaluationContext as llr_EvaluationContext, ParentCtx as llr_ParentCtx,
@@ -312,6 +333,7 @@ fn cpp_type(&self) -> Option<String> {
             Type::String => Some("slint::SharedString".to_owned()),
             Type::Color => Some("slint::Color".to_owned()),
             Type::Duration => Some("std::int64_t".to_owned()),
+            Type::DateTime => Some("slint::DateTime".to_owned()),
             Type::Angle => Some("float".to_owned()),
             Type::PhysicalLength => Some("float".to_owned()),
             Type::LogicalLength => Some("float".to_owned()),
@@ -334,9 +356,7 @@ fn cpp_type(&self) -> Option<String> {
 
             Type::Array(i) => Some(format!("std::shared_ptr<slint::Model<{}>>", i.cpp_type()?)),
             Type::Image => Some("slint::Image".to_owned()),
-            Type::Enumeration(enumeration) => {
-                Some(format!("slint::cbindgen_private::{}", ident(&enumeration.name)))
-            }
+            Type::Enumeration(enumeration) => Some(enumeration_type_name(enumeration)),
             Type::Brush => Some("slint::Brush".to_owned()),
             Type::LayoutCache => Some("slint::SharedVector<float>".into()),
             _ => None,
@@ -392,11 +412,41 @@ fn property_set_value_code(
     let prop = access_member(property, ctx);
     if let Some(animation) = ctx.current_sub_component.and_then(|c| c.animations.get(property)) {
         let animation_code = compile_expression(animation, ctx);
-        return format!("{}.set_animated_value({}, {})", prop, value_expr, animation_code);
+        let set_value = format!("{}.set_animated_value({}, {})", prop, value_expr, animation_code);
+        return match ctx
+            .current_sub_component
+            .and_then(|c| c.animation_event_handlers.get(property))
+        {
+            Some(handler) => format!(
+                "[&]{{ {set_value}; {handler} }}()",
+                handler = animation_event_handler_code(&prop, handler, ctx)
+            ),
+            None => set_value,
+        };
     }
     format!("{}.set({})", prop, value_expr)
 }
 
+/// Returns the statement that installs the `animation-started` and `animation-finished` handlers
+/// on the animation controller of the property.
+fn animation_event_handler_code(
+    prop_access: &str,
+    handler: &llr::AnimationEventHandler,
+    ctx: &EvaluationContext,
+) -> String {
+    format!(
+        "if (auto controller = {prop_access}.animation_controller()) {{
+            controller->set_event_handler([self, weak = self->self_weak](slint::AnimationEvent event) {{
+                // The events are delivered on the next animation tick, the component might be gone by then
+                if (!weak.lock()) return;
+                if (event == slint::AnimationEvent::Started) {{ {started}; }} else {{ {finished}; }}
+            }});
+        }}",
+        started = compile_expression(&handler.started, ctx),
+        finished = compile_expression(&handler.finished, ctx),
+    )
+}
+
 fn handle_property_init(
     prop: &llr::PropertyReference,
     binding_expression: &llr::BindingExpression,
@@ -467,6 +517,10 @@ fn handle_property_init(
                 }
             }
         });
+
+        if let Some(handler) = &binding_expression.animation_event_handler {
+            init.push(animation_event_handler_code(&prop_access, handler, ctx));
+        }
     }
 }
 
@@ -578,9 +632,14 @@ pub fn generate(doc: &Document) -> impl std::fmt::Display {
         }
     }
 
-    for ty in doc.root_component.used_types.borrow().structs.iter() {
-        if let Type::Struct { fields, name: Some(name), node: Some(node) } = ty {
-            generate_struct(&mut file, name, fields, node);
+    for ty in doc.root_component.used_types.borrow().structs_and_enums.iter() {
+        match ty {
+            Type::Struct { fields, name: Some(name), node: Some(node), definition } => {
+                let definition = definition.as_ref().map(|d| d.borrow());
+                generate_struct(&mut file, name, fields, node, definition.as_deref());
+            }
+            Type::Enumeration(en) => generate_enum(&mut file, en),
+            _ => (),
         }
     }
 
@@ -643,16 +702,23 @@ fn generate_struct(
     name: &str,
     fields: &BTreeMap<String, Type>,
     node: &syntax_nodes::ObjectType,
+    definition: Option<&StructDefinition>,
 ) {
     let mut members = node
         .ObjectTypeMember()
         .map(|n| crate::parser::identifier_text(&n).unwrap())
         .map(|name| {
+            // Fields without a default are value-initialized, so that a default constructed
+            // struct is well-defined
+            let init = definition
+                .and_then(|d| d.field_defaults.get(&name))
+                .map_or_else(|| "{}".into(), struct_field_default);
             (
                 Access::Public,
                 Declaration::Var(Var {
                     ty: fields.get(&name).unwrap().cpp_type().unwrap(),
                     name: ident(&name),
+                    init: Some(init),
                     ..Default::default()
                 }),
             )
@@ -677,6 +743,51 @@ fn generate_struct(
     }))
 }
 
+fn generate_enum(file: &mut File, en: &Enumeration) {
+    file.declarations.push(Declaration::Enum(Enum {
+        name: ident(&en.name),
+        values: en.values.iter().map(|v| ident(&crate::generator::to_pascal_case(v))).collect(),
+    }))
+}
+
+/// The C++ name of the enum. Builtin enums are declared by the runtime in the
+/// `slint::cbindgen_private` namespace
+fn enumeration_type_name(en: &Enumeration) -> String {
+    if en.node.is_some() {
+        ident(&en.name)
+    } else {
+        format!("slint::cbindgen_private::{}", ident(&en.name))
+    }
+}
+
+/// Return the C++ code for the default value of a struct field, which was folded to a literal
+/// (see [`StructDefinition::field_defaults`])
+fn struct_field_default(expr: &tree_Expression) -> String {
+    match expr {
+        tree_Expression::StringLiteral(s) => {
+            format!(r#"slint::SharedString(u8"{}")"#, escape_string(s.as_str()))
+        }
+        tree_Expression::NumberLiteral(n, unit) => unit.normalize(*n).to_string(),
+        tree_Expression::BoolLiteral(b) => b.to_string(),
+        tree_Expression::EnumerationValue(value) => format!(
+            "{}::{}",
+            enumeration_type_name(&value.enumeration),
+            ident(&value.to_pascal_case()),
+        ),
+        tree_Expression::Cast { from, to: Type::Color } => {
+            format!("slint::Color::from_argb_encoded({})", struct_field_default(from))
+        }
+        tree_Expression::Cast { from, to: Type::Brush } => {
+            format!("slint::Brush({})", struct_field_default(from))
+        }
+        tree_Expression::Cast { from, to: Type::Int32 } => {
+            format!("int({})", struct_field_default(from))
+        }
+        tree_Expression::Cast { from, .. } => struct_field_default(from),
+        _ => "{}".into(),
+    }
+}
+
 /// Generate the component in `file`.
 ///
 /// `sub_components`, if Some, will be filled with all the sub component which needs to be added as friends
@@ -1182,19 +1293,42 @@ fn generate_item_tree(
         "self->self_weak = vtable::VWeak(self_rc).into_dyn();".into(),
     ];
 
-    if parent_ctx.is_none() {
-        create_code.extend([format!(
-            "{}->m_window.window_handle().set_component(*self_rc);",
-            root_access
-        )]);
-    }
+    let window_adapter = match &parent_ctx {
+        None => {
+            create_code.extend([format!(
+                "{}->m_window.window_handle().set_component(*self_rc);",
+                root_access
+            )]);
+            format!("&{}->m_window.window_handle()", root_access)
+        }
+        Some(parent) if parent.repeater_index.is_some() => "parent->window_adapter".into(),
+        Some(_) => {
+            // The popups and the child windows get the window in which they are shown. It's the
+            // first member so that it's destroyed last.
+            create_parameters
+                .push("const slint::private_api::WindowAdapterRc &window_adapter".into());
+            target_struct.members.insert(
+                0,
+                (
+                    Access::Private,
+                    Declaration::Var(Var {
+                        ty: "std::optional<slint::private_api::WindowAdapterRc>".to_owned(),
+                        name: "m_window_adapter".to_owned(),
+                        ..Default::default()
+                    }),
+                ),
+            );
+            create_code.push("self->m_window_adapter.emplace(window_adapter);".into());
+            "&*self->m_window_adapter".into()
+        }
+    };
 
     create_code.extend([
+        format!("({})->register_component(self, self->item_array());", window_adapter),
         format!(
-            "{}->m_window.window_handle().register_component(self, self->item_array());",
-            root_access
+            "self->init({}, {}, self->self_weak, 0, 1 {});",
+            root_access, window_adapter, init_parent_parameters
         ),
-        format!("self->init({}, self->self_weak, 0, 1 {});", root_access, init_parent_parameters),
     ]);
 
     // Repeaters run their user_init() code from Repeater::ensure_updated() after update() initialized model_data/index.
@@ -1223,10 +1357,7 @@ fn generate_item_tree(
 
     let mut destructor = vec!["auto self = this;".to_owned()];
 
-    destructor.push(format!(
-        "{}->m_window.window_handle().unregister_component(self, item_array());",
-        root_access
-    ));
+    destructor.push("self->window_adapter->unregister_component(self, item_array());".into());
 
     target_struct.members.push((
         Access::Public,
@@ -1252,6 +1383,7 @@ fn generate_sub_component(
 
     let mut init_parameters = vec![
         format!("{} root", root_ptr_type),
+        "slint::private_api::WindowAdapterRc *window_adapter".into(),
         "slint::cbindgen_private::ComponentWeak enclosing_component".into(),
         "uintptr_t tree_index".into(),
         "uintptr_t tree_index_of_first_child".into(),
@@ -1275,6 +1407,17 @@ fn generate_sub_component(
     ));
     init.push("self->root = root;".into());
 
+    // The window in which the component is shown, which isn't the one of the root in a ChildWindow
+    target_struct.members.push((
+        field_access,
+        Declaration::Var(Var {
+            ty: "slint::private_api::WindowAdapterRc *".to_owned(),
+            name: "window_adapter".to_owned(),
+            ..Default::default()
+        }),
+    ));
+    init.push("self->window_adapter = window_adapter;".into());
+
     target_struct.members.push((
         field_access,
         Declaration::Var(Var {
@@ -1377,7 +1520,7 @@ fn generate_sub_component(
         };
 
         init.push(format!(
-            "this->{}.init(root, self_weak.into_dyn(), {}, {});",
+            "this->{}.init(root, window_adapter, self_weak.into_dyn(), {}, {});",
             field_name, global_index, global_children
         ));
         user_init.push(format!("this->{}.user_init();", field_name));
@@ -1496,10 +1639,16 @@ fn generate_sub_component(
             let lv_h = access_member(&listview.listview_height, &ctx);
             let vp_w = access_member(&listview.viewport_width, &ctx);
             let lv_w = access_member(&listview.listview_width, &ctx);
+            let first_row = access_member(&listview.first_visible_row, &ctx);
+            let last_row = access_member(&listview.last_visible_row, &ctx);
+            let request_row = access_member(&listview.scroll_request_row, &ctx);
+            let request_alignment = access_member(&listview.scroll_request_alignment, &ctx);
 
             format!(
-                "self->{}.ensure_updated_listview(self, &{}, &{}, &{}, {}.get(), {}.get());",
-                repeater_id, vp_w, vp_h, vp_y, lv_w, lv_h
+                "if (auto row = {request_row}.get(); row >= 0 && self->{repeater_id}.listview_scroll_to(row, {request_alignment}.get(), &{vp_y}, {lv_h}.get())) {{ {request_row}.set(-1); }}
+                auto visible_rows = self->{repeater_id}.ensure_updated_listview(self, &{vp_w}, &{vp_h}, &{vp_y}, {lv_w}.get(), {lv_h}.get());
+                {first_row}.set(visible_rows.start == visible_rows.end ? -1 : int(visible_rows.start));
+                {last_row}.set(int(visible_rows.end) - 1);"
             )
         } else {
             format!("self->{id}.ensure_updated(self);", id = repeater_id)
@@ -1556,6 +1705,24 @@ fn generate_sub_component(
         expr_str
     }));
 
+    for (idx, (prop, handler)) in component.change_callbacks.iter().enumerate() {
+        let tracker_id = format!("change_tracker{}", idx);
+        target_struct.members.push((
+            Access::Private,
+            Declaration::Var(Var {
+                ty: "slint::private_api::ChangeTracker".into(),
+                name: tracker_id.clone(),
+                ..Default::default()
+            }),
+        ));
+        // The change tracker is owned by the component, so `self` outlives it
+        user_init.push(format!(
+            "self->{tracker_id}.init(self, [](auto self) {{ return {value}; }}, [](auto self, auto) {{ {handler}; }});",
+            value = compile_expression(&llr::Expression::PropertyReference(prop.clone()), &ctx),
+            handler = compile_expression(&handler.borrow(), &ctx),
+        ));
+    }
+
     target_struct
         .members
         .extend(generate_functions(&component.functions, &ctx).map(|x| (Access::Public, x)));
@@ -1940,6 +2107,7 @@ fn generate_public_api_for_properties(
     private_properties: &llr::PrivateProperties,
     ctx: &EvaluationContext,
 ) {
+    let mut has_observable_properties = false;
     for p in public_properties {
         let prop_ident = ident(&p.name);
 
@@ -2032,6 +2200,42 @@ fn generate_public_api_for_properties(
                 }),
             ));
 
+            has_observable_properties = true;
+            declarations.push((
+                Access::Public,
+                Declaration::Function(Function {
+                    name: format!("observe_{}", &prop_ident),
+                    template_parameters: Some(format!(
+                        "std::invocable<{}> Functor",
+                        &cpp_property_type
+                    )),
+                    signature: "(Functor && callback) const".into(),
+                    statements: Some(vec![
+                        "auto &tracker = *property_observers.emplace_back(std::make_unique<slint::private_api::ChangeTracker>());".into(),
+                        // The tracker is owned by this, so `this` outlives it
+                        format!(
+                            "tracker.init(this, [this](auto) {{ [[maybe_unused]] auto self = this; return {access}.get(); }}, [callback = std::forward<Functor>(callback)](auto, const auto &value) mutable {{ callback(value); }});"
+                        ),
+                    ]),
+                    ..Default::default()
+                }),
+            ));
+
+            if p.is_animated {
+                declarations.push((
+                    Access::Public,
+                    Declaration::Function(Function {
+                        name: format!("get_{}_animation", &prop_ident),
+                        signature: "() const -> std::optional<slint::AnimationController>".into(),
+                        statements: Some(vec![
+                            "[[maybe_unused]] auto self = this;".into(),
+                            format!("return {}.animation_controller();", access),
+                        ]),
+                        ..Default::default()
+                    }),
+                ));
+            }
+
             if !p.read_only {
                 let prop_setter: Vec<String> = vec![
                     "[[maybe_unused]] auto self = this;".into(),
@@ -2097,8 +2301,30 @@ fn generate_public_api_for_properties(
                     ..Default::default()
                 }),
             ));
+            declarations.push((
+                Access::Private,
+                Declaration::Function(Function {
+                    name: format!("observe_{prop_ident}"),
+                    signature: format!(
+                        "(auto &&) const = delete /* the property '{name}' is declared as private. Declare it as 'in', 'out', or 'in-out' to make it public */",
+                    ),
+                    ..Default::default()
+                }),
+            ));
         }
     }
+
+    if has_observable_properties {
+        declarations.push((
+            Access::Private,
+            Declaration::Var(Var {
+                ty: "mutable std::vector<std::unique_ptr<slint::private_api::ChangeTracker>>"
+                    .into(),
+                name: "property_observers".into(),
+                ..Default::default()
+            }),
+        ));
+    }
 }
 
 fn follow_sub_component_path<'a>(
@@ -2116,8 +2342,13 @@ fn follow_sub_component_path<'a>(
 }
 
 fn access_window_field(ctx: &EvaluationContext) -> String {
-    let root = &ctx.generator_state;
-    format!("{}->window().window_handle()", root)
+    if ctx.current_sub_component.is_some() {
+        // Not the one of the root, as the components of a ChildWindow have their own window
+        "(*self->window_adapter)".into()
+    } else {
+        let root = &ctx.generator_state;
+        format!("{}->window().window_handle()", root)
+    }
 }
 
 /// Returns the code that can access the given property (but without the set or get)
@@ -2326,6 +2557,22 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 compile_expression(array, ctx), compile_expression(index, ctx)
             )
         },
+        Expression::ArrayOperation { op, array, element, index, body } => {
+            let param = |name: &str| if body.reads_local_variable(name) { ident(name) } else { String::new() };
+            let lambda = format!(
+                "[&](const auto &{}, int {}) {{ return {}; }}",
+                param(element), param(index), compile_expression(body, ctx)
+            );
+            let function = match op {
+                ArrayOperation::Filter => "array_filter".into(),
+                ArrayOperation::Map => format!("array_map<{}>", body.ty(ctx).cpp_type().unwrap()),
+                ArrayOperation::Any => "array_any".into(),
+                ArrayOperation::All => "array_all".into(),
+                ArrayOperation::Sum => format!("array_sum<{}>", body.ty(ctx).cpp_type().unwrap()),
+                ArrayOperation::IndexOf => "array_index_of".into(),
+            };
+            format!("slint::private_api::{}({}, {})", function, compile_expression(array, ctx), lambda)
+        }
         Expression::Cast { from, to } => {
             let f = compile_expression(&*from, ctx);
             match (from.ty(ctx), to) {
@@ -2345,6 +2592,12 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 (Type::Brush, Type::Color) => {
                     format!("{}.color()", f)
                 }
+                (_, Type::DateTime) => {
+                    format!("slint::DateTime::from_timestamp_millis({})", f)
+                }
+                (Type::DateTime, Type::Duration) => {
+                    format!("{}.timestamp_millis()", f)
+                }
                 (Type::Struct { .. }, Type::Struct{ fields, name: Some(_), ..}) => {
                     format!(
                         "[&](const auto &o){{ {struct_name} s; {fields} return s; }}({obj})",
@@ -2563,6 +2816,23 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::CubicBezier, {}, {}, {}, {})",
             a, b, c, d
         ),
+        Expression::EasingCurve(EasingCurve::EaseInElastic) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInElastic)".into(),
+        Expression::EasingCurve(EasingCurve::EaseOutElastic) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic)".into(),
+        Expression::EasingCurve(EasingCurve::EaseInOutElastic) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic)".into(),
+        Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInBounce)".into(),
+        Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseOutBounce)".into(),
+        Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInOutBounce)".into(),
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::Spring, {}, {}, {})",
+            stiffness, damping, mass
+        ),
+        Expression::EasingCurve(EasingCurve::Keyframes(keyframes)) => format!(
+            r#"[]{{
+                static constexpr slint::cbindgen_private::EasingKeyframe keyframes[] = {{ {} }};
+                return slint::cbindgen_private::EasingCurve(keyframes, std::size(keyframes));
+            }}()"#,
+            keyframes.iter().map(|(position, progress)| format!("{{ {}, {} }}", position, progress)).join(", ")
+        ),
         Expression::LinearGradient{angle, stops} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
@@ -2588,8 +2858,8 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         }
         Expression::EnumerationValue(value) => {
             format!(
-                "slint::cbindgen_private::{}::{}",
-                value.enumeration.name,
+                "{}::{}",
+                enumeration_type_name(&value.enumeration),
                 ident(&value.to_pascal_case()),
             )
         }
@@ -2659,6 +2929,9 @@ fn compile_builtin_function_call(
             format!("{}->{}.default_font_size.get()", ctx.generator_state, window_item_name)
         }
         BuiltinFunction::AnimationTick => "slint::cbindgen_private::slint_animation_tick()".into(),
+        BuiltinFunction::Translate => {
+            format!("slint::private_api::translate({})", a.join(", "))
+        }
         BuiltinFunction::Debug => {
             format!("std::cout << {} << std::endl;", a.join("<<"))
         }
@@ -2704,6 +2977,49 @@ fn compile_builtin_function_call(
         BuiltinFunction::StringToFloat => {
             format!("[](const auto &a){{ auto e1 = std::end(a); auto e2 = const_cast<char*>(e1); auto r = std::strtod(std::begin(a), &e2); return e1 == e2 ? r : 0; }}({})", a.next().unwrap())
         }
+        BuiltinFunction::StringToUppercase => {
+            format!("slint::private_api::string_to_uppercase({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringToLowercase => {
+            format!("slint::private_api::string_to_lowercase({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringTrim => {
+            format!("slint::private_api::string_trim({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringContains => {
+            format!(
+                "(std::string_view({}).find(std::string_view({})) != std::string_view::npos)",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::StringStartsWith => {
+            format!("slint::SharedString({}).starts_with({})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringSplit => {
+            format!("slint::private_api::string_split({})", a.join(", "))
+        }
+        BuiltinFunction::StringCharacterCount => {
+            format!("slint::private_api::string_character_count({})", a.next().unwrap())
+        }
+        BuiltinFunction::FormatNumber => {
+            format!("slint::private_api::format_number({})", a.join(", "))
+        }
+        BuiltinFunction::DateTimeFromTimestamp => {
+            format!("slint::DateTime::from_timestamp_millis({})", a.next().unwrap())
+        }
+        BuiltinFunction::DateTimeFromComponents => {
+            format!("slint::DateTime::from_components({})", a.join(", "))
+        }
+        BuiltinFunction::DateTimeComponent(component) => {
+            format!("{}.{}()", a.next().unwrap(), ident(component.name()))
+        }
+        BuiltinFunction::DateTimeTimestamp => {
+            format!("{}.timestamp_millis()", a.next().unwrap())
+        }
+        BuiltinFunction::DateTimeToIsoString => {
+            format!("{}.to_iso_string()", a.next().unwrap())
+        }
         BuiltinFunction::ColorBrighter => {
             format!("{}.brighter({})", a.next().unwrap(), a.next().unwrap())
         }
@@ -2734,8 +3050,16 @@ fn compile_builtin_function_call(
         BuiltinFunction::TextInputFocused => {
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
+        BuiltinFunction::ClipboardText => "slint::private_api::clipboard_text()".into(),
+        BuiltinFunction::SetClipboardText => {
+            format!("slint::private_api::set_clipboard_text({})", a.next().unwrap())
+        }
+        BuiltinFunction::ClipboardImage => "slint::private_api::clipboard_image()".into(),
+        BuiltinFunction::SetClipboardImage => {
+            format!("slint::private_api::set_clipboard_image({})", a.next().unwrap())
+        }
         BuiltinFunction::ShowPopupWindow => {
-            if let [llr::Expression::NumberLiteral(popup_index), x, y, llr::Expression::PropertyReference(parent_ref)] =
+            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_policy, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2755,13 +3079,53 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
+                let close_policy = compile_expression(close_policy, ctx);
                 format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {{ {parent_component} }})"
+                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_policy}, {{ {parent_component} }})"
                 )
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
+        BuiltinFunction::ShowChildWindow => {
+            if let [llr::Expression::NumberLiteral(index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut parent_ctx = ctx;
+                let mut component_access = "self".into();
+
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access = format!("{}->parent", component_access);
+                        parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
+                    }
+                };
+
+                let window = access_window_field(ctx);
+                let current_sub_component = parent_ctx.current_sub_component.unwrap();
+                let child_window_id =
+                    ident(&current_sub_component.popup_windows[*index as usize].root.name);
+                let parent_item = access_item_rc(parent_ref, ctx);
+                let index = *index as usize;
+                format!(
+                    "{window}.show_child_window<{child_window_id}>({component_access}, {index}, {{ {parent_item} }})"
+                )
+            } else {
+                panic!("internal error: invalid args to ShowChildWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::HideChildWindow => {
+            if let [llr::Expression::NumberLiteral(index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let window = access_window_field(ctx);
+                let parent_item = access_item_rc(parent_ref, ctx);
+                let index = *index as usize;
+                format!("{window}.hide_child_window({index}, {{ {parent_item} }})")
+            } else {
+                panic!("internal error: invalid args to HideChildWindow {:?}", arguments)
+            }
+        }
         BuiltinFunction::RegisterCustomFontByPath => {
             if let [llr::Expression::StringLiteral(path)] = arguments {
                 let window = access_window_field(ctx);
@@ -12,8 +12,9 @@
     this is usually a local variable to the init code that shouldn't rbe relied upon by the binding code.
 */
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve, OperatorClass};
-use crate::langtype::{ElementType, Type};
+use crate::expression_tree::Expression as tree_Expression;
+use crate::expression_tree::{ArrayOperation, BuiltinFunction, EasingCurve, OperatorClass};
+use crate::langtype::{ElementType, Enumeration, StructDefinition, Type};
 use crate::layout::Orientation;
 use crate::llr::{
     self, EvaluationContext as llr_EvaluationContext, Expression, ParentCtx as llr_ParentCtx,
@@ -74,6 +75,7 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
         Type::String => Some(quote!(slint::private_unstable_api::re_exports::SharedString)),
         Type::Color => Some(quote!(slint::private_unstable_api::re_exports::Color)),
         Type::Duration => Some(quote!(i64)),
+        Type::DateTime => Some(quote!(slint::private_unstable_api::re_exports::DateTime)),
         Type::Angle => Some(quote!(f32)),
         Type::PhysicalLength => Some(quote!(slint::private_unstable_api::re_exports::Coord)),
         Type::LogicalLength => Some(quote!(slint::private_unstable_api::re_exports::Coord)),
@@ -91,10 +93,7 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
             let inner = rust_primitive_type(o)?;
             Some(quote!(slint::private_unstable_api::re_exports::ModelRc<#inner>))
         }
-        Type::Enumeration(e) => {
-            let e = ident(&e.name);
-            Some(quote!(slint::private_unstable_api::re_exports::#e))
-        }
+        Type::Enumeration(e) => Some(enumeration_to_tokens(e)),
         Type::Brush => Some(quote!(slint::Brush)),
         Type::LayoutCache => Some(quote!(
             slint::private_unstable_api::re_exports::SharedVector<
@@ -132,18 +131,19 @@ fn set_primitive_property_value(ty: &Type, value_expression: TokenStream) -> Tok
 
 /// Generate the rust code for the given component.
 pub fn generate(doc: &Document) -> TokenStream {
-    let (structs_ids, structs): (Vec<_>, Vec<_>) = doc
+    let (structs_and_enums_ids, structs_and_enums): (Vec<_>, Vec<_>) = doc
         .root_component
         .used_types
         .borrow()
-        .structs
+        .structs_and_enums
         .iter()
-        .filter_map(|ty| {
-            if let Type::Struct { fields, name: Some(name), node: Some(_) } = ty {
-                Some((ident(name), generate_struct(name, fields)))
-            } else {
-                None
+        .filter_map(|ty| match ty {
+            Type::Struct { fields, name: Some(name), node: Some(_), definition } => {
+                let definition = definition.as_ref().map(|d| d.borrow());
+                Some((ident(name), generate_struct(name, fields, definition.as_deref())))
             }
+            Type::Enumeration(en) => Some((ident(&en.name), generate_enum(en))),
+            _ => None,
         })
         .unzip();
 
@@ -307,14 +307,14 @@ pub fn generate(doc: &Document) -> TokenStream {
                                           // according to clippy!
         mod #compo_module {
             use slint::private_unstable_api::re_exports::*;
-            #(#structs)*
+            #(#structs_and_enums)*
             #(#globals)*
             #(#sub_compos)*
             #compo
             #(#resource_symbols)*
             const _THE_SAME_VERSION_MUST_BE_USED_FOR_THE_COMPILER_AND_THE_RUNTIME : slint::#version_check = slint::#version_check;
         }
-        pub use #compo_module::{#compo_id #(,#structs_ids)* #(,#globals_ids)* };
+        pub use #compo_module::{#compo_id #(,#structs_and_enums_ids)* #(,#globals_ids)* };
         pub use slint::{ComponentHandle as _, Global as _, ModelExt as _};
     }
 }
@@ -324,8 +324,13 @@ fn generate_public_component(llr: &llr::PublicComponent) -> TokenStream {
     let inner_component_id = inner_component_id(&llr.item_tree.root);
     let global_container_id = format_ident!("Globals_{}", public_component_id);
 
-    let component =
-        generate_item_tree(&llr.item_tree, llr, None, quote!(globals: #global_container_id), None);
+    let component = generate_item_tree(
+        &llr.item_tree,
+        llr,
+        None,
+        quote!(globals: #global_container_id, property_observers: ::core::cell::RefCell<slint::private_unstable_api::re_exports::Vec<slint::private_unstable_api::re_exports::ChangeTracker>>),
+        None,
+    );
 
     let ctx = EvaluationContext {
         public_component: llr,
@@ -420,16 +425,96 @@ fn default() -> Self {
     )
 }
 
-fn generate_struct(name: &str, fields: &BTreeMap<String, Type>) -> TokenStream {
+fn generate_struct(
+    name: &str,
+    fields: &BTreeMap<String, Type>,
+    definition: Option<&StructDefinition>,
+) -> TokenStream {
     let component_id = struct_name_to_tokens(name);
     let (declared_property_vars, declared_property_types): (Vec<_>, Vec<_>) =
         fields.iter().map(|(name, ty)| (ident(name), rust_primitive_type(ty).unwrap())).unzip();
 
+    let Some(field_defaults) = definition.map(|d| &d.field_defaults).filter(|d| !d.is_empty())
+    else {
+        return quote! {
+            #[derive(Default, PartialEq, Debug, Clone)]
+            pub struct #component_id {
+                #(pub #declared_property_vars : #declared_property_types),*
+            }
+        };
+    };
+
+    let default_values = fields.keys().map(|name| {
+        field_defaults.get(name).map_or_else(|| quote!(Default::default()), struct_field_default)
+    });
     quote! {
-        #[derive(Default, PartialEq, Debug, Clone)]
+        #[derive(PartialEq, Debug, Clone)]
         pub struct #component_id {
             #(pub #declared_property_vars : #declared_property_types),*
         }
+        impl Default for #component_id {
+            fn default() -> Self {
+                Self { #(#declared_property_vars : #default_values),* }
+            }
+        }
+    }
+}
+
+fn generate_enum(en: &Enumeration) -> TokenStream {
+    let enum_name = ident(&en.name);
+    let enum_values = en.values.iter().enumerate().map(|(i, value)| {
+        let value = ident(&crate::generator::to_pascal_case(value));
+        if i == en.default_value {
+            quote!(#[default] #value)
+        } else {
+            quote!(#value)
+        }
+    });
+    quote! {
+        #[derive(Default, Copy, Clone, PartialEq, Eq, Debug, Hash)]
+        pub enum #enum_name {
+            #(#enum_values,)*
+        }
+    }
+}
+
+/// The path of the rust type of the enum. Builtin enums are re-exported by the runtime
+fn enumeration_to_tokens(en: &Enumeration) -> TokenStream {
+    let name = ident(&en.name);
+    if en.node.is_some() {
+        quote!(#name)
+    } else {
+        quote!(slint::private_unstable_api::re_exports::#name)
+    }
+}
+
+/// Return the tokens for the default value of a struct field, which was folded to a literal
+/// (see [`StructDefinition::field_defaults`])
+fn struct_field_default(expr: &tree_Expression) -> TokenStream {
+    match expr {
+        tree_Expression::StringLiteral(s) => {
+            quote!(slint::private_unstable_api::re_exports::SharedString::from(#s))
+        }
+        tree_Expression::NumberLiteral(n, unit) => {
+            let n = unit.normalize(*n);
+            quote!(#n as _)
+        }
+        tree_Expression::BoolLiteral(b) => quote!(#b),
+        tree_Expression::EnumerationValue(value) => {
+            let base = enumeration_to_tokens(&value.enumeration);
+            let value_ident = ident(&value.to_pascal_case());
+            quote!(#base::#value_ident)
+        }
+        tree_Expression::Cast { from, to: Type::Color } => {
+            let f = struct_field_default(from);
+            quote!(slint::private_unstable_api::re_exports::Color::from_argb_encoded(#f))
+        }
+        tree_Expression::Cast { from, to: Type::Brush } => {
+            let f = struct_field_default(from);
+            quote!(slint::Brush::SolidColor(#f))
+        }
+        tree_Expression::Cast { from, .. } => struct_field_default(from),
+        _ => quote!(Default::default()),
     }
 }
 
@@ -537,9 +622,36 @@ fn handle_property_init(
                 }
             }
         });
+
+        if let Some(handler) = &binding_expression.animation_event_handler {
+            init.push(animation_event_handler_tokens(&rust_property, handler, ctx));
+        }
     }
 }
 
+/// Returns the code that installs the `animation-started` and `animation-finished` handlers
+/// on the animation controller of the property. `self_rc` must be in scope.
+fn animation_event_handler_tokens(
+    rust_property: &TokenStream,
+    handler: &llr::AnimationEventHandler,
+    ctx: &EvaluationContext,
+) -> TokenStream {
+    let init_self_pin_ref = if ctx.current_global.is_some() {
+        quote!(let _self = self_rc.as_ref();)
+    } else {
+        quote!(let _self = self_rc.as_pin_ref();)
+    };
+    let started = compile_expression(&handler.started, ctx);
+    let finished = compile_expression(&handler.finished, ctx);
+    quote!({
+        #[allow(unreachable_code, unused)]
+        slint::private_unstable_api::set_animation_event_handler(#rust_property, &self_rc, move |self_rc, event| {
+            #init_self_pin_ref
+            if event == slint::private_unstable_api::re_exports::AnimationEvent::Started { #started; } else { #finished; }
+        });
+    })
+}
+
 /// Public API for Global and root component
 fn public_api(
     public_properties: &llr::PublicProperties,
@@ -597,7 +709,7 @@ pub fn #caller_ident(&self, #(#args_name : #callback_args,)*) -> #return_type {
 
             let getter_ident = format_ident!("get_{}", prop_ident);
 
-            let prop_expression = primitive_property_value(&p.ty, prop);
+            let prop_expression = primitive_property_value(&p.ty, prop.clone());
 
             property_and_callback_accessors.push(quote!(
                 #[allow(dead_code)]
@@ -608,6 +720,37 @@ pub fn #getter_ident(&self) -> #rust_property_type {
                 }
             ));
 
+            let observe_ident = format_ident!("observe_{}", prop_ident);
+            let (strong_ref, pin_ref) = if ctx.current_global.is_some() {
+                (quote!(self.0), quote!(self_rc.as_ref()))
+            } else {
+                (quote!(&self.0), quote!(vtable::VRc::as_pin_ref(&self_rc)))
+            };
+            property_and_callback_accessors.push(quote!(
+                #[allow(dead_code)]
+                pub fn #observe_ident(&self, f: impl FnMut(#rust_property_type) + 'static) {
+                    #[allow(unused_imports)]
+                    let _self = #self_init;
+                    slint::private_unstable_api::observe_property(&_self.property_observers, #strong_ref, |self_rc| {
+                        #[allow(unused_imports)]
+                        let _self = #pin_ref;
+                        #prop_expression
+                    }, f)
+                }
+            ));
+
+            if p.is_animated {
+                let animation_getter_ident = format_ident!("get_{}_animation", prop_ident);
+                property_and_callback_accessors.push(quote!(
+                    #[allow(dead_code)]
+                    pub fn #animation_getter_ident(&self) -> Option<slint::AnimationController> {
+                        #[allow(unused_imports)]
+                        let _self = #self_init;
+                        #prop.animation_controller()
+                    }
+                ));
+            }
+
             let setter_ident = format_ident!("set_{}", prop_ident);
             if !p.read_only {
                 let set_value = property_set_value_tokens(&p.prop, quote!(value), ctx);
@@ -637,9 +780,11 @@ pub fn #setter_ident(&self, value: #rust_property_type) {
         } else {
             let getter_ident = format_ident!("get_{}", prop_ident);
             let setter_ident = format_ident!("set_{}", prop_ident);
+            let observe_ident = format_ident!("observe_{}", prop_ident);
             property_and_callback_accessors.push(quote!(
                 #[allow(dead_code)] fn #getter_ident(&self, _private_property: ()) {}
                 #[allow(dead_code)] fn #setter_ident(&self, _private_property: ()) {}
+                #[allow(dead_code)] fn #observe_ident(&self, _private_property: ()) {}
             ));
         }
     }
@@ -769,12 +914,25 @@ fn generate_sub_component(
             let lv_h = access_member(&listview.listview_height, &ctx);
             let vp_w = access_member(&listview.viewport_width, &ctx);
             let lv_w = access_member(&listview.listview_width, &ctx);
+            let first_row = access_member(&listview.first_visible_row, &ctx);
+            let last_row = access_member(&listview.last_visible_row, &ctx);
+            let request_row = access_member(&listview.scroll_request_row, &ctx);
+            let request_alignment = access_member(&listview.scroll_request_alignment, &ctx);
 
             quote! {
-                #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_listview(
+                let repeater = #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self);
+                let scroll_request_row = #request_row.get();
+                if scroll_request_row >= 0
+                    && repeater.listview_scroll_to(scroll_request_row as usize, #request_alignment.get(), #vp_y, #lv_h.get())
+                {
+                    #request_row.set(-1);
+                }
+                let visible_rows = repeater.ensure_updated_listview(
                     || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).into() },
                     #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h
                 );
+                #first_row.set(if visible_rows.is_empty() { -1 } else { visible_rows.start as i32 });
+                #last_row.set(visible_rows.end as i32 - 1);
             }
         } else {
             quote! {
@@ -848,6 +1006,7 @@ fn generate_sub_component(
         init.push(quote!(#sub_component_id::init(
             VRcMapped::map(self_rc.clone(), |x| #sub_compo_field.apply_pin(x)),
             &#root_ref_tokens,
+            _self.window_adapter.get().unwrap(),
             #global_index, #global_children
         );));
         user_init_code.push(quote!(#sub_component_id::user_init(
@@ -937,6 +1096,26 @@ fn generate_sub_component(
         quote!(#code;)
     }));
 
+    let change_tracker_names = (0..component.change_callbacks.len())
+        .map(|i| format_ident!("change_tracker{}", i))
+        .collect::<Vec<_>>();
+    user_init_code.extend(component.change_callbacks.iter().zip(&change_tracker_names).map(
+        |((prop, handler), tracker)| {
+            let value = compile_expression(&Expression::PropertyReference(prop.clone()), &ctx);
+            let handler = compile_expression(&handler.borrow(), &ctx);
+            quote!({
+                #[allow(unreachable_code, unused)]
+                slint::private_unstable_api::init_change_tracker(&_self.#tracker, &self_rc, |self_rc| {
+                    let _self = self_rc.as_pin_ref();
+                    #value
+                }, |self_rc| {
+                    let _self = self_rc.as_pin_ref();
+                    #handler;
+                });
+            })
+        },
+    ));
+
     let layout_info_h = compile_expression(&component.layout_info_h.borrow(), &ctx);
     let layout_info_v = compile_expression(&component.layout_info_v.borrow(), &ctx);
 
@@ -968,6 +1147,7 @@ struct #inner_component_id {
             #(#declared_property_vars : slint::private_unstable_api::re_exports::Property<#declared_property_types>,)*
             #(#declared_callbacks : slint::private_unstable_api::re_exports::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             #(#repeated_element_names : slint::private_unstable_api::re_exports::Repeater<#repeated_element_components>,)*
+            #(#change_tracker_names : slint::private_unstable_api::re_exports::ChangeTracker,)*
             self_weak : slint::private_unstable_api::re_exports::OnceCell<slint::private_unstable_api::re_exports::VWeakMapped<slint::private_unstable_api::re_exports::ComponentVTable, #inner_component_id>>,
             #(parent : #parent_component_type,)*
             // FIXME: Do we really need a window all the time?
@@ -981,13 +1161,14 @@ struct #inner_component_id {
         impl #inner_component_id {
             pub fn init(self_rc: slint::private_unstable_api::re_exports::VRcMapped<slint::private_unstable_api::re_exports::ComponentVTable, Self>,
                     root : &slint::private_unstable_api::re_exports::VRc<slint::private_unstable_api::re_exports::ComponentVTable, #root_component_id>,
+                    window_adapter: &slint::private_unstable_api::re_exports::Rc<dyn slint::private_unstable_api::re_exports::WindowAdapter>,
                     tree_index: u32, tree_index_of_first_child: u32) {
                 #![allow(unused)]
                 #![allow(unused)]
                 let _self = self_rc.as_pin_ref();
                 _self.self_weak.set(VRcMapped::downgrade(&self_rc));
                 _self.root.set(VRc::downgrade(root));
-                _self.window_adapter.set(root.window_adapter.get().unwrap().clone());
+                _self.window_adapter.set(window_adapter.clone());
                 _self.tree_index.set(tree_index);
                 _self.tree_index_of_first_child.set(tree_index_of_first_child);
                 #(#init)*
@@ -1211,6 +1392,7 @@ struct #inner_component_id {
             #(#declared_property_vars: slint::private_unstable_api::re_exports::Property<#declared_property_types>,)*
             #(#declared_callbacks: slint::private_unstable_api::re_exports::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             root : slint::private_unstable_api::re_exports::OnceCell<slint::private_unstable_api::re_exports::VWeak<slint::private_unstable_api::re_exports::ComponentVTable, #root_component_id>>,
+            property_observers: ::core::cell::RefCell<slint::private_unstable_api::re_exports::Vec<slint::private_unstable_api::re_exports::ChangeTracker>>,
         }
 
         impl #inner_component_id {
@@ -1262,7 +1444,11 @@ fn generate_item_tree(
         parent_ctx
     {
         (
-            None,
+            // The popups and the child windows get the window adapter in which they are shown as
+            // parameter, the repeated components use the one of their parent.
+            parent_ctx.repeater_index.is_some().then(|| {
+                quote!(let window_adapter = parent.upgrade().unwrap().window_adapter.get().unwrap().clone();)
+            }),
             None,
             quote!(vtable::VRc<slint::private_unstable_api::re_exports::ComponentVTable, Self>),
             if parent_ctx.repeater_index.is_some() {
@@ -1281,8 +1467,7 @@ fn generate_item_tree(
                 quote!(let window_adapter = slint::private_unstable_api::create_window_adapter()?;),
             ),
             Some(quote! {
-                _self.window_adapter.set(window_adapter);
-                slint::private_unstable_api::re_exports::WindowInner::from_pub(_self.window_adapter.get().unwrap().window()).set_component(&VRc::into_dyn(self_rc.clone()));
+                slint::private_unstable_api::re_exports::WindowInner::from_pub(window_adapter.window()).set_component(&VRc::into_dyn(self_rc.clone()));
             }),
             quote!(
                 core::result::Result<
@@ -1294,6 +1479,10 @@ fn generate_item_tree(
         )
     };
 
+    let window_adapter_parameter = parent_ctx.filter(|p| p.repeater_index.is_none()).map(|_| {
+        quote!(window_adapter: slint::private_unstable_api::re_exports::Rc<dyn slint::private_unstable_api::re_exports::WindowAdapter>)
+    });
+
     let parent_item_expression = parent_ctx.and_then(|parent| {
         parent.repeater_index.map(|idx| {
             let sub_component_offset = parent.ctx.current_sub_component.unwrap().repeated[idx].index_in_tree;
@@ -1363,7 +1552,7 @@ fn generate_item_tree(
         #sub_comp
 
         impl #inner_component_id {
-            pub fn new(#(parent: #parent_component_type)*) -> #new_result {
+            pub fn new(#(parent: #parent_component_type,)* #window_adapter_parameter) -> #new_result {
                 #![allow(unused)]
                 #create_window_adapter // We must create the window first to initialize the backend before using the style
                 let mut _self = Self::default();
@@ -1371,8 +1560,8 @@ pub fn new(#(parent: #parent_component_type)*) -> #new_result {
                 let self_rc = VRc::new(_self);
                 let _self = self_rc.as_pin_ref();
                 #init_window
-                slint::private_unstable_api::re_exports::register_component(_self, Self::item_array(), #root_token.window_adapter.get().unwrap());
-                Self::init(slint::private_unstable_api::re_exports::VRc::map(self_rc.clone(), |x| x), #root_token, 0, 1);
+                slint::private_unstable_api::re_exports::register_component(_self, Self::item_array(), &window_adapter);
+                Self::init(slint::private_unstable_api::re_exports::VRc::map(self_rc.clone(), |x| x), #root_token, &window_adapter, 0, 1);
                 #new_end
             }
 
@@ -1601,7 +1790,21 @@ fn property_set_value_tokens(
     let value_tokens = set_primitive_property_value(prop_type, value_tokens);
     if let Some(animation) = ctx.current_sub_component.and_then(|c| c.animations.get(property)) {
         let animation_tokens = compile_expression(animation, ctx);
-        return quote!(#prop.set_animated_value(#value_tokens as _, #animation_tokens));
+        let set_value = quote!(#prop.set_animated_value(#value_tokens as _, #animation_tokens));
+        return match ctx
+            .current_sub_component
+            .and_then(|c| c.animation_event_handlers.get(property))
+        {
+            Some(handler) => {
+                let handler_tokens = animation_event_handler_tokens(&prop, handler, ctx);
+                quote!({
+                    #set_value;
+                    let self_rc = _self.self_weak.get().unwrap().upgrade().unwrap();
+                    #handler_tokens
+                })
+            }
+            None => set_value,
+        };
     }
     quote!(#prop.set(#value_tokens as _))
 }
@@ -1768,8 +1971,13 @@ fn follow_sub_component_path<'a>(
 }
 
 fn access_window_adapter_field(ctx: &EvaluationContext) -> TokenStream {
-    let root = &ctx.generator_state;
-    quote!(#root.window_adapter.get().unwrap())
+    if ctx.current_sub_component.is_some() {
+        // Not the one of the root, as the components of a ChildWindow have their own window
+        quote!(_self.window_adapter.get().unwrap())
+    } else {
+        let root = &ctx.generator_state;
+        quote!(#root.window_adapter.get().unwrap())
+    }
 }
 
 /// Given a property reference to a native item (eg, the property name is empty)
@@ -1837,6 +2045,12 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 (Type::Brush, Type::Color) => {
                     quote!(#f.color())
                 }
+                (_, Type::DateTime) => {
+                    quote!(slint::private_unstable_api::re_exports::DateTime::from_timestamp_millis((#f) as i64))
+                }
+                (Type::DateTime, Type::Duration) => {
+                    quote!((#f).timestamp_millis())
+                }
                 (Type::Struct { ref fields, .. }, Type::Struct { name: Some(n), .. }) => {
                     let fields = fields.iter().enumerate().map(|(index, (name, _))| {
                         let index = proc_macro2::Literal::usize_unsuffixed(index);
@@ -1954,6 +2168,37 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 x.row_data_tracked(index).unwrap_or_default()
             }})
         }
+        Expression::ArrayOperation { op, array, element, index, body } => {
+            let array_e = compile_expression(array, ctx);
+            let param = |name: &str| {
+                if body.reads_local_variable(name) {
+                    let name = ident(name);
+                    quote!(#name)
+                } else {
+                    quote!(_)
+                }
+            };
+            let (element_p, index_p) = (param(element), param(index));
+            let body_e = compile_expression(body, ctx);
+            let function = match op {
+                ArrayOperation::Filter => quote!(array_filter),
+                ArrayOperation::Map => {
+                    let ty = rust_primitive_type(&body.ty(ctx)).unwrap();
+                    quote!(array_map::<_, #ty>)
+                }
+                ArrayOperation::Any => quote!(array_any),
+                ArrayOperation::All => quote!(array_all),
+                ArrayOperation::Sum => quote!(array_sum),
+                ArrayOperation::IndexOf => quote!(array_index_of),
+            };
+            if *op == ArrayOperation::Sum {
+                let ty = rust_primitive_type(&body.ty(ctx)).unwrap();
+                let lambda = quote!(|#element_p, #index_p| (#body_e) as f64);
+                quote!((slint::private_unstable_api::array_sum(#array_e, #lambda) as #ty))
+            } else {
+                quote!(slint::private_unstable_api::#function(#array_e, |#element_p, #index_p| #body_e))
+            }
+        }
         Expression::CodeBlock(sub) => {
             let map = sub.iter().map(|e| compile_expression(e, ctx));
             quote!({ #(#map);* })
@@ -2138,6 +2383,36 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::CubicBezier(a, b, c, d)) => {
             quote!(slint::private_unstable_api::re_exports::EasingCurve::CubicBezier([#a, #b, #c, #d]))
         }
+        Expression::EasingCurve(EasingCurve::EaseInElastic) => {
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::EaseInElastic)
+        }
+        Expression::EasingCurve(EasingCurve::EaseOutElastic) => {
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::EaseOutElastic)
+        }
+        Expression::EasingCurve(EasingCurve::EaseInOutElastic) => {
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::EaseInOutElastic)
+        }
+        Expression::EasingCurve(EasingCurve::EaseInBounce) => {
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::EaseInBounce)
+        }
+        Expression::EasingCurve(EasingCurve::EaseOutBounce) => {
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::EaseOutBounce)
+        }
+        Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::EaseInOutBounce)
+        }
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => {
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::Spring([#stiffness, #damping, #mass]))
+        }
+        Expression::EasingCurve(EasingCurve::Keyframes(keyframes)) => {
+            let keyframes = keyframes.iter().map(|(position, progress)| {
+                quote!(slint::private_unstable_api::re_exports::EasingKeyframe { position: #position, progress: #progress })
+            });
+            // The keyframes are constants, so the array is promoted to a 'static
+            quote!(slint::private_unstable_api::re_exports::EasingCurve::Keyframes(
+                slint::private_unstable_api::re_exports::Slice::from_slice(&[#(#keyframes),*])
+            ))
+        }
         Expression::LinearGradient { angle, stops } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
@@ -2160,9 +2435,9 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             ))
         }
         Expression::EnumerationValue(value) => {
-            let base_ident = ident(&value.enumeration.name);
+            let base = enumeration_to_tokens(&value.enumeration);
             let value_ident = ident(&value.to_pascal_case());
-            quote!(slint::private_unstable_api::re_exports::#base_ident::#value_ident)
+            quote!(#base::#value_ident)
         }
         Expression::ReturnStatement(expr) => {
             let return_expr = expr.as_ref().map(|expr| compile_expression(expr, ctx));
@@ -2231,7 +2506,7 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [Expression::NumberLiteral(popup_index), x, y, Expression::PropertyReference(parent_ref)] =
+            if let [Expression::NumberLiteral(popup_index), x, y, close_policy, Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2250,11 +2525,13 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
+                let close_policy = compile_expression(close_policy, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
                 quote!(
                     slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
-                        &VRc::into_dyn(#popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone()).into()),
+                        &VRc::into_dyn(#popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone(), #window_adapter_tokens.clone()).into()),
                         Point::new(#x as slint::private_unstable_api::re_exports::Coord, #y as slint::private_unstable_api::re_exports::Coord),
+                        #close_policy,
                         #parent_component
                     )
                 )
@@ -2262,6 +2539,54 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
+        BuiltinFunction::ShowChildWindow => {
+            if let [Expression::NumberLiteral(index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut parent_ctx = ctx;
+                let mut component_access_tokens = quote!(_self);
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access_tokens =
+                            quote!(#component_access_tokens.parent.upgrade().unwrap().as_pin_ref());
+                        parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
+                    }
+                }
+                let current_sub_component = parent_ctx.current_sub_component.unwrap();
+                let child_window_id =
+                    inner_component_id(&current_sub_component.popup_windows[*index as usize].root);
+                let parent_item = access_item_rc(parent_ref, ctx);
+                let index = *index as usize;
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                quote!(
+                    slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).show_child_window(
+                        #index,
+                        #parent_item,
+                        || {
+                            let window_adapter = slint::private_unstable_api::create_window_adapter()?;
+                            let component = #child_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone(), window_adapter.clone());
+                            Ok((window_adapter, VRc::into_dyn(component.into())))
+                        },
+                    )
+                )
+            } else {
+                panic!("internal error: invalid args to ShowChildWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::HideChildWindow => {
+            if let [Expression::NumberLiteral(index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let parent_item = access_item_rc(parent_ref, ctx);
+                let index = *index as usize;
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                quote!(
+                    slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).hide_child_window(#index, #parent_item)
+                )
+            } else {
+                panic!("internal error: invalid args to HideChildWindow {:?}", arguments)
+            }
+        }
         BuiltinFunction::ImplicitLayoutInfo(orient) => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -2315,6 +2640,17 @@ fn compile_builtin_function_call(
         BuiltinFunction::AnimationTick => {
             quote!(slint::private_unstable_api::re_exports::animation_tick())
         }
+        BuiltinFunction::Translate => {
+            let (original, context, domain, args, n, plural) = (
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+            );
+            quote!(slint::private_unstable_api::translate(#original, #context, #domain, #args, (#n) as i32, #plural))
+        }
         BuiltinFunction::Debug => quote!(slint::private_unstable_api::debug(#(#a)*)),
         BuiltinFunction::Mod => quote!((#(#a as f64)%*)),
         BuiltinFunction::Round => quote!((#(#a)* as f64).round()),
@@ -2340,6 +2676,55 @@ fn compile_builtin_function_call(
             quote!(#(#a)*.as_str().parse::<f64>().unwrap_or_default())
         }
         BuiltinFunction::StringIsFloat => quote!(#(#a)*.as_str().parse::<f64>().is_ok()),
+        BuiltinFunction::StringToUppercase => {
+            quote!(slint::private_unstable_api::re_exports::SharedString::from((#(#a)*).to_uppercase()))
+        }
+        BuiltinFunction::StringToLowercase => {
+            quote!(slint::private_unstable_api::re_exports::SharedString::from((#(#a)*).to_lowercase()))
+        }
+        BuiltinFunction::StringTrim => {
+            quote!(slint::private_unstable_api::re_exports::SharedString::from((#(#a)*).trim()))
+        }
+        BuiltinFunction::StringContains => {
+            let (s, pattern) = (a.next().unwrap(), a.next().unwrap());
+            quote!((#s).as_str().contains((#pattern).as_str()))
+        }
+        BuiltinFunction::StringStartsWith => {
+            let (s, prefix) = (a.next().unwrap(), a.next().unwrap());
+            quote!((#s).as_str().starts_with((#prefix).as_str()))
+        }
+        BuiltinFunction::StringSplit => {
+            let (s, separator) = (a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::string_split(#s, #separator))
+        }
+        BuiltinFunction::StringCharacterCount => {
+            quote!((#(#a)*).as_str().chars().count() as i32)
+        }
+        BuiltinFunction::FormatNumber => {
+            let (value, decimals, grouping) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::re_exports::format_number((#value) as f64, (#decimals) as i32, #grouping))
+        }
+        BuiltinFunction::DateTimeFromTimestamp => {
+            let milliseconds = a.next().unwrap();
+            quote!(slint::private_unstable_api::re_exports::DateTime::from_timestamp_millis((#milliseconds) as i64))
+        }
+        BuiltinFunction::DateTimeFromComponents => {
+            quote!(slint::private_unstable_api::re_exports::DateTime::from_components(#((#a) as i32),*))
+        }
+        BuiltinFunction::DateTimeComponent(component) => {
+            let date = a.next().unwrap();
+            let method = ident(component.name());
+            quote!((#date).#method())
+        }
+        BuiltinFunction::DateTimeTimestamp => {
+            let date = a.next().unwrap();
+            quote!((#date).timestamp_millis())
+        }
+        BuiltinFunction::DateTimeToIsoString => {
+            let date = a.next().unwrap();
+            quote!((#date).to_iso_string())
+        }
         BuiltinFunction::ColorBrighter => {
             let x = a.next().unwrap();
             let factor = a.next().unwrap();
@@ -2381,6 +2766,18 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).set_text_input_focused(#(#a)*))
         }
+        BuiltinFunction::ClipboardText => {
+            quote!(slint::private_unstable_api::re_exports::clipboard_text())
+        }
+        BuiltinFunction::SetClipboardText => {
+            quote!(slint::private_unstable_api::re_exports::set_clipboard_text(#(#a)*))
+        }
+        BuiltinFunction::ClipboardImage => {
+            quote!(slint::private_unstable_api::re_exports::clipboard_image())
+        }
+        BuiltinFunction::SetClipboardImage => {
+            quote!(slint::private_unstable_api::re_exports::set_clipboard_image(&(#(#a)*)))
+        }
     }
 }
 
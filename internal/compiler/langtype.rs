@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
 use std::rc::Rc;
@@ -40,6 +41,8 @@ pub enum Type {
     String,
     Color,
     Duration,
+    /// A point in time, `date-time` in .slint
+    DateTime,
     PhysicalLength,
     LogicalLength,
     Rem,
@@ -60,6 +63,9 @@ pub enum Type {
         name: Option<String>,
         /// When declared in .slint, this is the node of the declaration.
         node: Option<syntax_nodes::ObjectType>,
+        /// The field default values and the functions of a struct declared in .slint.
+        /// It is filled when resolving the expressions of the document declaring the struct.
+        definition: Option<Rc<RefCell<StructDefinition>>>,
     },
     Enumeration(Rc<Enumeration>),
 
@@ -92,6 +98,7 @@ fn eq(&self, other: &Self) -> bool {
             Type::String => matches!(other, Type::String),
             Type::Color => matches!(other, Type::Color),
             Type::Duration => matches!(other, Type::Duration),
+            Type::DateTime => matches!(other, Type::DateTime),
             Type::Angle => matches!(other, Type::Angle),
             Type::PhysicalLength => matches!(other, Type::PhysicalLength),
             Type::LogicalLength => matches!(other, Type::LogicalLength),
@@ -104,8 +111,8 @@ fn eq(&self, other: &Self) -> bool {
             Type::Easing => matches!(other, Type::Easing),
             Type::Brush => matches!(other, Type::Brush),
             Type::Array(a) => matches!(other, Type::Array(b) if a == b),
-            Type::Struct { fields, name, node: _ } => {
-                matches!(other, Type::Struct{fields: f, name: n, node: _} if fields == f && name == n)
+            Type::Struct { fields, name, node: _, definition: _ } => {
+                matches!(other, Type::Struct{fields: f, name: n, node: _, definition: _} if fields == f && name == n)
             }
             Type::Enumeration(lhs) => matches!(other, Type::Enumeration(rhs) if lhs == rhs),
             Type::UnitProduct(a) => matches!(other, Type::UnitProduct(b) if a == b),
@@ -153,6 +160,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Type::Int32 => write!(f, "int"),
             Type::String => write!(f, "string"),
             Type::Duration => write!(f, "duration"),
+            Type::DateTime => write!(f, "date-time"),
             Type::Angle => write!(f, "angle"),
             Type::PhysicalLength => write!(f, "physical-length"),
             Type::LogicalLength => write!(f, "length"),
@@ -208,6 +216,7 @@ pub fn is_property_type(&self) -> bool {
                 | Self::String
                 | Self::Color
                 | Self::Duration
+                | Self::DateTime
                 | Self::Angle
                 | Self::PhysicalLength
                 | Self::LogicalLength
@@ -312,6 +321,7 @@ pub fn default_unit(&self) -> Option<Unit> {
             Type::Float32 => None,
             Type::Int32 => None,
             Type::String => None,
+            Type::DateTime => None,
             Type::Color => None,
             Type::Image => None,
             Type::Bool => None,
@@ -336,6 +346,26 @@ pub fn as_unit_product(&self) -> Option<Vec<(Unit, i8)>> {
             _ => self.default_unit().map(|u| vec![(u, 1)]),
         }
     }
+
+    /// The default value declared in .slint for the field `name` of this struct, if any
+    pub fn struct_field_default(&self, name: &str) -> Option<Expression> {
+        match self {
+            Type::Struct { definition: Some(definition), .. } => {
+                definition.borrow().field_defaults.get(name).cloned()
+            }
+            _ => None,
+        }
+    }
+
+    /// The function `name` declared in this struct, if any
+    pub fn struct_function(&self, name: &str) -> Option<StructFunction> {
+        match self {
+            Type::Struct { definition: Some(definition), .. } => {
+                definition.borrow().function(name).cloned()
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Information about properties in NativeClass
@@ -475,7 +505,10 @@ pub fn lookup_type_for_child_element(
         tr: &TypeRegister,
     ) -> Result<ElementType, String> {
         match self {
-            Self::Component(component) if component.child_insertion_point.borrow().is_none() => {
+            Self::Component(component)
+                if component.child_insertion_point.borrow().is_none()
+                    && component.named_child_insertion_points.borrow().is_empty() =>
+            {
                 let base_type = component.root_element.borrow().base_type.clone();
                 if base_type == tr.empty_type() {
                     return Err(format!("'{}' cannot have children. Only components with @children can have children", component.id));
@@ -715,11 +748,41 @@ pub fn is_valid_for_assignment(&self) -> bool {
     }
 }
 
+/// The parts of a struct declared in .slint that are not part of its type:
+/// the default values of its fields and its functions.
+#[derive(Debug, Default)]
+pub struct StructDefinition {
+    /// The default value of the fields that have one, already folded to a literal
+    pub field_defaults: BTreeMap<String, Expression>,
+    /// The functions declared in the struct, in the order they were declared
+    pub functions: Vec<StructFunction>,
+}
+
+impl StructDefinition {
+    pub fn function(&self, name: &str) -> Option<&StructFunction> {
+        self.functions.iter().find(|f| f.name == name)
+    }
+}
+
+/// A function declared in a struct, such as `pure function area() -> length { ... }`.
+/// Its body reads the struct with the `self` local variable and the arguments with local
+/// variables of their name, so it can be inlined where it is called.
+#[derive(Debug, Clone)]
+pub struct StructFunction {
+    pub name: String,
+    pub args: Vec<(String, Type)>,
+    pub return_type: Type,
+    pub body: Expression,
+    pub node: syntax_nodes::Function,
+}
+
 #[derive(Debug, Clone)]
 pub struct Enumeration {
     pub name: String,
     pub values: Vec<String>,
     pub default_value: usize, // index in values
+    /// The declaration of an enum declared in .slint. None for builtin enums
+    pub node: Option<syntax_nodes::EnumDeclaration>,
 }
 
 impl PartialEq for Enumeration {
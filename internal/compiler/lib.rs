@@ -77,6 +77,9 @@ pub struct CompilerConfiguration {
 
     /// expose the accessible role and properties
     pub accessibility: bool,
+
+    /// The domain used to look up the translations of the strings marked with `@tr(...)`
+    pub translation_domain: Option<String>,
 }
 
 impl CompilerConfiguration {
@@ -128,6 +131,7 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             inline_all_elements,
             scale_factor,
             accessibility: true,
+            translation_domain: None,
         }
     }
 }
@@ -175,6 +179,10 @@ pub async fn compile_syntax_node(
         diagnostics
             .push_error("@children placeholder not allowed in the final component".into(), node)
     }
+    for (_, node) in doc.root_component.named_child_insertion_points.borrow().values() {
+        diagnostics
+            .push_error("@children placeholder not allowed in the final component".into(), node)
+    }
 
     if !diagnostics.has_error() {
         // FIXME: ideally we would be able to run more passes, but currently we panic because invariant are not met.
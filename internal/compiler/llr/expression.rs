@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 use super::PropertyReference;
-use crate::expression_tree::{BuiltinFunction, OperatorClass};
+use crate::expression_tree::{ArrayOperation, BuiltinFunction, OperatorClass};
 use crate::langtype::Type;
 use crate::layout::Orientation;
 use itertools::Either;
@@ -53,6 +53,17 @@ pub enum Expression {
         index: Box<Expression>,
     },
 
+    /// Evaluate `body` for each element of the array, with the element and its index in the
+    /// local variables `element` and `index`, and combine the results according to `op`
+    ArrayOperation {
+        op: ArrayOperation,
+        /// This expression should have [`Type::Array`] type
+        array: Box<Expression>,
+        element: String,
+        index: String,
+        body: Box<Expression>,
+    },
+
     /// Cast an expression to the given type
     Cast {
         from: Box<Expression>,
@@ -203,7 +214,7 @@ pub fn default_value_for_type(ty: &Type) -> Option<Self> {
             | Type::UnitProduct(_) => Expression::NumberLiteral(0.),
             Type::Percent => Expression::NumberLiteral(1.),
             Type::String => Expression::StringLiteral(String::new()),
-            Type::Color => {
+            Type::Color | Type::DateTime => {
                 Expression::Cast { from: Box::new(Expression::NumberLiteral(0.)), to: ty.clone() }
             }
             Type::Image => Expression::ImageReference {
@@ -252,6 +263,13 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
                 Type::Array(ty) => *ty,
                 _ => unreachable!(),
             },
+            Self::ArrayOperation { op, array, body, .. } => match op {
+                ArrayOperation::Filter => array.ty(ctx),
+                ArrayOperation::Map => Type::Array(Box::new(body.ty(ctx))),
+                ArrayOperation::Any | ArrayOperation::All => Type::Bool,
+                ArrayOperation::Sum => body.ty(ctx),
+                ArrayOperation::IndexOf => Type::Int32,
+            },
             Self::Cast { to, .. } => to.clone(),
             Self::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty(ctx)),
             Self::BuiltinFunctionCall { function, .. } => match function.ty() {
@@ -311,6 +329,10 @@ macro_rules! visit_impl {
                 $visitor(array);
                 $visitor(index);
             }
+            Expression::ArrayOperation { array, body, .. } => {
+                $visitor(array);
+                $visitor(body);
+            }
             Expression::Cast { from, .. } => $visitor(from),
             Expression::CodeBlock(b) => b.$iter().for_each($visitor),
             Expression::BuiltinFunctionCall { arguments, .. }
@@ -394,6 +416,15 @@ pub fn visit_recursive(&self, visitor: &mut dyn FnMut(&Self)) {
         visitor(self);
         self.visit(|e| e.visit_recursive(visitor));
     }
+
+    /// Returns true if this expression, or one of its sub expressions, reads the local variable `name`
+    pub fn reads_local_variable(&self, name: &str) -> bool {
+        let mut result = false;
+        self.visit_recursive(&mut |e| {
+            result |= matches!(e, Expression::ReadLocalVariable { name: n, .. } if n == name)
+        });
+        result
+    }
 }
 
 pub trait TypeResolutionContext {
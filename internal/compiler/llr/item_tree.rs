@@ -36,10 +36,18 @@ pub enum Animation {
     Transition(Expression),
 }
 
+/// The code of the `animation-started` and `animation-finished` callbacks of an animation
+#[derive(Debug, Clone)]
+pub struct AnimationEventHandler {
+    pub started: Expression,
+    pub finished: Expression,
+}
+
 #[derive(Debug, Clone)]
 pub struct BindingExpression {
     pub expression: MutExpression,
     pub animation: Option<Animation>,
+    pub animation_event_handler: Option<AnimationEventHandler>,
     /// When true, we can initialize the property with `set` otherwise, `set_binding` must be used
     pub is_constant: bool,
     /// When true, the expression is a "state binding".  Despite the type of the expression being a integer
@@ -119,6 +127,12 @@ pub struct ListViewInfo {
     pub listview_height: PropertyReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: PropertyReference,
+    /// The `first-visible-row` and `last-visible-row` properties, set by the repeater
+    pub first_visible_row: PropertyReference,
+    pub last_visible_row: PropertyReference,
+    /// The row requested by `scroll-to()`, or -1 if there is no pending request
+    pub scroll_request_row: PropertyReference,
+    pub scroll_request_alignment: PropertyReference,
 
     // In the repeated component context
     pub prop_y: PropertyReference,
@@ -231,10 +245,15 @@ pub struct SubComponent {
     pub property_init: Vec<(PropertyReference, BindingExpression)>,
     /// The animation for properties which are animated
     pub animations: HashMap<PropertyReference, Expression>,
+    /// The `animation-started` and `animation-finished` callbacks of these animations
+    pub animation_event_handlers: HashMap<PropertyReference, AnimationEventHandler>,
     pub two_way_bindings: Vec<(PropertyReference, PropertyReference)>,
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
     pub init_code: Vec<MutExpression>,
+    /// The `changed <property> => { ... }` handlers. They are called with the component's init
+    /// code, and then each time the property has changed, once the property changes have settled.
+    pub change_callbacks: Vec<(PropertyReference, MutExpression)>,
 
     pub layout_info_h: MutExpression,
     pub layout_info_v: MutExpression,
@@ -351,6 +370,9 @@ pub fn for_each_expression<'a>(
             for e in &sc.init_code {
                 visitor(e, ctx);
             }
+            for (_, e) in &sc.change_callbacks {
+                visitor(e, ctx);
+            }
             for (_, e) in &sc.property_init {
                 visitor(&e.expression, ctx);
             }
@@ -375,6 +397,8 @@ pub struct PublicProperty {
     pub ty: Type,
     pub prop: PropertyReference,
     pub read_only: bool,
+    /// The property has an `animate` declaration
+    pub is_animated: bool,
 }
 pub type PublicProperties = Vec<PublicProperty>;
 pub type PrivateProperties = Vec<(String, Type)>;
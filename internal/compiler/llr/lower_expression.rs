@@ -9,7 +9,7 @@
 use itertools::Either;
 
 use super::lower_to_item_tree::{LoweredElement, LoweredSubComponentMapping, LoweringState};
-use super::{Animation, PropertyReference};
+use super::{Animation, AnimationEventHandler, PropertyReference};
 use crate::expression_tree::{BuiltinFunction, Expression as tree_Expression};
 use crate::langtype::{EnumerationValue, Type};
 use crate::layout::Orientation;
@@ -106,6 +106,15 @@ pub fn lower_expression(
             array: Box::new(lower_expression(array, ctx)),
             index: Box::new(lower_expression(index, ctx)),
         },
+        tree_Expression::ArrayOperation { op, array, element, index, body } => {
+            llr_Expression::ArrayOperation {
+                op: *op,
+                array: Box::new(lower_expression(array, ctx)),
+                element: element.clone(),
+                index: index.clone(),
+                body: Box::new(lower_expression(body, ctx)),
+            }
+        }
         tree_Expression::Cast { from, to } => {
             llr_Expression::Cast { from: Box::new(lower_expression(from, ctx)), to: to.clone() }
         }
@@ -116,6 +125,22 @@ pub fn lower_expression(
             tree_Expression::BuiltinFunctionReference(BuiltinFunction::ShowPopupWindow, _) => {
                 lower_show_popup(arguments, ctx)
             }
+            tree_Expression::BuiltinFunctionReference(
+                function @ (BuiltinFunction::ShowChildWindow | BuiltinFunction::HideChildWindow),
+                _,
+            ) => lower_child_window_function(function, arguments, ctx),
+            tree_Expression::BuiltinFunctionReference(BuiltinFunction::Translate, _) => {
+                let mut arguments =
+                    arguments.iter().map(|e| lower_expression(e, ctx)).collect::<Vec<_>>();
+                // The formatting arguments are passed as a slice
+                if let llr_Expression::Array { as_model, .. } = &mut arguments[3] {
+                    *as_model = false;
+                }
+                llr_Expression::BuiltinFunctionCall {
+                    function: BuiltinFunction::Translate,
+                    arguments,
+                }
+            }
             tree_Expression::BuiltinFunctionReference(f, _) => {
                 let arguments = arguments.iter().map(|e| lower_expression(e, ctx)).collect::<_>();
                 llr_Expression::BuiltinFunctionCall { function: *f, arguments }
@@ -349,19 +374,62 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
             .unwrap();
         let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
         let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
+        let close_policy =
+            llr_Expression::PropertyReference(ctx.map_property_reference(&popup.close_policy));
         let item_ref = lower_expression(
             &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
             ctx,
         );
         llr_Expression::BuiltinFunctionCall {
             function: BuiltinFunction::ShowPopupWindow,
-            arguments: vec![llr_Expression::NumberLiteral(popup_index as _), x, y, item_ref],
+            arguments: vec![
+                llr_Expression::NumberLiteral(popup_index as _),
+                x,
+                y,
+                close_policy,
+                item_ref,
+            ],
         }
     } else {
         panic!("invalid arguments to ShowPopupWindow");
     }
 }
 
+fn lower_child_window_function(
+    function: &BuiltinFunction,
+    args: &[tree_Expression],
+    ctx: &ExpressionContext,
+) -> llr_Expression {
+    if let [tree_Expression::ElementReference(e)] = args {
+        let child_window = e.upgrade().unwrap();
+        let child_comp = child_window.borrow().enclosing_component.upgrade().unwrap();
+        let parent_component = child_comp
+            .parent_element
+            .upgrade()
+            .unwrap()
+            .borrow()
+            .enclosing_component
+            .upgrade()
+            .unwrap();
+        let popup_list = parent_component.popup_windows.borrow();
+        let (index, child_window) = popup_list
+            .iter()
+            .enumerate()
+            .find(|(_, p)| Rc::ptr_eq(&p.component, &child_comp))
+            .unwrap();
+        let item_ref = lower_expression(
+            &tree_Expression::ElementReference(Rc::downgrade(&child_window.parent_element)),
+            ctx,
+        );
+        llr_Expression::BuiltinFunctionCall {
+            function: function.clone(),
+            arguments: vec![llr_Expression::NumberLiteral(index as _), item_ref],
+        }
+    } else {
+        panic!("invalid arguments to {:?}", function);
+    }
+}
+
 pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> Animation {
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
@@ -384,6 +452,12 @@ fn animation_fields() -> impl Iterator<Item = (String, Type)> {
             ("iteration-count".to_string(), Type::Float32),
             ("easing".to_string(), Type::Easing),
             ("delay".to_string(), Type::Int32),
+            (
+                "direction".to_string(),
+                Type::Enumeration(
+                    crate::typeregister::BUILTIN_ENUMS.with(|e| e.AnimationDirection.clone()),
+                ),
+            ),
         ])
     }
 
@@ -392,6 +466,7 @@ fn animation_ty() -> Type {
             fields: animation_fields().collect(),
             name: Some("PropertyAnimation".into()),
             node: None,
+            definition: None,
         }
     }
 
@@ -429,6 +504,7 @@ fn animation_ty() -> Type {
                     .collect(),
                     name: None,
                     node: None,
+                    definition: None,
                 },
                 values: IntoIterator::into_iter([
                     ("0".to_string(), get_anim),
@@ -451,6 +527,65 @@ fn animation_ty() -> Type {
     }
 }
 
+/// Lower the `animation-started` and `animation-finished` callbacks of the animation.
+/// Returns None if the animation doesn't have such callbacks.
+pub fn lower_animation_event_handler(
+    a: &PropertyAnimation,
+    ctx: &ExpressionContext<'_>,
+) -> Option<AnimationEventHandler> {
+    fn lower_callback(
+        a: &ElementRc,
+        name: &str,
+        ctx: &ExpressionContext<'_>,
+    ) -> Option<llr_Expression> {
+        let code = lower_expression(&a.borrow().bindings.get(name)?.borrow().expression, ctx);
+        // Make sure the code doesn't evaluate to a value, so it can be used in a condition
+        Some(llr_Expression::CodeBlock(vec![code, llr_Expression::CodeBlock(vec![])]))
+    }
+
+    let lower = |name: &str| match a {
+        PropertyAnimation::Static(a) => lower_callback(a, name, ctx),
+        PropertyAnimation::Transition { state_ref, animations } => {
+            if !animations.iter().any(|tr| tr.animation.borrow().bindings.contains_key(name)) {
+                return None;
+            }
+            let set_state = llr_Expression::StoreLocalVariable {
+                name: "state".into(),
+                value: Box::new(lower_expression(state_ref, ctx)),
+            };
+            let mut code = llr_Expression::CodeBlock(vec![]);
+            for tr in animations.iter().rev() {
+                let condition = lower_expression(
+                    &tr.condition(tree_Expression::ReadLocalVariable {
+                        name: "state".into(),
+                        ty: state_ref.ty(),
+                    }),
+                    ctx,
+                );
+                code = llr_Expression::Condition {
+                    condition: Box::new(condition),
+                    true_expr: Box::new(
+                        lower_callback(&tr.animation, name, ctx)
+                            .unwrap_or_else(|| llr_Expression::CodeBlock(vec![])),
+                    ),
+                    false_expr: Box::new(code),
+                }
+            }
+            Some(llr_Expression::CodeBlock(vec![set_state, code]))
+        }
+    };
+
+    let started = lower("animation-started");
+    let finished = lower("animation-finished");
+    if started.is_none() && finished.is_none() {
+        return None;
+    }
+    Some(AnimationEventHandler {
+        started: started.unwrap_or_else(|| llr_Expression::CodeBlock(vec![])),
+        finished: finished.unwrap_or_else(|| llr_Expression::CodeBlock(vec![])),
+    })
+}
+
 fn compute_layout_info(
     l: &crate::layout::Layout,
     o: Orientation,
@@ -655,6 +790,7 @@ fn box_layout_data(
         .collect(),
         name: Some("BoxLayoutCellData".into()),
         node: None,
+        definition: None,
     };
 
     if repeater_count == 0 {
@@ -741,6 +877,7 @@ pub(super) fn grid_layout_cell_data_ty() -> Type {
         .collect(),
         name: Some("GridLayoutCellData".into()),
         node: None,
+        definition: None,
     }
 }
 
@@ -837,6 +974,7 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
                     fields: Default::default(),
                     name: Some("PathElement".to_owned()),
                     node: None,
+                    definition: None,
                 },
                 values: elements,
                 as_model: false,
@@ -860,6 +998,7 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
                             .collect(),
                         name: element.element_type.native_class.cpp_type.clone(),
                         node: None,
+                        definition: None,
                     };
 
                     llr_Expression::Struct {
@@ -911,6 +1050,7 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
                         .collect(),
                         name: None,
                         node: None,
+                        definition: None,
                     },
                     values: IntoIterator::into_iter([
                         (
@@ -954,5 +1094,8 @@ fn make_struct(
         values.insert(name.to_string(), expr);
     }
 
-    llr_Expression::Struct { ty: Type::Struct { fields, name: Some(name), node: None }, values }
+    llr_Expression::Struct {
+        ty: Type::Struct { fields, name: Some(name), node: None, definition: None },
+        values,
+    }
 }
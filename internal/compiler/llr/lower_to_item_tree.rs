@@ -188,9 +188,11 @@ fn lower_sub_component(
         sub_components: Default::default(),
         property_init: Default::default(),
         animations: Default::default(),
+        animation_event_handlers: Default::default(),
         two_way_bindings: Default::default(),
         const_properties: Default::default(),
         init_code: Default::default(),
+        change_callbacks: Default::default(),
         // just initialize to dummy expression right now and it will be set later
         layout_info_h: super::Expression::BoolLiteral(false).into(),
         layout_info_v: super::Expression::BoolLiteral(false).into(),
@@ -337,6 +339,11 @@ fn lower_sub_component(
                 .as_ref()
                 .filter(|_| !is_constant)
                 .map(|a| super::lower_expression::lower_animation(a, &ctx));
+            let animation_event_handler = binding
+                .animation
+                .as_ref()
+                .filter(|_| !is_constant)
+                .and_then(|a| super::lower_expression::lower_animation_event_handler(a, &ctx));
 
             sub_component.prop_analysis.insert(
                 prop.clone(),
@@ -356,6 +363,7 @@ fn lower_sub_component(
                 BindingExpression {
                     expression,
                     animation,
+                    animation_event_handler,
                     is_constant,
                     is_state_info,
                     use_count: 0.into(),
@@ -369,9 +377,14 @@ fn lower_sub_component(
             .get(p)
             .map_or(true, |a| a.is_set || a.is_set_externally)
         {
-            if let Some(anim) = binding.animation.as_ref() {
-                match super::lower_expression::lower_animation(anim, &ctx) {
+            if let Some(animation) = binding.animation.as_ref() {
+                match super::lower_expression::lower_animation(animation, &ctx) {
                     Animation::Static(anim) => {
+                        if let Some(handler) =
+                            super::lower_expression::lower_animation_event_handler(animation, &ctx)
+                        {
+                            sub_component.animation_event_handlers.insert(prop.clone(), handler);
+                        }
                         sub_component.animations.insert(prop, anim);
                     }
                     Animation::Transition(_) => {
@@ -405,6 +418,18 @@ fn lower_sub_component(
         .map(|e| super::lower_expression::lower_expression(e, &ctx).into())
         .collect();
 
+    crate::object_tree::recurse_elem(&component.root_element, &(), &mut |e, ()| {
+        for (p, handlers) in &e.borrow().change_callbacks {
+            let prop = ctx.map_property_reference(&NamedReference::new(e, p));
+            for handler in handlers.borrow().iter() {
+                sub_component.change_callbacks.push((
+                    prop.clone(),
+                    super::lower_expression::lower_expression(handler, &ctx).into(),
+                ));
+            }
+        }
+    });
+
     sub_component.layout_info_h = super::lower_expression::get_layout_info(
         &component.root_element,
         &ctx,
@@ -489,6 +514,10 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
         viewport_width: ctx.map_property_reference(&lv.viewport_width),
         listview_height: ctx.map_property_reference(&lv.listview_height),
         listview_width: ctx.map_property_reference(&lv.listview_width),
+        first_visible_row: ctx.map_property_reference(&lv.first_visible_row),
+        last_visible_row: ctx.map_property_reference(&lv.last_visible_row),
+        scroll_request_row: ctx.map_property_reference(&lv.scroll_request_row),
+        scroll_request_alignment: ctx.map_property_reference(&lv.scroll_request_alignment),
 
         prop_y: map_inner_prop("y"),
         prop_width: map_inner_prop("width"),
@@ -617,6 +646,7 @@ fn lower_global(
         init_values[property_index] = Some(BindingExpression {
             expression: expression.into(),
             animation: None,
+            animation_event_handler: None,
             is_constant,
             is_state_info: false,
             use_count: 0.into(),
@@ -726,6 +756,12 @@ fn public_properties(
                 ty: c.property_type.clone(),
                 prop: property_reference,
                 read_only: c.visibility == PropertyVisibility::Output,
+                is_animated: component
+                    .root_element
+                    .borrow()
+                    .bindings
+                    .get(p)
+                    .map_or(false, |b| b.borrow().animation.is_some()),
             }
         })
         .collect()
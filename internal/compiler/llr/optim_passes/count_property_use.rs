@@ -53,11 +53,23 @@ pub fn count_property_use(root: &PublicComponent) {
             }
             expr.use_count.set(c + 1);
             expr.expression.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+            if let Some(handler) = &expr.animation_event_handler {
+                handler.started.visit_recursive(&mut |e| visit_expression(e, ctx));
+                handler.finished.visit_recursive(&mut |e| visit_expression(e, ctx));
+            }
         }
         // 3. the init code
         for expr in &sc.init_code {
             expr.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
         }
+        for (p, e) in &sc.change_callbacks {
+            visit_property(p, ctx);
+            e.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+        }
+        for handler in sc.animation_event_handlers.values() {
+            handler.started.visit_recursive(&mut |e| visit_expression(e, ctx));
+            handler.finished.visit_recursive(&mut |e| visit_expression(e, ctx));
+        }
         // 4. the models
         for (idx, r) in sc.repeated.iter().enumerate() {
             r.model.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
@@ -67,6 +79,10 @@ pub fn count_property_use(root: &PublicComponent) {
                 visit_property(&lv.viewport_height, ctx);
                 visit_property(&lv.listview_width, ctx);
                 visit_property(&lv.listview_height, ctx);
+                visit_property(&lv.first_visible_row, ctx);
+                visit_property(&lv.last_visible_row, ctx);
+                visit_property(&lv.scroll_request_row, ctx);
+                visit_property(&lv.scroll_request_alignment, ctx);
 
                 let rep_ctx = EvaluationContext::new_sub_component(
                     root,
@@ -31,6 +31,8 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::ReadLocalVariable { .. } => 1,
         Expression::StructFieldAccess { .. } => 1,
         Expression::ArrayIndex { .. } => ARRAY_INDEX_COST,
+        // Iterates over the whole array
+        Expression::ArrayOperation { .. } => return isize::MAX,
         Expression::Cast { .. } => 0,
         Expression::CodeBlock(_) => 0,
         Expression::BuiltinFunctionCall { function, .. } => builtin_function_cost(*function),
@@ -88,8 +90,22 @@ fn builtin_function_cost(function: BuiltinFunction) -> isize {
         BuiltinFunction::Pow => 10,
         BuiltinFunction::SetFocusItem => isize::MAX,
         BuiltinFunction::ShowPopupWindow => isize::MAX,
+        BuiltinFunction::ShowChildWindow | BuiltinFunction::HideChildWindow => isize::MAX,
         BuiltinFunction::StringToFloat => 50,
         BuiltinFunction::StringIsFloat => 50,
+        BuiltinFunction::StringToUppercase => ALLOC_COST,
+        BuiltinFunction::StringToLowercase => ALLOC_COST,
+        BuiltinFunction::StringTrim => ALLOC_COST,
+        BuiltinFunction::StringContains => 50,
+        BuiltinFunction::StringStartsWith => 50,
+        BuiltinFunction::StringSplit => 2 * ALLOC_COST,
+        BuiltinFunction::StringCharacterCount => 50,
+        BuiltinFunction::FormatNumber => ALLOC_COST,
+        BuiltinFunction::DateTimeFromTimestamp => 10,
+        BuiltinFunction::DateTimeFromComponents => 50,
+        BuiltinFunction::DateTimeComponent(_) => 50,
+        BuiltinFunction::DateTimeTimestamp => 10,
+        BuiltinFunction::DateTimeToIsoString => ALLOC_COST,
         BuiltinFunction::ColorBrighter => 50,
         BuiltinFunction::ColorDarker => 50,
         BuiltinFunction::ImageSize => 50,
@@ -102,6 +118,11 @@ fn builtin_function_cost(function: BuiltinFunction) -> isize {
         BuiltinFunction::DarkColorScheme => isize::MAX,
         BuiltinFunction::SetTextInputFocused => PROPERTY_ACCESS_COST,
         BuiltinFunction::TextInputFocused => PROPERTY_ACCESS_COST,
+        BuiltinFunction::Translate => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
+        BuiltinFunction::ClipboardText => isize::MAX,
+        BuiltinFunction::SetClipboardText => isize::MAX,
+        BuiltinFunction::ClipboardImage => isize::MAX,
+        BuiltinFunction::SetClipboardImage => isize::MAX,
     }
 }
 
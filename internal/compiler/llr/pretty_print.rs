@@ -170,6 +170,9 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::ReadLocalVariable { name, .. } => write!(f, "{}", name),
             Expression::StructFieldAccess { base, name } => write!(f, "{}.{}", e(base), name),
             Expression::ArrayIndex { array, index } => write!(f, "{}[{}]", e(array), e(index)),
+            Expression::ArrayOperation { op, array, element, index, body } => {
+                write!(f, "{}.{:?}(({}, {}) => {})", e(array), op, element, index, e(body))
+            }
             Expression::Cast { from, to } => write!(f, "{} /*as {:?}*/", e(from), to),
             Expression::CodeBlock(v) => {
                 write!(f, "{{ {} }}", v.iter().map(e).join("; "))
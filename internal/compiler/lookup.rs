@@ -14,7 +14,7 @@
 use crate::object_tree::{ElementRc, PropertyVisibility};
 use crate::parser::NodeOrToken;
 use crate::typeregister::TypeRegister;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 /// Contains information which allow to lookup identifier in expressions
 pub struct LookupCtx<'a> {
@@ -34,6 +34,10 @@ pub struct LookupCtx<'a> {
     /// The name of the arguments of the callback or function
     pub arguments: Vec<String>,
 
+    /// The local variables in scope, such as the parameter of a lambda, with the expression
+    /// reading them. The last one is looked up first.
+    pub local_variables: Vec<(String, Expression)>,
+
     /// The type register in which to look for Globals
     pub type_register: &'a TypeRegister,
 
@@ -43,6 +47,9 @@ pub struct LookupCtx<'a> {
 
     /// The token currently processed
     pub current_token: Option<NodeOrToken>,
+
+    /// Used by [`Self::unique_local_name`] outside of a component, for example in struct definitions
+    pub local_variable_counter: Cell<usize>,
 }
 
 impl<'a> LookupCtx<'a> {
@@ -54,12 +61,31 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             component_scope: Default::default(),
             diag,
             arguments: Default::default(),
+            local_variables: Default::default(),
             type_register,
             type_loader: None,
             current_token: None,
+            local_variable_counter: Default::default(),
         }
     }
 
+    /// Returns a name for a local variable introduced by the compiler, which is unique within the
+    /// component, so that nested expressions don't shadow each other's variables.
+    pub fn unique_local_name(&self, name: &str) -> String {
+        let next = |counter: &Cell<usize>| {
+            let index = counter.get() + 1;
+            counter.set(index);
+            index
+        };
+        let component =
+            self.component_scope.first().and_then(|e| e.borrow().enclosing_component.upgrade());
+        let index = match component {
+            Some(component) => next(&component.local_variable_counter),
+            None => next(&self.local_variable_counter),
+        };
+        format!("{}-{}", name, index)
+    }
+
     pub fn return_type(&self) -> &Type {
         match &self.property_type {
             Type::Callback { return_type, .. } => {
@@ -100,6 +126,7 @@ pub enum BuiltinNamespace {
     Colors,
     Math,
     Key,
+    Clipboard,
     SlintInternal,
 }
 
@@ -163,6 +190,9 @@ fn for_each_entry<R>(
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.for_each_entry(ctx, f),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.for_each_entry(ctx, f),
+            LookupResult::Namespace(BuiltinNamespace::Clipboard) => {
+                ClipboardFunctions.for_each_entry(ctx, f)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.for_each_entry(ctx, f)
             }
@@ -178,6 +208,9 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.lookup(ctx, name),
+            LookupResult::Namespace(BuiltinNamespace::Clipboard) => {
+                ClipboardFunctions.lookup(ctx, name)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.lookup(ctx, name)
             }
@@ -207,6 +240,22 @@ fn for_each_entry<R>(
     }
 }
 
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for (name, expression) in ctx.local_variables.iter().rev() {
+            if let Some(r) = f(name, expression.clone().into()) {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
 struct SpecialIdLookup;
 impl LookupObject for SpecialIdLookup {
     fn for_each_entry<R>(
@@ -584,24 +633,79 @@ fn for_each_entry<R>(
         f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
     ) -> Option<R> {
         use EasingCurve::CubicBezier;
+        const NAMED_CUBIC_BEZIER: &[(&str, [f32; 4])] = &[
+            ("ease", [0.25, 0.1, 0.25, 1.0]),
+            ("ease-in", [0.42, 0.0, 1.0, 1.0]),
+            ("ease-in-out", [0.42, 0.0, 0.58, 1.0]),
+            ("ease-out", [0.0, 0.0, 0.58, 1.0]),
+            ("ease-in-sine", [0.12, 0.0, 0.39, 0.0]),
+            ("ease-out-sine", [0.61, 1.0, 0.88, 1.0]),
+            ("ease-in-out-sine", [0.37, 0.0, 0.63, 1.0]),
+            ("ease-in-quad", [0.11, 0.0, 0.5, 0.0]),
+            ("ease-out-quad", [0.5, 1.0, 0.89, 1.0]),
+            ("ease-in-out-quad", [0.45, 0.0, 0.55, 1.0]),
+            ("ease-in-cubic", [0.32, 0.0, 0.67, 0.0]),
+            ("ease-out-cubic", [0.33, 1.0, 0.68, 1.0]),
+            ("ease-in-out-cubic", [0.65, 0.0, 0.35, 1.0]),
+            ("ease-in-quart", [0.5, 0.0, 0.75, 0.0]),
+            ("ease-out-quart", [0.25, 1.0, 0.5, 1.0]),
+            ("ease-in-out-quart", [0.76, 0.0, 0.24, 1.0]),
+            ("ease-in-quint", [0.64, 0.0, 0.78, 0.0]),
+            ("ease-out-quint", [0.22, 1.0, 0.36, 1.0]),
+            ("ease-in-out-quint", [0.83, 0.0, 0.17, 1.0]),
+            ("ease-in-expo", [0.7, 0.0, 0.84, 0.0]),
+            ("ease-out-expo", [0.16, 1.0, 0.3, 1.0]),
+            ("ease-in-out-expo", [0.87, 0.0, 0.13, 1.0]),
+            ("ease-in-circ", [0.55, 0.0, 1.0, 0.45]),
+            ("ease-out-circ", [0.0, 0.55, 0.45, 1.0]),
+            ("ease-in-out-circ", [0.85, 0.0, 0.15, 1.0]),
+            ("ease-in-back", [0.36, 0.0, 0.66, -0.56]),
+            ("ease-out-back", [0.34, 1.56, 0.64, 1.0]),
+            ("ease-in-out-back", [0.68, -0.6, 0.32, 1.6]),
+        ];
         None.or_else(|| f("linear", Expression::EasingCurve(EasingCurve::Linear).into()))
             .or_else(|| {
-                f("ease", Expression::EasingCurve(CubicBezier(0.25, 0.1, 0.25, 1.0)).into())
+                NAMED_CUBIC_BEZIER.iter().find_map(|(name, [a, b, c, d])| {
+                    f(name, Expression::EasingCurve(CubicBezier(*a, *b, *c, *d)).into())
+                })
             })
             .or_else(|| {
-                f("ease-in", Expression::EasingCurve(CubicBezier(0.42, 0.0, 1.0, 1.0)).into())
+                [
+                    ("ease-in-elastic", EasingCurve::EaseInElastic),
+                    ("ease-out-elastic", EasingCurve::EaseOutElastic),
+                    ("ease-in-out-elastic", EasingCurve::EaseInOutElastic),
+                    ("ease-in-bounce", EasingCurve::EaseInBounce),
+                    ("ease-out-bounce", EasingCurve::EaseOutBounce),
+                    ("ease-in-out-bounce", EasingCurve::EaseInOutBounce),
+                ]
+                .into_iter()
+                .find_map(|(name, curve)| f(name, Expression::EasingCurve(curve).into()))
             })
             .or_else(|| {
-                f("ease-in-out", Expression::EasingCurve(CubicBezier(0.42, 0.0, 0.58, 1.0)).into())
+                f(
+                    "cubic-bezier",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::CubicBezier,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
             })
             .or_else(|| {
-                f("ease-out", Expression::EasingCurve(CubicBezier(0.0, 0.0, 0.58, 1.0)).into())
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
             })
             .or_else(|| {
                 f(
-                    "cubic-bezier",
+                    "keyframes",
                     Expression::BuiltinMacroReference(
-                        BuiltinMacroFunction::CubicBezier,
+                        BuiltinMacroFunction::Keyframes,
                         ctx.current_token.clone(),
                     )
                     .into(),
@@ -659,6 +763,28 @@ fn for_each_entry<R>(
     }
 }
 
+struct ClipboardFunctions;
+impl LookupObject for ClipboardFunctions {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        use Expression::BuiltinFunctionReference;
+        let t = &ctx.current_token;
+        let sl = || t.as_ref().map(|t| t.to_source_location());
+        let mut f = |n, e: Expression| f(n, e.into());
+        None.or_else(|| f("text", BuiltinFunctionReference(BuiltinFunction::ClipboardText, sl())))
+            .or_else(|| {
+                f("set-text", BuiltinFunctionReference(BuiltinFunction::SetClipboardText, sl()))
+            })
+            .or_else(|| f("image", BuiltinFunctionReference(BuiltinFunction::ClipboardImage, sl())))
+            .or_else(|| {
+                f("set-image", BuiltinFunctionReference(BuiltinFunction::SetClipboardImage, sl()))
+            })
+    }
+}
+
 struct SlintInternal;
 impl LookupObject for SlintInternal {
     fn for_each_entry<R>(
@@ -716,6 +842,36 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "format-number",
+                    Expression::BuiltinFunctionReference(
+                        BuiltinFunction::FormatNumber,
+                        ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "date-time",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::DateTime,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "date-time-from-timestamp",
+                    Expression::BuiltinFunctionReference(
+                        BuiltinFunction::DateTimeFromTimestamp,
+                        ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                    )
+                    .into(),
+                )
+            })
             .or_else(|| {
                 f(
                     "animation-tick",
@@ -739,6 +895,7 @@ fn for_each_entry<R>(
         None.or_else(|| f("Colors", LookupResult::Namespace(BuiltinNamespace::Colors)))
             .or_else(|| f("Math", LookupResult::Namespace(BuiltinNamespace::Math)))
             .or_else(|| f("Key", LookupResult::Namespace(BuiltinNamespace::Key)))
+            .or_else(|| f("Clipboard", LookupResult::Namespace(BuiltinNamespace::Clipboard)))
             .or_else(|| {
                 f("SlintInternal", LookupResult::Namespace(BuiltinNamespace::SlintInternal))
             })
@@ -747,16 +904,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, BuiltinFunctionLookup),
+                            ),
+                        ),
                     ),
                 ),
             ),
@@ -773,7 +936,7 @@ fn for_each_entry<R>(
         match self {
             Expression::ElementReference(e) => e.upgrade().unwrap().for_each_entry(ctx, f),
             _ => match self.ty() {
-                Type::Struct { fields, .. } => {
+                Type::Struct { fields, definition, .. } => {
                     for name in fields.keys() {
                         if let Some(r) = f(
                             name,
@@ -786,12 +949,20 @@ fn for_each_entry<R>(
                             return Some(r);
                         }
                     }
+                    for function in definition.iter().flat_map(|d| d.borrow().functions.clone()) {
+                        if let Some(r) =
+                            f(&function.name, self.struct_function_reference(ctx, &function.name))
+                        {
+                            return Some(r);
+                        }
+                    }
                     None
                 }
                 Type::String => StringExpression(self).for_each_entry(ctx, f),
                 Type::Brush | Type::Color => ColorExpression(self).for_each_entry(ctx, f),
                 Type::Image => ImageExpression(self).for_each_entry(ctx, f),
                 Type::Array(_) => ArrayExpression(self).for_each_entry(ctx, f),
+                Type::DateTime => DateTimeExpression(self).for_each_entry(ctx, f),
                 _ => None,
             },
         }
@@ -801,22 +972,40 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
         match self {
             Expression::ElementReference(e) => e.upgrade().unwrap().lookup(ctx, name),
             _ => match self.ty() {
-                Type::Struct { fields, .. } => fields.contains_key(name).then(|| {
-                    LookupResult::from(Expression::StructFieldAccess {
+                Type::Struct { fields, .. } if fields.contains_key(name) => {
+                    Some(LookupResult::from(Expression::StructFieldAccess {
                         base: Box::new(self.clone()),
                         name: name.to_string(),
-                    })
-                }),
+                    }))
+                }
+                ty @ Type::Struct { .. } => ty
+                    .struct_function(name)
+                    .map(|function| self.struct_function_reference(ctx, &function.name)),
                 Type::String => StringExpression(self).lookup(ctx, name),
                 Type::Brush | Type::Color => ColorExpression(self).lookup(ctx, name),
                 Type::Image => ImageExpression(self).lookup(ctx, name),
                 Type::Array(_) => ArrayExpression(self).lookup(ctx, name),
+                Type::DateTime => DateTimeExpression(self).lookup(ctx, name),
                 _ => None,
             },
         }
     }
 }
 
+impl Expression {
+    /// The function `name` of the struct of this expression, which is inlined when it is called
+    fn struct_function_reference(&self, ctx: &LookupCtx, name: &str) -> LookupResult {
+        LookupResult::from(Expression::MemberFunction {
+            base: Box::new(self.clone()),
+            base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+            member: Box::new(Expression::BuiltinMacroReference(
+                BuiltinMacroFunction::StructFunction(name.into()),
+                ctx.current_token.clone(),
+            )),
+        })
+    }
+}
+
 struct StringExpression<'a>(&'a Expression);
 impl<'a> LookupObject for StringExpression<'a> {
     fn for_each_entry<R>(
@@ -836,6 +1025,15 @@ fn for_each_entry<R>(
         };
         None.or_else(|| f("is-float", member_function(BuiltinFunction::StringIsFloat)))
             .or_else(|| f("to-float", member_function(BuiltinFunction::StringToFloat)))
+            .or_else(|| f("to-uppercase", member_function(BuiltinFunction::StringToUppercase)))
+            .or_else(|| f("to-lowercase", member_function(BuiltinFunction::StringToLowercase)))
+            .or_else(|| f("trim", member_function(BuiltinFunction::StringTrim)))
+            .or_else(|| f("contains", member_function(BuiltinFunction::StringContains)))
+            .or_else(|| f("starts-with", member_function(BuiltinFunction::StringStartsWith)))
+            .or_else(|| f("split", member_function(BuiltinFunction::StringSplit)))
+            .or_else(|| {
+                f("character-count", member_function(BuiltinFunction::StringCharacterCount))
+            })
     }
 }
 struct ColorExpression<'a>(&'a Expression);
@@ -902,6 +1100,55 @@ fn for_each_entry<R>(
                 arguments: vec![self.0.clone()],
             })
         };
+        // The functions taking a lambda are resolved when they are called, see `Expression::from_array_function_call`
+        let array_function = |f: BuiltinMacroFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(),
+                member: Box::new(Expression::BuiltinMacroReference(f, ctx.current_token.clone())),
+            })
+        };
         None.or_else(|| f("length", member_function(BuiltinFunction::ArrayLength)))
+            .or_else(|| f("filter", array_function(BuiltinMacroFunction::ArrayFilter)))
+            .or_else(|| f("map", array_function(BuiltinMacroFunction::ArrayMap)))
+            .or_else(|| f("any", array_function(BuiltinMacroFunction::ArrayAny)))
+            .or_else(|| f("all", array_function(BuiltinMacroFunction::ArrayAll)))
+            .or_else(|| f("sum", array_function(BuiltinMacroFunction::ArraySum)))
+            .or_else(|| f("index-of", array_function(BuiltinMacroFunction::ArrayIndexOf)))
+            .or_else(|| f("slice", array_function(BuiltinMacroFunction::ArraySlice)))
+    }
+}
+
+struct DateTimeExpression<'a>(&'a Expression);
+impl<'a> LookupObject for DateTimeExpression<'a> {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let source_location = || ctx.current_token.as_ref().map(|t| t.to_source_location());
+        let field_access = |f: BuiltinFunction| {
+            LookupResult::from(Expression::FunctionCall {
+                function: Box::new(Expression::BuiltinFunctionReference(f, source_location())),
+                source_location: source_location(),
+                arguments: vec![self.0.clone()],
+            })
+        };
+        let member_function = |f: BuiltinFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinFunctionReference(f, source_location())),
+            })
+        };
+        use crate::expression_tree::DateTimeComponent::*;
+        for component in [Year, Month, Day, Hour, Minute, Second, DayOfWeek] {
+            let function = BuiltinFunction::DateTimeComponent(component);
+            if let Some(r) = f(component.name(), field_access(function)) {
+                return Some(r);
+            }
+        }
+        None.or_else(|| f("timestamp", field_access(BuiltinFunction::DateTimeTimestamp)))
+            .or_else(|| f("to-iso-string", member_function(BuiltinFunction::DateTimeToIsoString)))
     }
 }
@@ -11,7 +11,7 @@
 
 use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
 use crate::expression_tree::{self, BindingExpression, Expression, Unit};
-use crate::langtype::{BuiltinElement, NativeClass, Type};
+use crate::langtype::{BuiltinElement, Enumeration, NativeClass, Type};
 use crate::langtype::{ElementType, PropertyLookupResult};
 use crate::layout::{LayoutConstraints, Orientation};
 use crate::namedreference::NamedReference;
@@ -21,7 +21,7 @@
 use crate::typeregister::TypeRegister;
 use std::cell::{Cell, RefCell};
 use std::collections::btree_map::Entry;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
 use std::rc::{Rc, Weak};
 
@@ -42,7 +42,11 @@ macro_rules! unwrap_or_continue {
 pub struct Document {
     pub node: Option<syntax_nodes::Document>,
     pub inner_components: Vec<Rc<Component>>,
-    pub inner_structs: Vec<Type>,
+    /// The generic components declared in this document. They are type-checked once, with
+    /// placeholders for their generic parameters, but only their instances are compiled.
+    pub generic_components: Vec<Rc<Component>>,
+    /// The structs and enums declared in this document
+    pub inner_types: Vec<Type>,
     pub root_component: Rc<Component>,
     pub local_registry: TypeRegister,
     /// A list of paths to .ttf/.ttc files that are supposed to be registered on
@@ -63,7 +67,8 @@ pub fn from_node(
 
         let mut local_registry = TypeRegister::new(parent_registry);
         let mut inner_components = vec![];
-        let mut inner_structs = vec![];
+        let mut generic_components = vec![];
+        let mut inner_types = vec![];
 
         let mut process_component =
             |n: syntax_nodes::Component,
@@ -71,22 +76,65 @@ pub fn from_node(
              local_registry: &mut TypeRegister| {
                 let compo = Component::from_node(n, diag, local_registry);
                 local_registry.add(compo.clone());
-                inner_components.push(compo);
+                // Generic components are only compiled once instantiated with concrete types
+                if compo.generic.is_none() {
+                    inner_components.push(compo);
+                } else {
+                    generic_components.push(compo);
+                }
             };
         let mut process_struct =
             |n: syntax_nodes::StructDeclaration,
              diag: &mut BuildDiagnostics,
              local_registry: &mut TypeRegister| {
                 let mut ty = type_struct_from_node(n.ObjectType(), diag, local_registry);
-                if let Type::Struct { name, .. } = &mut ty {
+                if let Type::Struct { name, definition, .. } = &mut ty {
                     *name = parser::identifier_text(&n.DeclaredIdentifier());
+                    let object_type = n.ObjectType();
+                    if object_type.ObjectTypeMember().any(|m| m.Expression().is_some())
+                        || object_type.Function().next().is_some()
+                    {
+                        // Filled by the resolving pass
+                        *definition = Some(Default::default());
+                    }
                 } else {
                     assert!(diag.has_error());
                     return;
                 }
                 local_registry.insert_type(ty.clone());
-                inner_structs.push(ty);
+                inner_types.push(ty);
             };
+        let mut process_enum = |n: syntax_nodes::EnumDeclaration,
+                                diag: &mut BuildDiagnostics,
+                                local_registry: &mut TypeRegister| {
+            let Some(name) = parser::identifier_text(&n.DeclaredIdentifier()) else {
+                assert!(diag.has_error());
+                return;
+            };
+            let mut values = Vec::<String>::new();
+            for value in n.EnumValue() {
+                let Some(v) = parser::identifier_text(&value) else { continue };
+                if values.contains(&v) {
+                    diag.push_error(format!("Duplicated enum value '{}'", v), &value);
+                    continue;
+                }
+                values.push(v);
+            }
+            if values.is_empty() {
+                diag.push_error(
+                    "An enum must have at least one value".into(),
+                    &n.DeclaredIdentifier(),
+                );
+            }
+            let ty = Type::Enumeration(Rc::new(Enumeration {
+                name,
+                values,
+                default_value: 0,
+                node: Some(n),
+            }));
+            local_registry.insert_type(ty.clone());
+            inner_types.push(ty);
+        };
 
         for n in node.children() {
             match n.kind() {
@@ -94,6 +142,7 @@ pub fn from_node(
                 SyntaxKind::StructDeclaration => {
                     process_struct(n.into(), diag, &mut local_registry)
                 }
+                SyntaxKind::EnumDeclaration => process_enum(n.into(), diag, &mut local_registry),
                 SyntaxKind::ExportsList => {
                     for n in n.children() {
                         match n.kind() {
@@ -103,6 +152,9 @@ pub fn from_node(
                             SyntaxKind::StructDeclaration => {
                                 process_struct(n.into(), diag, &mut local_registry)
                             }
+                            SyntaxKind::EnumDeclaration => {
+                                process_enum(n.into(), diag, &mut local_registry)
+                            }
                             _ => {}
                         }
                     }
@@ -190,7 +242,8 @@ pub fn from_node(
             node: Some(node),
             root_component,
             inner_components,
-            inner_structs,
+            generic_components,
+            inner_types,
             local_registry,
             custom_fonts,
             exports,
@@ -203,18 +256,87 @@ pub struct PopupWindow {
     pub component: Rc<Component>,
     pub x: NamedReference,
     pub y: NamedReference,
+    pub close_policy: NamedReference,
     pub parent_element: ElementRc,
 }
 
 type ChildrenInsertionPoint = (ElementRc, syntax_nodes::ChildrenPlaceholder);
 
+/// The `@children` placeholders found while creating the elements of a component
+#[derive(Default)]
+pub struct ChildrenInsertionPoints {
+    /// The `@children` placeholder
+    default: Option<ChildrenInsertionPoint>,
+    /// The `@children(name)` placeholders, by name
+    named: BTreeMap<String, ChildrenInsertionPoint>,
+}
+
+impl ChildrenInsertionPoints {
+    fn first(&self) -> Option<&syntax_nodes::ChildrenPlaceholder> {
+        self.default.as_ref().or_else(|| self.named.values().next()).map(|(_, node)| node)
+    }
+}
+
+/// The generic parameters of a component declared as `component Foo<T> { ... }`
+#[derive(Debug)]
+pub struct GenericComponentInfo {
+    /// The names of the generic parameters
+    pub parameters: Vec<String>,
+    /// The type register in which the component was declared
+    pub type_register: TypeRegister,
+    /// The components already instantiated, with the types of their generic parameters
+    pub instances: RefCell<Vec<(Vec<Type>, Rc<Component>)>>,
+    /// The diagnostics already reported for the body of the component, so that the errors
+    /// found in several instances are only reported once
+    pub reported_diagnostics: RefCell<HashSet<String>>,
+}
+
+impl GenericComponentInfo {
+    /// Returns a type register in which the generic parameters are the given types
+    pub fn type_register_for(&self, types: &[Type]) -> TypeRegister {
+        let mut tr = self.type_register.clone();
+        for (name, ty) in self.parameters.iter().zip(types) {
+            tr.insert_type_with_name(ty.clone(), name.clone());
+        }
+        tr
+    }
+
+    /// Returns a type register in which the generic parameters are placeholders, to type-check
+    /// the generic component itself
+    pub fn placeholder_type_register(&self) -> TypeRegister {
+        let placeholders =
+            self.parameters.iter().map(|p| Self::placeholder_type(p)).collect::<Vec<_>>();
+        self.type_register_for(&placeholders)
+    }
+
+    /// Returns the placeholder type that stands for a generic parameter in the generic component
+    fn placeholder_type(name: &str) -> Type {
+        Type::Struct {
+            fields: Default::default(),
+            name: Some(name.into()),
+            node: None,
+            definition: None,
+        }
+    }
+
+    /// If `ty` is the placeholder of one of the generic parameters, returns the index of that parameter
+    pub fn parameter_index(&self, ty: &Type) -> Option<usize> {
+        match ty {
+            Type::Struct { fields, name: Some(name), node: None, .. } if fields.is_empty() => {
+                self.parameters.iter().position(|p| p == name)
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Used sub types for a root component
 #[derive(Debug, Default)]
 pub struct UsedSubTypes {
     /// All the globals used by the component and its children.
     pub globals: Vec<Rc<Component>>,
-    /// All the structs used by the component and its children.
-    pub structs: Vec<Type>,
+    /// All the structs and enums used by the component and its children.
+    pub structs_and_enums: Vec<Type>,
     /// All the sub components use by this components and its children,
     /// and the amount of time it is used
     pub sub_components: Vec<Rc<Component>>,
@@ -278,6 +400,10 @@ pub struct Component {
     /// the element pointer to by this field.
     pub child_insertion_point: RefCell<Option<ChildrenInsertionPoint>>,
 
+    /// The insertion points of the `@children(name)` placeholders, by name. The children
+    /// that have `slot: name;` are appended to the children of these elements.
+    pub named_child_insertion_points: RefCell<BTreeMap<String, ChildrenInsertionPoint>>,
+
     pub init_code: RefCell<InitCode>,
 
     /// The list of used extra types used (recursively) by this root component.
@@ -293,9 +419,17 @@ pub struct Component {
     /// This is used to issue better error in the generated code if the property is used.
     pub private_properties: RefCell<Vec<(String, Type)>>,
 
+    /// Used to give unique names to the local variables that the compiler introduces in the
+    /// expressions of this component, see [`crate::lookup::LookupCtx::unique_local_name`]
+    pub local_variable_counter: Cell<usize>,
+
     /// This is the main entry point for the code generators. Such a component
     /// should have the full API, etc.
     pub is_root_component: Cell<bool>,
+
+    /// Set if this is a generic component. A generic component is not compiled by itself, but
+    /// instantiated for each list of types of its generic parameters by the `generic_components` pass.
+    pub generic: Option<GenericComponentInfo>,
 }
 
 impl Component {
@@ -304,7 +438,51 @@ pub fn from_node(
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
     ) -> Rc<Self> {
-        let mut child_insertion_point = None;
+        let parameters = node
+            .GenericParameters()
+            .map(|g| g.DeclaredIdentifier().filter_map(|i| parser::identifier_text(&i)).collect())
+            .unwrap_or_else(Vec::<String>::new);
+        if parameters.is_empty() {
+            return Self::from_node_impl(node, diag, tr, None);
+        }
+        for (i, p) in parameters.iter().enumerate() {
+            if parameters[..i].contains(p) {
+                diag.push_error(
+                    format!("Duplicated generic parameter '{}'", p),
+                    &node.GenericParameters().unwrap().DeclaredIdentifier().nth(i).unwrap(),
+                );
+            }
+        }
+        let mut generic_tr = tr.clone();
+        for p in &parameters {
+            generic_tr.insert_type_with_name(GenericComponentInfo::placeholder_type(p), p.clone());
+        }
+        let generic = GenericComponentInfo {
+            parameters,
+            type_register: tr.clone(),
+            instances: Default::default(),
+            reported_diagnostics: Default::default(),
+        };
+        Self::from_node_impl(node, diag, &generic_tr, Some(generic))
+    }
+
+    /// Creates the component from the node of a generic component, for the concrete types of
+    /// its generic parameters which are registered in `tr`.
+    pub fn instantiate_generic(
+        node: syntax_nodes::Component,
+        diag: &mut BuildDiagnostics,
+        tr: &TypeRegister,
+    ) -> Rc<Self> {
+        Self::from_node_impl(node, diag, tr, None)
+    }
+
+    fn from_node_impl(
+        node: syntax_nodes::Component,
+        diag: &mut BuildDiagnostics,
+        tr: &TypeRegister,
+        generic: Option<GenericComponentInfo>,
+    ) -> Rc<Self> {
+        let mut child_insertion_points = ChildrenInsertionPoints::default();
         let is_legacy_syntax = node.child_token(SyntaxKind::ColonEqual).is_some();
         let c = Component {
             node: Some(node.clone().into()),
@@ -317,12 +495,14 @@ pub fn from_node(
                 } else {
                     ElementType::Error
                 },
-                &mut child_insertion_point,
+                &mut child_insertion_points,
                 is_legacy_syntax,
                 diag,
                 tr,
             ),
-            child_insertion_point: RefCell::new(child_insertion_point),
+            child_insertion_point: RefCell::new(child_insertion_points.default),
+            named_child_insertion_points: RefCell::new(child_insertion_points.named),
+            generic,
             ..Default::default()
         };
         let c = Rc::new(c);
@@ -416,6 +596,10 @@ pub struct PropertyDeclaration {
     pub visibility: PropertyVisibility,
     /// For function or callback: whether it is declared as `pure` (None for private function for which this has to be deduced)
     pub pure: Option<bool>,
+    /// Declared with the `const` keyword: the binding must be a constant expression, and the property cannot be set
+    pub is_const: bool,
+    /// For functions: the default value of each argument, resolved once in the scope of the declaration
+    pub default_arguments: Vec<Option<Expression>>,
 }
 
 impl PropertyDeclaration {
@@ -540,6 +724,10 @@ pub struct Element {
     pub states: Vec<State>,
     pub transitions: Vec<Transition>,
 
+    /// The `changed <property> => { ... }` handlers, by property name.
+    /// There can be several handlers for the same property after inlining.
+    pub change_callbacks: BTreeMap<String, RefCell<Vec<Expression>>>,
+
     /// true when this item's geometry is handled by a layout
     pub child_of_layout: bool,
     /// The property pointing to the layout info. `(horizontal, vertical)`
@@ -568,6 +756,10 @@ pub struct Element {
     /// How many times the element was inlined
     pub inline_depth: i32,
 
+    /// The name of the `@children(name)` placeholder of the parent component in which this
+    /// element is inserted, set with `slot: name;`
+    pub slot: Option<String>,
+
     /// The AST node, if available
     pub node: Option<syntax_nodes::Element>,
 }
@@ -648,6 +840,14 @@ macro_rules! indent {
         indent!();
         writeln!(f, "transitions {:?} ", e.transitions)?;
     }
+    for (name, ch) in &e.change_callbacks {
+        for ex in &*ch.borrow() {
+            indent!();
+            write!(f, "changed {} => ", name)?;
+            expression_tree::pretty_print(f, ex)?;
+            writeln!(f)?;
+        }
+    }
     for c in &e.children {
         indent!();
         pretty_print(f, &c.borrow(), indentation)?
@@ -713,6 +913,12 @@ pub struct ListViewInfo {
     pub listview_height: NamedReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: NamedReference,
+    /// The `first-visible-row` and `last-visible-row` properties, set by the repeater
+    pub first_visible_row: NamedReference,
+    pub last_visible_row: NamedReference,
+    /// The row requested by `scroll-to()`, or -1 if there is no pending request
+    pub scroll_request_row: NamedReference,
+    pub scroll_request_alignment: NamedReference,
 }
 
 #[derive(Debug, Clone)]
@@ -736,7 +942,7 @@ pub fn from_node(
         node: syntax_nodes::Element,
         id: String,
         parent_type: ElementType,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_legacy_syntax: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -765,12 +971,11 @@ pub fn from_node(
             };
             node.SubElement().for_each(|n| error_on(&n, "sub elements"));
             node.RepeatedElement().for_each(|n| error_on(&n, "sub elements"));
-            if let Some(n) = node.ChildrenPlaceholder() {
-                error_on(&n, "sub elements");
-            }
+            node.ChildrenPlaceholder().for_each(|n| error_on(&n, "sub elements"));
             node.PropertyAnimation().for_each(|n| error_on(&n, "animations"));
             node.States().for_each(|n| error_on(&n, "states"));
             node.Transitions().for_each(|n| error_on(&n, "transitions"));
+            node.PropertyChangedCallback().for_each(|n| error_on(&n, "change callbacks"));
             node.CallbackDeclaration().for_each(|cb| {
                 if parser::identifier_text(&cb.DeclaredIdentifier()).map_or(false, |s| s == "init")
                 {
@@ -842,11 +1047,16 @@ pub fn from_node(
             }
 
             let mut visibility = None;
+            let mut is_const = false;
             for token in prop_decl.children_with_tokens() {
                 if token.kind() != SyntaxKind::Identifier {
                     continue;
                 }
                 match (token.as_token().unwrap().text(), visibility) {
+                    ("const", _) if is_const => {
+                        diag.push_error("Extra 'const' keyword".into(), &token)
+                    }
+                    ("const", _) => is_const = true,
                     ("in", None) => visibility = Some(PropertyVisibility::Input),
                     ("in", Some(_)) => diag.push_error("Extra 'in' keyword".into(), &token),
                     ("out", None) => visibility = Some(PropertyVisibility::Output),
@@ -864,18 +1074,43 @@ pub fn from_node(
             }
             let visibility = visibility.unwrap_or_else(|| {
                 if is_legacy_syntax {
-                    PropertyVisibility::InOut
+                    if is_const {
+                        PropertyVisibility::Output
+                    } else {
+                        PropertyVisibility::InOut
+                    }
                 } else {
                     PropertyVisibility::Private
                 }
             });
 
+            if is_const {
+                if matches!(visibility, PropertyVisibility::Input | PropertyVisibility::InOut) {
+                    diag.push_error(
+                        format!("A const property cannot be declared as {}", visibility),
+                        &prop_decl,
+                    );
+                }
+                if prop_decl.TwoWayBinding().is_some() {
+                    diag.push_error(
+                        "A const property cannot be declared with a two-way binding".into(),
+                        &prop_decl.DeclaredIdentifier(),
+                    );
+                } else if prop_decl.BindingExpression().is_none() {
+                    diag.push_error(
+                        format!("The const property '{}' must have a value", prop_name),
+                        &prop_decl.DeclaredIdentifier(),
+                    );
+                }
+            }
+
             r.property_declarations.insert(
                 prop_name.to_string(),
                 PropertyDeclaration {
                     property_type: prop_type,
                     node: Some(prop_decl.clone().into()),
                     visibility,
+                    is_const,
                     ..Default::default()
                 },
             );
@@ -907,8 +1142,16 @@ pub fn from_node(
             }
         }
 
+        let has_slot_property = r.lookup_property("slot").property_type != Type::Invalid;
+        let (slot_bindings, bindings): (Vec<_>, Vec<_>) = node.Binding().partition(|b| {
+            !has_slot_property
+                && b.child_text(SyntaxKind::Identifier).map_or(false, |t| t == "slot")
+        });
+        for b in slot_bindings {
+            r.slot = slot_from_binding(b, &parent_type, diag);
+        }
         r.parse_bindings(
-            node.Binding().filter_map(|b| {
+            bindings.into_iter().filter_map(|b| {
                 Some((b.child_token(SyntaxKind::Identifier)?, b.BindingExpression().into()))
             }),
             is_legacy_syntax,
@@ -1020,6 +1263,7 @@ pub fn from_node(
 
             let mut args = vec![];
             let mut arg_names = vec![];
+            let mut has_default_value = false;
             for a in func.ArgumentDeclaration() {
                 args.push(type_from_node(a.Type(), diag, tr));
                 let name =
@@ -1030,6 +1274,14 @@ pub fn from_node(
                         &a.DeclaredIdentifier(),
                     );
                 }
+                if a.Expression().is_some() {
+                    has_default_value = true;
+                } else if has_default_value {
+                    diag.push_error(
+                        format!("The argument '{name}' must have a default value because it follows an argument with a default value"),
+                        &a.DeclaredIdentifier(),
+                    );
+                }
                 arg_names.push(name);
             }
             let return_type = Box::new(
@@ -1111,6 +1363,51 @@ pub fn from_node(
             }
         }
 
+        for ch in node.PropertyChangedCallback() {
+            if r.base_type == ElementType::Global {
+                // Error reported above
+                break;
+            }
+            let prop_name_node = ch.DeclaredIdentifier();
+            let unresolved_name =
+                unwrap_or_continue!(parser::identifier_text(&prop_name_node); diag);
+            let lookup_result = r.lookup_property(&unresolved_name);
+            if !lookup_result.is_valid() {
+                diag.push_error(
+                    format!("Property '{}' does not exist", unresolved_name),
+                    &prop_name_node,
+                );
+                continue;
+            } else if !lookup_result.property_type.is_property_type() {
+                let what = match lookup_result.property_type {
+                    Type::Function { .. } => "a function",
+                    Type::Callback { .. } | Type::InferredCallback => "a callback",
+                    _ => "not a property",
+                };
+                diag.push_error(
+                    format!(
+                        "Change callback can only be set on properties, and '{}' is {}",
+                        unresolved_name, what
+                    ),
+                    &prop_name_node,
+                );
+                continue;
+            }
+            let handler = Expression::Uncompiled(ch.clone().into());
+            match r.change_callbacks.entry(lookup_result.resolved_name.into_owned()) {
+                Entry::Vacant(e) => {
+                    e.insert(RefCell::new(vec![handler]));
+                }
+                Entry::Occupied(mut e) => {
+                    diag.push_error(
+                        format!("Duplicated change callback on '{}'", unresolved_name),
+                        &prop_name_node,
+                    );
+                    e.get_mut().get_mut().push(handler);
+                }
+            }
+        }
+
         for anim in node.PropertyAnimation() {
             if let Some(star) = anim.child_token(SyntaxKind::Star) {
                 diag.push_error(
@@ -1175,7 +1472,7 @@ pub fn from_node(
             }
         }
 
-        let mut children_placeholder = None;
+        let mut has_children_placeholder = false;
         let r = ElementRc::new(RefCell::new(r));
 
         for se in node.children() {
@@ -1184,68 +1481,83 @@ pub fn from_node(
                 r.borrow_mut().children.push(Element::from_sub_element_node(
                     se.into(),
                     parent_type,
-                    component_child_insertion_point,
+                    component_child_insertion_points,
                     is_legacy_syntax,
                     diag,
                     tr,
                 ));
             } else if se.kind() == SyntaxKind::RepeatedElement {
-                let mut sub_child_insertion_point = None;
+                let mut sub_child_insertion_points = ChildrenInsertionPoints::default();
                 let rep = Element::from_repeated_node(
                     se.into(),
                     &r,
-                    &mut sub_child_insertion_point,
+                    &mut sub_child_insertion_points,
                     is_legacy_syntax,
                     diag,
                     tr,
                 );
-                if let Some((_, se)) = sub_child_insertion_point {
+                if let Some(se) = sub_child_insertion_points.first() {
                     diag.push_error(
                         "The @children placeholder cannot appear in a repeated element".into(),
-                        &se,
+                        se,
                     )
                 }
                 r.borrow_mut().children.push(rep);
             } else if se.kind() == SyntaxKind::ConditionalElement {
-                let mut sub_child_insertion_point = None;
+                let mut sub_child_insertion_points = ChildrenInsertionPoints::default();
                 let rep = Element::from_conditional_node(
                     se.into(),
                     r.borrow().base_type.clone(),
-                    &mut sub_child_insertion_point,
+                    &mut sub_child_insertion_points,
                     is_legacy_syntax,
                     diag,
                     tr,
                 );
-                if let Some((_, se)) = sub_child_insertion_point {
+                if let Some(se) = sub_child_insertion_points.first() {
                     diag.push_error(
                         "The @children placeholder cannot appear in a conditional element".into(),
-                        &se,
+                        se,
                     )
                 }
                 r.borrow_mut().children.push(rep);
             } else if se.kind() == SyntaxKind::ChildrenPlaceholder {
-                if children_placeholder.is_some() {
-                    diag.push_error(
+                let children_placeholder = syntax_nodes::ChildrenPlaceholder::from(se.clone());
+                match children_placeholder
+                    .DeclaredIdentifier()
+                    .and_then(|n| parser::identifier_text(&n))
+                {
+                    None if has_children_placeholder => diag.push_error(
                         "The @children placeholder can only appear once in an element".into(),
                         &se,
-                    )
-                } else {
-                    children_placeholder = Some(se.clone().into());
+                    ),
+                    None if component_child_insertion_points.default.is_some() => diag.push_error(
+                        "The @children placeholder can only appear once in an element hierarchy"
+                            .into(),
+                        &se,
+                    ),
+                    None => {
+                        has_children_placeholder = true;
+                        component_child_insertion_points.default =
+                            Some((r.clone(), children_placeholder));
+                    }
+                    Some(name) if component_child_insertion_points.named.contains_key(&name) => {
+                        diag.push_error(
+                            format!(
+                                "The @children({}) placeholder can only appear once in an element hierarchy",
+                                name
+                            ),
+                            &se,
+                        )
+                    }
+                    Some(name) => {
+                        component_child_insertion_points
+                            .named
+                            .insert(name, (r.clone(), children_placeholder));
+                    }
                 }
             }
         }
 
-        if let Some(children_placeholder) = children_placeholder {
-            if component_child_insertion_point.is_some() {
-                diag.push_error(
-                    "The @children placeholder can only appear once in an element hierarchy".into(),
-                    &children_placeholder,
-                )
-            } else {
-                *component_child_insertion_point = Some((r.clone(), children_placeholder));
-            }
-        }
-
         for state in node.States().flat_map(|s| s.State()) {
             let s = State {
                 id: parser::identifier_text(&state.DeclaredIdentifier()).unwrap_or_default(),
@@ -1301,7 +1613,7 @@ pub fn from_node(
     fn from_sub_element_node(
         node: syntax_nodes::SubElement,
         parent_type: ElementType,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_in_legacy_component: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -1317,7 +1629,7 @@ fn from_sub_element_node(
             node.Element(),
             id,
             parent_type,
-            component_child_insertion_point,
+            component_child_insertion_points,
             is_in_legacy_component,
             diag,
             tr,
@@ -1327,7 +1639,7 @@ fn from_sub_element_node(
     fn from_repeated_node(
         node: syntax_nodes::RepeatedElement,
         parent: &ElementRc,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_in_legacy_component: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -1339,6 +1651,10 @@ fn from_repeated_node(
                 viewport_width: NamedReference::new(parent, "viewport-width"),
                 listview_height: NamedReference::new(parent, "visible-height"),
                 listview_width: NamedReference::new(parent, "visible-width"),
+                first_visible_row: NamedReference::new(parent, "first-visible-row"),
+                last_visible_row: NamedReference::new(parent, "last-visible-row"),
+                scroll_request_row: NamedReference::new(parent, "scroll-request-row"),
+                scroll_request_alignment: NamedReference::new(parent, "scroll-request-alignment"),
             })
         } else {
             None
@@ -1359,7 +1675,7 @@ fn from_repeated_node(
         let e = Element::from_sub_element_node(
             node.SubElement(),
             parent.borrow().base_type.clone(),
-            component_child_insertion_point,
+            component_child_insertion_points,
             is_in_legacy_component,
             diag,
             tr,
@@ -1371,7 +1687,7 @@ fn from_repeated_node(
     fn from_conditional_node(
         node: syntax_nodes::ConditionalElement,
         parent_type: ElementType,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_in_legacy_component: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -1386,7 +1702,7 @@ fn from_conditional_node(
         let e = Element::from_sub_element_node(
             node.SubElement(),
             parent_type,
-            component_child_insertion_point,
+            component_child_insertion_points,
             is_in_legacy_component,
             diag,
             tr,
@@ -1608,6 +1924,46 @@ fn apply_default_type_properties(element: &mut Element) {
     }
 }
 
+/// Returns the name of the slot from the `slot: name;` binding of an element, if the component
+/// in which the element is inserted has a `@children(name)` placeholder
+fn slot_from_binding(
+    binding: syntax_nodes::Binding,
+    parent_type: &ElementType,
+    diag: &mut BuildDiagnostics,
+) -> Option<String> {
+    let qualified_name = binding
+        .BindingExpression()
+        .Expression()
+        .and_then(|e| e.QualifiedName())
+        .filter(|n| n.child_token(SyntaxKind::Dot).is_none());
+    let qualified_name = match qualified_name {
+        Some(n) => n,
+        None => {
+            diag.push_error(
+                "The slot must be the name of a @children placeholder".into(),
+                &binding.BindingExpression(),
+            );
+            return None;
+        }
+    };
+    let name = parser::identifier_text(&qualified_name)?;
+    match parent_type {
+        ElementType::Component(c)
+            if c.named_child_insertion_points.borrow().contains_key(&name) =>
+        {
+            Some(name)
+        }
+        ElementType::Error => None,
+        _ => {
+            diag.push_error(
+                format!("'{}' has no @children({}) placeholder", parent_type, name),
+                &qualified_name,
+            );
+            None
+        }
+    }
+}
+
 /// Create a Type for this node
 pub fn type_from_node(
     node: syntax_nodes::Type,
@@ -1629,6 +1985,20 @@ pub fn type_from_node(
         }
         prop_type
     } else if let Some(object_node) = node.ObjectType() {
+        for member in object_node.ObjectTypeMember() {
+            if let Some(default_value) = member.Expression() {
+                diag.push_error(
+                    "Default values can only be declared in the fields of a named struct".into(),
+                    &default_value,
+                );
+            }
+        }
+        for function in object_node.Function() {
+            diag.push_error(
+                "Functions can only be declared in a named struct".into(),
+                &function.DeclaredIdentifier(),
+            );
+        }
         type_struct_from_node(object_node, diag, tr)
     } else if let Some(array_node) = node.ArrayType() {
         Type::Array(Box::new(type_from_node(array_node.Type(), diag, tr)))
@@ -1653,7 +2023,7 @@ pub fn type_struct_from_node(
             )
         })
         .collect();
-    Type::Struct { fields, name: None, node: Some(object_node) }
+    Type::Struct { fields, name: None, node: Some(object_node), definition: None }
 }
 
 fn animation_element_from_node(
@@ -1684,6 +2054,48 @@ fn animation_element_from_node(
             diag,
         );
 
+        for con_node in anim.CallbackConnection() {
+            let unresolved_name = unwrap_or_continue!(parser::identifier_text(&con_node); diag);
+            let PropertyLookupResult { resolved_name, property_type, .. } =
+                anim_element.lookup_property(&unresolved_name);
+            if let Type::Callback { args, .. } = &property_type {
+                let num_arg = con_node.DeclaredIdentifier().count();
+                if num_arg > args.len() {
+                    diag.push_error(
+                        format!(
+                            "'{}' only has {} arguments, but {} were provided",
+                            unresolved_name,
+                            args.len(),
+                            num_arg
+                        ),
+                        &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                    );
+                }
+            } else {
+                diag.push_error(
+                    format!(
+                        "'{}' is not a callback in {}",
+                        unresolved_name, anim_element.base_type
+                    ),
+                    &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                );
+                continue;
+            }
+            if anim_element
+                .bindings
+                .insert(
+                    resolved_name.into_owned(),
+                    BindingExpression::new_uncompiled(con_node.clone().into()).into(),
+                )
+                .is_some()
+            {
+                diag.push_error(
+                    "Duplicated callback".into(),
+                    &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                );
+            }
+        }
+
         apply_default_type_properties(&mut anim_element);
 
         Some(Rc::new(RefCell::new(anim_element)))
@@ -1938,6 +2350,13 @@ fn visit_element_expressions_simple(
         elem.borrow_mut().repeated = Some(r)
     }
     visit_element_expressions_simple(elem, &mut vis);
+    let change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+    for exprs in change_callbacks.values() {
+        for expr in exprs.borrow_mut().iter_mut() {
+            vis(expr, None, &|| Type::Void);
+        }
+    }
+    elem.borrow_mut().change_callbacks = change_callbacks;
     let mut states = std::mem::take(&mut elem.borrow_mut().states);
     for s in &mut states {
         if let Some(cond) = s.condition.as_mut() {
@@ -2024,6 +2443,10 @@ pub fn visit_all_named_references_in_element(
             vis(&mut lv.viewport_width);
             vis(&mut lv.listview_height);
             vis(&mut lv.listview_width);
+            vis(&mut lv.first_visible_row);
+            vis(&mut lv.last_visible_row);
+            vis(&mut lv.scroll_request_row);
+            vis(&mut lv.scroll_request_alignment);
         }
     }
     elem.borrow_mut().repeated = repeated;
@@ -2066,6 +2489,7 @@ pub fn visit_all_named_references(
                 compo.popup_windows.borrow_mut().iter_mut().for_each(|p| {
                     vis(&mut p.x);
                     vis(&mut p.y);
+                    vis(&mut p.close_policy);
                 });
             }
             compo
@@ -2168,7 +2592,9 @@ pub fn from_node(
             |internal_name: &str, internal_name_node: &dyn Spanned, diag: &mut BuildDiagnostics| {
                 if let Ok(ElementType::Component(c)) = type_registry.lookup_element(internal_name) {
                     Some(Either::Left(c))
-                } else if let ty @ Type::Struct { .. } = type_registry.lookup(internal_name) {
+                } else if let ty @ (Type::Struct { .. } | Type::Enumeration(_)) =
+                    type_registry.lookup(internal_name)
+                {
                     Some(Either::Right(ty))
                 } else if type_registry.lookup_element(internal_name).is_ok()
                     || type_registry.lookup(internal_name) != Type::Invalid
@@ -2191,7 +2617,7 @@ pub fn from_node(
             |it: &mut dyn Iterator<Item = (ExportedName, Either<Rc<Component>, Type>)>| {
                 for (name, compo_or_type) in it {
                     match compo_or_type.as_ref().left() {
-                        Some(compo) if !compo.is_global() => {
+                        Some(compo) if !compo.is_global() && compo.generic.is_none() => {
                             last_exported_component = Some(compo.clone())
                         }
                         _ => {}
@@ -2268,6 +2694,21 @@ pub fn from_node(
             ),
         );
 
+        extend_exports(
+            &mut doc.ExportsList().flat_map(|exports| exports.EnumDeclaration()).filter_map(|en| {
+                let name_ident: SyntaxNode = en.DeclaredIdentifier().into();
+                let name = parser::identifier_text(&en.DeclaredIdentifier()).unwrap_or_else(|| {
+                    debug_assert!(diag.has_error());
+                    String::new()
+                });
+
+                let compo_or_type =
+                    resolve_export_to_inner_component_or_import(&name, &name_ident, diag)?;
+
+                Some((ExportedName { name, name_ident }, compo_or_type))
+            }),
+        );
+
         let mut sorted_deduped_exports = Vec::with_capacity(sorted_exports_with_duplicates.len());
         let mut it = sorted_exports_with_duplicates.into_iter().peekable();
         while let Some((exported_name, compo_or_type)) = it.next() {
@@ -298,6 +298,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         Arrow -> "->",
         OrOr -> "||",
         AndAnd -> "&&",
+        Pipe -> "|",
         LBrace -> "{",
         RBrace -> "}",
         LParent -> "(",
@@ -310,6 +311,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         Minus -> "-",
         Star -> "*",
         Div -> "/",
+        Percent -> "%",
         Equal -> "=",
         Colon -> ":",
         Comma -> ",",
@@ -322,32 +324,39 @@ fn source_file(&self) -> Option<&SourceFile> {
     }
     // syntax kind
     {
-        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration ],
+        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
         /// `DeclaredIdentifier := Element { ... }`
-        Component -> [ DeclaredIdentifier, Element ],
+        Component -> [ DeclaredIdentifier, ?GenericParameters, Element ],
+        /// `<T, U>` after the name of a generic component
+        GenericParameters -> [ *DeclaredIdentifier ],
         /// `id := Element { ... }`
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
                      *CallbackDeclaration, *Function, *SubElement, *RepeatedElement, *PropertyAnimation,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *TwoWayBinding, *States, *Transitions, *PropertyChangedCallback,
+                     *ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
         CallbackDeclaration -> [ DeclaredIdentifier, *Type, ?ReturnType, ?TwoWayBinding ],
         Function -> [DeclaredIdentifier, *ArgumentDeclaration, ?ReturnType, CodeBlock ],
-        ArgumentDeclaration -> [DeclaredIdentifier, Type],
+        /// `name: type`, or `name: type = default-value`
+        ArgumentDeclaration -> [DeclaredIdentifier, Type, ?Expression],
         /// `-> type`  (but without the ->)
         ReturnType -> [Type],
         CallbackConnection -> [ *DeclaredIdentifier,  CodeBlock ],
+        /// `changed xxx => { ... }`, where `xxx` is the DeclaredIdentifier
+        PropertyChangedCallback -> [ DeclaredIdentifier, CodeBlock ],
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, *CallbackConnection ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
         QualifiedName-> [],
         /// Wraps single identifier (to disambiguate when there are other identifier in the production)
         DeclaredIdentifier -> [],
-        ChildrenPlaceholder -> [],
+        /// `@children`, or `@children(name)` for a named placeholder
+        ChildrenPlaceholder -> [ ?DeclaredIdentifier ],
         Binding-> [ BindingExpression ],
         /// `xxx <=> something`
         TwoWayBinding -> [ Expression ],
@@ -360,15 +369,25 @@ fn source_file(&self) -> Option<&SourceFile> {
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
                        ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtGradient,
-                       ?MemberAccess ],
+                       ?AtTr, ?MemberAccess, ?Lambda ],
         /// Concatenate the Expressions to make a string (usually expended from a template string)
         StringTemplate -> [*Expression],
         /// `@image-url("foo.png")`
         AtImageUrl -> [],
         /// `@linear-gradient(...)` or `@radial-gradient(...)`
         AtGradient -> [*Expression],
+        /// `@tr("foo", ...)`  // the string is a StringLiteral
+        AtTr -> [?TrContext, ?TrPlural, *Expression],
+        /// `"foo" =>`  in a `AtTr` node
+        TrContext -> [],
+        /// `| "foo" % n`  in a `AtTr` node
+        TrPlural -> [Expression],
+        /// `x => expression`, as argument of the array functions
+        Lambda -> [DeclaredIdentifier, Expression],
         /// expression()
-        FunctionCallExpression -> [*Expression],
+        FunctionCallExpression -> [*Expression, *NamedArgument],
+        /// `name: expression` as argument of a FunctionCallExpression
+        NamedArgument -> [DeclaredIdentifier, Expression],
         /// `expression[index]`
         IndexExpression -> [2 Expression],
         /// `expression += expression`
@@ -398,7 +417,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// There is an identifier "in" or "out", the DeclaredIdentifier is the state name
         Transition -> [?DeclaredIdentifier, *PropertyAnimation],
         /// Export a set of declared components by name
-        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, *ExportModule ],
+        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, *ExportModule, *EnumDeclaration ],
         /// Declare the first identifier to be exported, either under its name or instead
         /// under the name of the second identifier.
         ExportSpecifier -> [ ExportIdentifier, ?ExportName ],
@@ -416,13 +435,17 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// The representation of a type
         Type -> [ ?QualifiedName, ?ObjectType, ?ArrayType ],
         /// `{foo: string, bar: string} `
-        ObjectType ->[ *ObjectTypeMember ],
-        /// `foo: type` inside an ObjectType
-        ObjectTypeMember -> [ Type ],
+        ObjectType ->[ *ObjectTypeMember, *Function ],
+        /// `foo: type` or `foo: type = default-value` inside an ObjectType
+        ObjectTypeMember -> [ Type, ?Expression ],
         /// `[ type ]`
         ArrayType -> [ Type ],
         /// `struct Foo := { ... }
         StructDeclaration -> [DeclaredIdentifier, ObjectType],
+        /// `enum Foo { bli, bla, blu }`
+        EnumDeclaration -> [DeclaredIdentifier, *EnumValue],
+        /// The value is a Identifier
+        EnumValue -> [],
 
     }
 }
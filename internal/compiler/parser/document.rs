@@ -3,7 +3,7 @@
 
 use super::element::{parse_element, parse_element_content};
 use super::prelude::*;
-use super::r#type::parse_struct_declaration;
+use super::r#type::{parse_enum_declaration, parse_struct_declaration};
 
 #[cfg_attr(test, parser_test)]
 /// ```test,Document
@@ -13,6 +13,7 @@
 /// component Q {} Type := Base {} export { Type }
 /// import { Base } from "somewhere"; Type := Base {}
 /// struct Foo { foo: foo }
+/// enum Foo { hello }
 /// /* empty */
 /// ```
 pub fn parse_document(p: &mut impl Parser) -> bool {
@@ -45,6 +46,11 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
                     break;
                 }
             }
+            "enum" => {
+                if !parse_enum_declaration(&mut *p) {
+                    break;
+                }
+            }
             _ => {
                 if !parse_component(&mut *p) {
                     break;
@@ -68,6 +74,8 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
 /// global Struct { property<int> xx; }
 /// component C { property<int> xx; }
 /// component C inherits D { }
+/// component C<T> { in property <[T]> items; }
+/// component C<T, U> inherits D { }
 /// ```
 pub fn parse_component(p: &mut impl Parser) -> bool {
     let simple_component = p.nth(1).kind() == SyntaxKind::ColonEqual;
@@ -87,6 +95,9 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
         drop(p.start_node(SyntaxKind::Element));
         return false;
     }
+    if is_new_component && p.peek().kind() == SyntaxKind::LAngle {
+        parse_generic_parameters(&mut *p);
+    }
     if is_global {
         if p.peek().kind() == SyntaxKind::ColonEqual {
             p.warning("':=' to declare a global is deprecated. Remove the ':='");
@@ -125,6 +136,25 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
     parse_element(&mut *p)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,GenericParameters
+/// <T>
+/// <T, U>
+/// ```
+fn parse_generic_parameters(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::GenericParameters);
+    p.expect(SyntaxKind::LAngle);
+    loop {
+        if !p.start_node(SyntaxKind::DeclaredIdentifier).expect(SyntaxKind::Identifier) {
+            break;
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RAngle);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,QualifiedName
 /// Rectangle
@@ -155,6 +185,7 @@ pub fn parse_qualified_name(p: &mut impl Parser) -> bool {
 /// export { Type as Foo, AnotherType }
 /// export Foo := Item { }
 /// export struct Foo := { foo: bar }
+/// export enum Foo { bar }
 /// export * from "foo";
 /// ```
 fn parse_export(p: &mut impl Parser) -> bool {
@@ -184,6 +215,8 @@ fn parse_export(p: &mut impl Parser) -> bool {
         }
     } else if p.peek().as_str() == "struct" {
         parse_struct_declaration(&mut *p)
+    } else if p.peek().as_str() == "enum" {
+        parse_enum_declaration(&mut *p)
     } else if p.peek().kind == SyntaxKind::Star {
         let mut p = p.start_node(SyntaxKind::ExportModule);
         p.consume(); // *
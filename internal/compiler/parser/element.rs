@@ -37,7 +37,9 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// animate someProp { }
 /// animate * { }
 /// @children
+/// @children(header)
 /// double_binding <=> element.property;
+/// changed someProp => { }
 /// ```
 pub fn parse_element_content(p: &mut impl Parser) {
     let mut had_parse_error = false;
@@ -55,6 +57,12 @@ pub fn parse_element_content(p: &mut impl Parser) {
                 SyntaxKind::Identifier if p.peek().as_str() == "for" => {
                     parse_repeated_element(&mut *p);
                 }
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "changed"
+                        && p.nth(2).kind() == SyntaxKind::FatArrow =>
+                {
+                    parse_changed_callback(&mut *p);
+                }
                 SyntaxKind::Identifier
                     if p.peek().as_str() == "callback"
                         || (p.peek().as_str() == "pure" && p.nth(1).as_str() == "callback") =>
@@ -77,11 +85,11 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     parse_property_declaration(&mut *p);
                 }
                 SyntaxKind::Identifier
-                    if p.nth(1).as_str() == "property"
-                        && matches!(
-                            p.peek().as_str(),
-                            "in" | "out" | "in_out" | "in-out" | "private"
-                        ) =>
+                    if matches!(
+                        p.peek().as_str(),
+                        "in" | "out" | "in_out" | "in-out" | "private" | "const"
+                    ) && (p.nth(1).as_str() == "property"
+                        || (p.nth(1).as_str() == "const" && p.nth(2).as_str() == "property")) =>
                 {
                     parse_property_declaration(&mut *p);
                 }
@@ -108,7 +116,11 @@ pub fn parse_element_content(p: &mut impl Parser) {
                 if p.peek().as_str() == "children" {
                     let mut p =
                         p.start_node_at(checkpoint.clone(), SyntaxKind::ChildrenPlaceholder);
-                    p.consume()
+                    p.consume();
+                    if p.test(SyntaxKind::LParent) {
+                        p.start_node(SyntaxKind::DeclaredIdentifier).expect(SyntaxKind::Identifier);
+                        p.expect(SyntaxKind::RParent);
+                    }
                 } else {
                     p.test(SyntaxKind::Identifier);
                     p.error("Parse error: Expected @children")
@@ -274,6 +286,23 @@ fn parse_callback_connection(p: &mut impl Parser) {
     parse_code_block(&mut *p);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,PropertyChangedCallback
+/// changed foo => {}
+/// changed foo => { bar(); }
+/// ```
+fn parse_changed_callback(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::PropertyChangedCallback);
+    debug_assert_eq!(p.peek().as_str(), "changed");
+    p.consume(); // "changed"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,TwoWayBinding
 /// foo <=> bar;
@@ -348,10 +377,12 @@ fn parse_callback_declaration(p: &mut impl Parser) {
 /// property<string> text: "Something";
 /// property<string> text <=> two.way;
 /// property alias <=> two.way;
+/// const property <int> answer: 42;
+/// out const property <length> margin: 4px * 2;
 /// ```
 fn parse_property_declaration(p: &mut impl Parser) {
     let checkpoint = p.checkpoint();
-    while matches!(p.peek().as_str(), "in" | "out" | "in-out" | "in_out" | "private") {
+    while matches!(p.peek().as_str(), "in" | "out" | "in-out" | "in_out" | "private" | "const") {
         p.consume();
     }
     if p.peek().as_str() != "property" {
@@ -397,6 +428,7 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 1000; animation-finished => { foo(); } }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -422,14 +454,15 @@ fn parse_property_animation(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::FatArrow | SyntaxKind::LParent => parse_callback_connection(&mut *p),
                 _ => {
                     p.consume();
-                    p.error("Only bindings are allowed in animations");
+                    p.error("Only bindings and callback handlers are allowed in animations");
                 }
             },
             _ => {
                 p.consume();
-                p.error("Only bindings are allowed in animations");
+                p.error("Only bindings and callback handlers are allowed in animations");
             }
         }
     }
@@ -575,8 +608,9 @@ fn parse_transition_inner(p: &mut impl Parser) -> bool {
 /// function bar(xx : int) { yy = xx; }
 /// function bar(xx : int,) -> int { return 42; }
 /// public function aa(x: int, b: {a: int}, c: int) {}
+/// function bb(x: int, y: int = 42, z: string = "foo") {}
 /// ```
-fn parse_function(p: &mut impl Parser) {
+pub fn parse_function(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::Function);
     if p.peek().as_str() == "public" {
         p.consume();
@@ -611,6 +645,9 @@ fn parse_function(p: &mut impl Parser) {
             }
             p.expect(SyntaxKind::Colon);
             parse_type(&mut *p);
+            if p.test(SyntaxKind::Equal) {
+                parse_expression(&mut *p);
+            }
             if !p.test(SyntaxKind::Comma) {
                 break;
             }
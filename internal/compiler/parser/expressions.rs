@@ -30,6 +30,7 @@
 /// array[index]
 /// {object:42}
 /// "foo".bar.something().something.xx({a: 1.foo}.a)
+/// model.filter(x => x.checked && x.value > 2)
 /// ```
 pub fn parse_expression(p: &mut impl Parser) -> bool {
     p.peek(); // consume the whitespace so they aren't part of the Expression node
@@ -56,6 +57,11 @@ fn parse_expression_helper(p: &mut impl Parser, precedence: OperatorPrecedence)
     let mut p = p.start_node(SyntaxKind::Expression);
     let checkpoint = p.checkpoint();
     match p.nth(0).kind() {
+        SyntaxKind::Identifier if p.nth(1).kind() == SyntaxKind::FatArrow => {
+            // The body of the lambda extends as far as possible
+            parse_lambda(&mut *p);
+            return true;
+        }
         SyntaxKind::Identifier => {
             parse_qualified_name(&mut *p);
         }
@@ -212,6 +218,7 @@ fn parse_expression_helper(p: &mut impl Parser, precedence: OperatorPrecedence)
 /// ```test
 /// @image-url("/foo/bar.png")
 /// @linear-gradient(0deg, blue, red)
+/// @tr("foo")
 /// ```
 fn parse_at_keyword(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().kind(), SyntaxKind::At);
@@ -230,14 +237,74 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "radial-gradient" | "radial_gradient" => {
             parse_gradient(p);
         }
+        "tr" => {
+            parse_tr(p);
+        }
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'linear-gradient' or 'radial-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'linear-gradient' or 'radial-gradient' after '@'");
         }
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,AtTr
+/// @tr("foo")
+/// @tr("foo{0}", bar(42))
+/// @tr("context" => "ccc{}", 0)
+/// @tr("xxx" => "ccc{n}" | "ddd{}" % 42, 45)
+/// @tr("foo" | "foos" % count)
+/// ```
+fn parse_tr(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtTr);
+    p.consume(); // "@"
+    p.consume(); // "tr"
+    p.expect(SyntaxKind::LParent);
+
+    let checkpoint = p.checkpoint();
+
+    fn consume_literal(p: &mut impl Parser) -> bool {
+        let peek = p.peek();
+        if peek.kind() != SyntaxKind::StringLiteral
+            || !peek.as_str().starts_with('"')
+            || !peek.as_str().ends_with('"')
+        {
+            p.error("Expected plain string literal");
+            return false;
+        }
+        p.expect(SyntaxKind::StringLiteral)
+    }
+
+    if !consume_literal(&mut *p) {
+        return;
+    }
+
+    if p.test(SyntaxKind::FatArrow) {
+        drop(p.start_node_at(checkpoint, SyntaxKind::TrContext));
+        if !consume_literal(&mut *p) {
+            return;
+        }
+    }
+
+    if p.peek().kind() == SyntaxKind::Pipe {
+        let mut p = p.start_node(SyntaxKind::TrPlural);
+        p.consume(); // "|"
+        if !consume_literal(&mut *p) || !p.expect(SyntaxKind::Percent) {
+            let _ = p.start_node(SyntaxKind::Expression);
+            return;
+        }
+        parse_expression(&mut *p);
+    }
+
+    while p.test(SyntaxKind::Comma) {
+        if !parse_expression(&mut *p) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RParent);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Array
 /// [ a, b, c , d]
@@ -281,18 +348,45 @@ fn parse_object_notation(p: &mut impl Parser) {
     p.expect(SyntaxKind::RBrace);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Lambda
+/// x => x
+/// item => item.value > 42 && item.checked
+/// x => y => x + y
+/// ```
+fn parse_lambda(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::Lambda);
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_expression(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test
 /// ()
 /// (foo)
 /// (foo, bar, foo)
 /// (foo, bar(), xx+xx,)
+/// (foo, bar: 42, baz: foo + 1)
 /// ```
 fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::LParent);
 
     while p.nth(0).kind() != SyntaxKind::RParent {
-        parse_expression(&mut *p);
+        if p.nth(0).kind() == SyntaxKind::Identifier && p.nth(1).kind() == SyntaxKind::Colon {
+            let mut p = p.start_node(SyntaxKind::NamedArgument);
+            {
+                let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+                p.expect(SyntaxKind::Identifier);
+            }
+            p.expect(SyntaxKind::Colon);
+            parse_expression(&mut *p);
+        } else {
+            parse_expression(&mut *p);
+        }
         if !p.test(SyntaxKind::Comma) {
             break;
         }
@@ -4,6 +4,8 @@
 //! Module containing the parsing functions for type names
 
 use super::document::parse_qualified_name;
+use super::element::parse_function;
+use super::expressions::parse_expression;
 use super::prelude::*;
 
 #[cfg_attr(test, parser_test)]
@@ -30,6 +32,10 @@ pub fn parse_type(p: &mut impl Parser) {
 /// {a: string}
 /// {a: string,}
 /// {a: { foo: string, bar: int, }, q: {} }
+/// {a: string = "foo", b: length = 10px * 2}
+/// {a: string = "foo"; b: int; }
+/// {w: length; h: length; pure function area() -> length { return w * h / 1px; } }
+/// {function f(x: int) -> int { x * 2 } a: int}
 /// ```
 pub fn parse_type_object(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::ObjectType);
@@ -37,11 +43,19 @@ pub fn parse_type_object(p: &mut impl Parser) {
         return;
     }
     while p.nth(0).kind() != SyntaxKind::RBrace {
+        if p.nth(1).kind() != SyntaxKind::Colon && matches!(p.peek().as_str(), "function" | "pure")
+        {
+            parse_function(&mut *p);
+            continue;
+        }
         let mut p = p.start_node(SyntaxKind::ObjectTypeMember);
         p.expect(SyntaxKind::Identifier);
         p.expect(SyntaxKind::Colon);
         parse_type(&mut *p);
-        if !p.test(SyntaxKind::Comma) {
+        if p.test(SyntaxKind::Equal) {
+            parse_expression(&mut *p);
+        }
+        if !p.test(SyntaxKind::Comma) && !p.test(SyntaxKind::Semicolon) {
             break;
         }
     }
@@ -67,6 +81,7 @@ pub fn parse_type_array(p: &mut impl Parser) {
 /// struct Bar := {}
 /// struct Foo { foo: bar, xxx: { aaa: bbb, } }
 /// struct Bar {}
+/// struct Item { name: string = "untitled"; size: length = 10px; }
 /// ```
 pub fn parse_struct_declaration(p: &mut impl Parser) -> bool {
     debug_assert_eq!(p.peek().as_str(), "struct");
@@ -85,3 +100,36 @@ pub fn parse_struct_declaration(p: &mut impl Parser) -> bool {
     parse_type_object(&mut *p);
     true
 }
+
+#[cfg_attr(test, parser_test)]
+/// ```test,EnumDeclaration
+/// enum Foo {}
+/// enum Foo { el1 }
+/// enum Foo { el1, xxx, yyy }
+/// enum Foo { el1, xxx, yyy, }
+/// ```
+pub fn parse_enum_declaration(p: &mut impl Parser) -> bool {
+    debug_assert_eq!(p.peek().as_str(), "enum");
+    let mut p = p.start_node(SyntaxKind::EnumDeclaration);
+    p.consume(); // "enum"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+
+    if !p.expect(SyntaxKind::LBrace) {
+        return false;
+    }
+    while p.nth(0).kind() != SyntaxKind::RBrace {
+        {
+            let mut p = p.start_node(SyntaxKind::EnumValue);
+            if !p.expect(SyntaxKind::Identifier) {
+                return false;
+            }
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RBrace)
+}
@@ -10,10 +10,10 @@
 mod collect_custom_fonts;
 mod collect_globals;
 mod collect_init_code;
-mod collect_structs;
+mod collect_structs_and_enums;
 mod collect_subcomponents;
 mod compile_paths;
-mod const_propagation;
+pub mod const_propagation;
 mod deduplicate_property_read;
 mod default_geometry;
 #[cfg(feature = "software-renderer")]
@@ -23,6 +23,7 @@
 mod flickable;
 mod focus_item;
 pub mod generate_item_indices;
+mod generic_components;
 pub mod infer_aliases_types;
 mod inlining;
 mod lower_accessibility;
@@ -200,7 +201,7 @@ pub async fn run_passes(
         remove_unused_properties::remove_unused_properties(component);
     }
 
-    collect_structs::collect_structs(doc);
+    collect_structs_and_enums::collect_structs_and_enums(doc);
 
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
@@ -264,9 +265,11 @@ pub fn run_import_passes(
     type_loader: &crate::typeloader::TypeLoader,
     diag: &mut crate::diagnostics::BuildDiagnostics,
 ) {
+    generic_components::instantiate_generic_components(doc, type_loader, diag);
     infer_aliases_types::resolve_aliases(doc, diag);
     resolving::resolve_expressions(doc, type_loader, diag);
     check_expressions::check_expressions(doc, diag);
+    const_propagation::check_constants(doc, diag);
     purity_check::purity_check(doc, diag);
     check_rotation::check_rotation(doc, diag);
     unique_id::check_unique_id(doc, diag);
@@ -15,6 +15,7 @@
 use crate::expression_tree::BuiltinFunction;
 use crate::expression_tree::Expression;
 use crate::langtype::ElementType;
+use crate::langtype::Type;
 
 use crate::layout::LayoutItem;
 use crate::layout::Orientation;
@@ -161,6 +162,19 @@ fn analyze_element(
     for (_, nr) in &elem.borrow().accessibility_props.0 {
         process_property(&PropertyPath::from(nr.clone()), context, reverse_aliases, diag);
     }
+    for (name, handlers) in &elem.borrow().change_callbacks {
+        process_property(
+            &PropertyPath::from(NamedReference::new(elem, name)),
+            context,
+            reverse_aliases,
+            diag,
+        );
+        for e in handlers.borrow().iter() {
+            recurse_expression(e, &mut |prop| {
+                process_property(prop, context, reverse_aliases, diag);
+            });
+        }
+    }
 
     if let Some(component) = elem.borrow().enclosing_component.upgrade() {
         if Rc::ptr_eq(&component.root_element, elem) {
@@ -173,6 +187,32 @@ fn analyze_element(
     }
 }
 
+/// The bindings of an animation are evaluated with the animated property, but its callbacks
+/// are invoked later, so they are analyzed on their own.
+fn analyze_animation_element(
+    elem: &ElementRc,
+    context: &mut AnalysisContext,
+    reverse_aliases: &ReverseAliases,
+    diag: &mut BuildDiagnostics,
+) {
+    let currently_analyzing = std::mem::take(&mut context.currently_analyzing);
+    for (name, binding) in &elem.borrow().bindings {
+        if binding.borrow().analysis.is_some()
+            || !matches!(elem.borrow().lookup_property(name).property_type, Type::Callback { .. })
+        {
+            continue;
+        }
+        analyse_binding(
+            &PropertyPath::from(NamedReference::new(elem, name)),
+            context,
+            reverse_aliases,
+            diag,
+        );
+    }
+    context.currently_analyzing = currently_analyzing;
+    analyze_element(elem, context, reverse_aliases, diag);
+}
+
 #[derive(Copy, Clone, dm::BitAnd, dm::BitOr, dm::BitAndAssign, dm::BitOrAssign)]
 struct DependsOnExternal(bool);
 
@@ -279,11 +319,13 @@ fn analyse_binding(
     }
 
     match &binding.borrow().animation {
-        Some(PropertyAnimation::Static(e)) => analyze_element(e, context, reverse_aliases, diag),
+        Some(PropertyAnimation::Static(e)) => {
+            analyze_animation_element(e, context, reverse_aliases, diag)
+        }
         Some(PropertyAnimation::Transition { animations, state_ref }) => {
             recurse_expression(state_ref, &mut process_prop);
             for a in animations {
-                analyze_element(&a.animation, context, reverse_aliases, diag);
+                analyze_animation_element(&a.animation, context, reverse_aliases, diag);
             }
         }
         None => (),
@@ -18,6 +18,12 @@ pub fn check_expressions(doc: &crate::object_tree::Document, diag: &mut BuildDia
 
 fn check_expression(component: &Rc<Component>, e: &Expression, diag: &mut BuildDiagnostics) {
     match e {
+        Expression::MemberFunction { member, .. }
+            if matches!(**member, Expression::BuiltinMacroReference(..)) =>
+        {
+            // An array function that is not called
+            check_expression(component, member, diag)
+        }
         Expression::MemberFunction { .. } => {
             // Must already have been be reported.
             debug_assert!(diag.has_error());
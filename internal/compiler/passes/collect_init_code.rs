@@ -26,4 +26,9 @@ pub fn collect_init_code(component: &Rc<Component>) {
                 .push(init_callback.into_inner().expression);
         }
     });
+
+    // The popups and child windows run their init code when they are created
+    for popup in component.popup_windows.borrow().iter() {
+        collect_init_code(&popup.component);
+    }
 }
@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint-ui.com>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
-//! Passes that fills the root component used_types.structs
+//! Passes that fills the root component used_types.structs_and_enums
 
 use crate::expression_tree::Expression;
 use crate::langtype::Type;
@@ -9,8 +9,8 @@
 use std::collections::BTreeMap;
 use std::rc::Rc;
 
-/// Fill the root_component's used_types.structs
-pub fn collect_structs(doc: &Document) {
+/// Fill the root_component's used_types.structs_and_enums
+pub fn collect_structs_and_enums(doc: &Document) {
     let mut hash = BTreeMap::new();
 
     for (name, exp) in doc.exports.iter() {
@@ -22,20 +22,20 @@ pub fn collect_structs(doc: &Document) {
     for component in (doc.root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(&doc.root_component))
     {
-        collect_structs_in_component(component, &mut hash)
+        collect_types_in_component(component, &mut hash)
     }
 
     let mut used_types = doc.root_component.used_types.borrow_mut();
-    let used_struct = &mut used_types.structs;
-    *used_struct = Vec::with_capacity(hash.len());
+    let used_types = &mut used_types.structs_and_enums;
+    *used_types = Vec::with_capacity(hash.len());
     while let Some(next) = hash.iter().next() {
         // Here, using BTreeMap::pop_first would be great when it is stable
         let key = next.0.clone();
-        sort_struct(&mut hash, used_struct, &key);
+        sort_types(&mut hash, used_types, &key);
     }
 }
 
-fn collect_structs_in_component(root_component: &Rc<Component>, hash: &mut BTreeMap<String, Type>) {
+fn collect_types_in_component(root_component: &Rc<Component>, hash: &mut BTreeMap<String, Type>) {
     let mut maybe_collect_object = |ty: &Type| {
         visit_named_object(ty, &mut |name, sub_ty| {
             hash.entry(name.clone()).or_insert_with(|| sub_ty.clone());
@@ -52,15 +52,24 @@ fn collect_structs_in_component(root_component: &Rc<Component>, hash: &mut BTree
         expr.visit_recursive(&mut |expr| match expr {
             Expression::Struct { ty, .. } => maybe_collect_object(ty),
             Expression::Array { element_ty, .. } => maybe_collect_object(element_ty),
+            Expression::EnumerationValue(value) => {
+                maybe_collect_object(&Type::Enumeration(value.enumeration.clone()))
+            }
             _ => (),
         })
     });
 }
 
-/// Move the object named `key` from hash to vector, making sure that all object used by
+/// Move the type named `key` from hash to vector, making sure that all types used by
 /// it are placed before in the vector
-fn sort_struct(hash: &mut BTreeMap<String, Type>, vec: &mut Vec<Type>, key: &str) {
+fn sort_types(hash: &mut BTreeMap<String, Type>, vec: &mut Vec<Type>, key: &str) {
     let ty = if let Some(ty) = hash.remove(key) { ty } else { return };
+    if let Type::Enumeration(en) = &ty {
+        if en.node.is_none() {
+            // This is a builtin enum
+            return;
+        }
+    }
     if let Type::Struct { fields, name: Some(name), .. } = &ty {
         if name.contains("::") {
             // This is a builtin type.
@@ -69,7 +78,7 @@ fn sort_struct(hash: &mut BTreeMap<String, Type>, vec: &mut Vec<Type>, key: &str
         }
 
         for sub_ty in fields.values() {
-            visit_named_object(sub_ty, &mut |name, _| sort_struct(hash, vec, name));
+            visit_named_object(sub_ty, &mut |name, _| sort_types(hash, vec, name));
         }
     }
     vec.push(ty)
@@ -85,6 +94,7 @@ fn visit_named_object(ty: &Type, visitor: &mut impl FnMut(&String, &Type)) {
                 visit_named_object(sub_ty, visitor);
             }
         }
+        Type::Enumeration(en) if en.node.is_some() => visitor(&en.name, ty),
         Type::Array(x) => visit_named_object(x, visitor),
         Type::Callback { return_type, args } => {
             if let Some(rt) = return_type {
@@ -139,6 +139,7 @@ fn compile_path_from_string_literal(
         .collect(),
         name: Some("Point".into()),
         node: None,
+        definition: None,
     };
 
     let mut points = Vec::new();
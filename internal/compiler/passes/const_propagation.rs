@@ -3,9 +3,11 @@
 
 //! Try to simplify property bindings by propagating constant expressions
 
+use crate::diagnostics::{BuildDiagnostics, DiagnosticLevel, SourceLocation, Spanned};
 use crate::expression_tree::*;
 use crate::langtype::ElementType;
 use crate::langtype::Type;
+use crate::namedreference::NamedReference;
 use crate::object_tree::*;
 
 pub fn const_propagation(component: &Component) {
@@ -13,19 +15,163 @@ pub fn const_propagation(component: &Component) {
         if matches!(ty(), Type::Callback { .. }) {
             return;
         }
-        simplify_expression(expr);
+        simplify_expression(expr, &mut FoldContext::default());
     });
 }
 
+/// Check that the `const` properties are constant and never set, and report the problems
+/// found while folding the constant sub-expressions (such as a division by zero).
+///
+/// This runs before the binding analysis, so only literals and `const` properties are folded.
+pub fn check_constants(doc: &Document, diag: &mut BuildDiagnostics) {
+    for component in &doc.inner_components {
+        recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+            check_element_constants(elem, diag)
+        });
+    }
+}
+
+/// Returns the value of the expression if it can be folded into a constant
+/// by only inlining the literals and the `const` properties.
+pub fn constant_value(expr: &Expression) -> Option<Expression> {
+    let mut expr = expr.clone();
+    let mut ctx = FoldContext { const_properties_only: true, ..Default::default() };
+    simplify_expression(&mut expr, &mut ctx);
+    (ctx.diagnostics.is_empty() && is_constant_expression(&expr)).then_some(expr)
+}
+
+/// Returns true if the property was declared with the `const` keyword
+pub fn is_const_property(nr: &NamedReference) -> bool {
+    let mut element = nr.element();
+    loop {
+        let next = match element.borrow().property_declarations.get(nr.name()) {
+            Some(decl) => return decl.is_const,
+            None => match &element.borrow().base_type {
+                ElementType::Component(c) => c.root_element.clone(),
+                _ => return false,
+            },
+        };
+        element = next;
+    }
+}
+
+#[derive(Default)]
+struct FoldContext {
+    /// Only inline the `const` properties, because the binding analysis did not run yet
+    const_properties_only: bool,
+    /// The `const` properties being inlined, to stop on binding loops
+    visiting: Vec<NamedReference>,
+    /// The problems found while folding, with their location when it is known
+    diagnostics: Vec<(String, Option<SourceLocation>, DiagnosticLevel)>,
+}
+
+fn check_element_constants(elem: &ElementRc, diag: &mut BuildDiagnostics) {
+    let e = elem.borrow();
+    for (name, binding) in &e.bindings {
+        let binding = binding.borrow();
+        let is_const = e.property_declarations.get(name).map_or(false, |d| d.is_const);
+        if binding.two_way_bindings.iter().any(is_const_property) {
+            diag.push_error(
+                "A const property cannot be part of a two-way binding".into(),
+                &binding.span,
+            );
+        }
+        let mut folded = binding.expression.clone();
+        let mut ctx = FoldContext { const_properties_only: true, ..Default::default() };
+        simplify_expression(&mut folded, &mut ctx);
+        report_fold_diagnostics(ctx, &binding.span, diag);
+        if is_const
+            && !matches!(binding.expression, Expression::Invalid)
+            && !is_constant_expression(&folded)
+        {
+            diag.push_error(
+                format!("The value of the const property '{}' must be a constant expression", name),
+                &binding.span,
+            );
+        }
+        check_const_assignments(&binding.expression, diag);
+    }
+    for state in &e.states {
+        for (nr, expr, node) in &state.property_changes {
+            if is_const_property(nr) {
+                diag.push_error(
+                    format!("The const property '{}' cannot be changed in a state", nr.name()),
+                    node,
+                );
+            }
+            let mut folded = expr.clone();
+            let mut ctx = FoldContext { const_properties_only: true, ..Default::default() };
+            simplify_expression(&mut folded, &mut ctx);
+            report_fold_diagnostics(ctx, node, diag);
+        }
+    }
+}
+
+fn report_fold_diagnostics(ctx: FoldContext, fallback: &dyn Spanned, diag: &mut BuildDiagnostics) {
+    for (message, location, level) in ctx.diagnostics {
+        let location = location.unwrap_or_else(|| fallback.to_source_location());
+        diag.push_diagnostic_with_span(message, location, level);
+    }
+}
+
+fn check_const_assignments(expr: &Expression, diag: &mut BuildDiagnostics) {
+    expr.visit_recursive(&mut |e| {
+        if let Expression::SelfAssignment { lhs, node, .. } = e {
+            let mut lhs = &**lhs;
+            loop {
+                match lhs {
+                    Expression::StructFieldAccess { base, .. } => lhs = &**base,
+                    Expression::ArrayIndex { array, .. } => lhs = &**array,
+                    _ => break,
+                }
+            }
+            if let Expression::PropertyReference(nr) = lhs {
+                if is_const_property(nr) {
+                    diag.push_error(
+                        format!("Cannot assign to the const property '{}'", nr.name()),
+                        node,
+                    );
+                }
+            }
+        }
+    });
+}
+
+/// Returns true if the expression is constant and does not depend on any property
+fn is_constant_expression(expr: &Expression) -> bool {
+    let mut is_constant = expr.is_constant();
+    expr.visit_recursive(&mut |e| {
+        if matches!(e, Expression::PropertyReference(_) | Expression::FunctionReference(..)) {
+            is_constant = false;
+        }
+    });
+    is_constant
+}
+
 /// Returns false if the expression still contains a reference to an element
-fn simplify_expression(expr: &mut Expression) -> bool {
+fn simplify_expression(expr: &mut Expression, ctx: &mut FoldContext) -> bool {
     match expr {
+        Expression::PropertyReference(nr) if ctx.const_properties_only => {
+            if is_const_property(nr) && !ctx.visiting.contains(nr) {
+                ctx.visiting.push(nr.clone());
+                // The problems in the binding of the const property are reported on that binding
+                let diagnostics_len = ctx.diagnostics.len();
+                let result = extract_constant_property_reference(nr, ctx);
+                ctx.diagnostics.truncate(diagnostics_len);
+                ctx.visiting.pop();
+                if let Some(result) = result.filter(is_constant_expression) {
+                    *expr = result;
+                    return true;
+                }
+            }
+            false
+        }
         Expression::PropertyReference(nr) => {
             if nr.is_constant()
                 && !matches!(nr.ty(), Type::Struct { name: Some(name), .. } if name.ends_with("::StateInfo"))
             {
                 // Inline the constant value
-                if let Some(result) = extract_constant_property_reference(nr) {
+                if let Some(result) = extract_constant_property_reference(nr, ctx) {
                     *expr = result;
                     return true;
                 }
@@ -33,8 +179,8 @@ fn simplify_expression(expr: &mut Expression) -> bool {
             false
         }
         Expression::BinaryExpression { lhs, op, rhs } => {
-            let mut can_inline = simplify_expression(lhs);
-            can_inline &= simplify_expression(rhs);
+            let mut can_inline = simplify_expression(lhs, ctx);
+            can_inline &= simplify_expression(rhs, ctx);
 
             let new = match (*op, &mut **lhs, &mut **rhs) {
                 ('+', Expression::StringLiteral(a), Expression::StringLiteral(b)) => {
@@ -56,6 +202,12 @@ fn simplify_expression(expr: &mut Expression) -> bool {
                     let preserved_unit = if *un1 == Unit::None { *un2 } else { *un1 };
                     Some(Expression::NumberLiteral(*a * *b, preserved_unit))
                 }
+                ('/', Expression::NumberLiteral(_, _), Expression::NumberLiteral(b, _))
+                    if *b == 0. =>
+                {
+                    ctx.diagnostics.push(("Division by zero".into(), None, DiagnosticLevel::Error));
+                    None
+                }
                 (
                     '/',
                     Expression::NumberLiteral(a, un1),
@@ -99,17 +251,31 @@ fn simplify_expression(expr: &mut Expression) -> bool {
             }
             can_inline
         }
+        Expression::UnaryOp { sub, op } => {
+            let can_inline = simplify_expression(sub, ctx);
+            let new = match (*op, &**sub) {
+                ('-', Expression::NumberLiteral(x, unit)) => {
+                    Some(Expression::NumberLiteral(-*x, *unit))
+                }
+                ('!', Expression::BoolLiteral(b)) => Some(Expression::BoolLiteral(!*b)),
+                _ => None,
+            };
+            if let Some(new) = new {
+                *expr = new;
+            }
+            can_inline
+        }
         Expression::StructFieldAccess { base, name } => {
             if let Expression::Struct { values, .. } = &mut **base {
                 if let Some(e) = values.remove(name) {
                     *expr = e;
-                    return simplify_expression(expr);
+                    return simplify_expression(expr, ctx);
                 }
             };
-            simplify_expression(base)
+            simplify_expression(base, ctx)
         }
         Expression::Cast { from, to } => {
-            let can_inline = simplify_expression(from);
+            let can_inline = simplify_expression(from, ctx);
             let new = if from.ty() == *to {
                 Some(std::mem::take(&mut **from))
             } else {
@@ -117,6 +283,15 @@ fn simplify_expression(expr: &mut Expression) -> bool {
                     (Expression::NumberLiteral(x, Unit::None), Type::String) => {
                         Some(Expression::StringLiteral((*x).to_string()))
                     }
+                    (Expression::Cast { from: int, to: Type::Int32 }, Type::Float32) => {
+                        match **int {
+                            // The conversion to int truncates
+                            Expression::NumberLiteral(x, Unit::None) => {
+                                Some(Expression::NumberLiteral(x.trunc(), Unit::None))
+                            }
+                            _ => None,
+                        }
+                    }
                     _ => None,
                 }
             };
@@ -125,6 +300,16 @@ fn simplify_expression(expr: &mut Expression) -> bool {
             }
             can_inline
         }
+        Expression::FunctionCall { function, arguments, source_location } => {
+            let mut result = simplify_expression(function, ctx);
+            for a in arguments.iter_mut() {
+                result &= simplify_expression(a, ctx);
+            }
+            if matches!(**function, Expression::BuiltinFunctionReference(BuiltinFunction::Rgb, _)) {
+                check_color_components(arguments, source_location, ctx);
+            }
+            result
+        }
         Expression::CallbackReference { .. } => false,
         Expression::ElementReference { .. } => false,
         // FIXME
@@ -134,17 +319,49 @@ fn simplify_expression(expr: &mut Expression) -> bool {
         Expression::ComputeLayoutInfo { .. } => false,
         _ => {
             let mut result = true;
-            expr.visit_mut(|expr| result &= simplify_expression(expr));
+            expr.visit_mut(|expr| result &= simplify_expression(expr, ctx));
             result
         }
     }
 }
 
+/// The red, green and blue components of `rgb()` are clamped between 0 and 255, and the alpha between 0 and 1
+fn check_color_components(
+    arguments: &[Expression],
+    source_location: &Option<SourceLocation>,
+    ctx: &mut FoldContext,
+) {
+    for (i, arg) in arguments.iter().enumerate() {
+        let value = match arg {
+            Expression::NumberLiteral(value, Unit::None) => *value,
+            Expression::Cast { from, .. } => match &**from {
+                Expression::NumberLiteral(value, Unit::None) => *value,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let max = if i < 3 { 255. } else { 1. };
+        if !(0. ..=max).contains(&value) {
+            ctx.diagnostics.push((
+                format!(
+                    "The color component {} is out of range and will be clamped between 0 and {}",
+                    value, max
+                ),
+                source_location.clone(),
+                DiagnosticLevel::Warning,
+            ));
+        }
+    }
+}
+
 /// Will extract the property binding from the given named reference
 /// and propagate constant expression within it. If that's possible,
 /// return the new expression
-fn extract_constant_property_reference(nr: &NamedReference) -> Option<Expression> {
-    debug_assert!(nr.is_constant());
+fn extract_constant_property_reference(
+    nr: &NamedReference,
+    ctx: &mut FoldContext,
+) -> Option<Expression> {
+    debug_assert!(ctx.const_properties_only || nr.is_constant());
     // find the binding.
     let mut element = nr.element();
     let mut expression = loop {
@@ -161,7 +378,7 @@ fn extract_constant_property_reference(nr: &NamedReference) -> Option<Expression
         };
         if let Some(decl) = element.clone().borrow().property_declarations.get(nr.name()) {
             if let Some(alias) = &decl.is_alias {
-                return extract_constant_property_reference(alias);
+                return extract_constant_property_reference(alias, ctx);
             }
         } else if let ElementType::Component(c) = &element.clone().borrow().base_type {
             element = c.root_element.clone();
@@ -171,7 +388,7 @@ fn extract_constant_property_reference(nr: &NamedReference) -> Option<Expression
         // There is no binding for this property, return the default value
         return Some(Expression::default_value_for_type(&nr.ty()));
     };
-    if !(simplify_expression(&mut expression)) {
+    if !(simplify_expression(&mut expression, ctx)) {
         return None;
     }
     Some(expression)
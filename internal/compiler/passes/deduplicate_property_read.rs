@@ -125,6 +125,8 @@ fn collect_unconditional_read_count(expr: &Expression, result: &DedupPropState)
         Expression::Condition { condition, .. } => {
             condition.visit(|sub| collect_unconditional_read_count(sub, result))
         }
+        // The body is not evaluated if the array is empty
+        Expression::ArrayOperation { array, .. } => collect_unconditional_read_count(array, result),
         Expression::SelfAssignment { .. } => {
             result.counts.borrow_mut().has_set = true;
             return;
@@ -147,6 +149,10 @@ fn process_conditional_expressions(expr: &mut Expression, state: &DedupPropState
             process_expression(true_expr, state);
             process_expression(false_expr, state);
         }
+        Expression::ArrayOperation { array, body, .. } => {
+            process_conditional_expressions(array, state);
+            process_expression(body, state);
+        }
         Expression::SelfAssignment { .. } => {
             state.counts.borrow_mut().has_set = true;
             return;
@@ -169,6 +175,7 @@ fn do_replacements(expr: &mut Expression, state: &DedupPropState) {
         Expression::Condition { condition, .. } => {
             condition.visit_mut(|sub| do_replacements(sub, state));
         }
+        Expression::ArrayOperation { array, .. } => do_replacements(array, state),
         _ => expr.visit_mut(|sub| do_replacements(sub, state)),
     }
 }
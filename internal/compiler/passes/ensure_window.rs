@@ -18,7 +18,10 @@ pub fn ensure_window(
     style_metrics: &Rc<Component>,
 ) {
     if component.root_element.borrow().builtin_type().map_or(true, |b| {
-        matches!(b.name.as_str(), "Window" | "Dialog" | "WindowItem" | "PopupWindow")
+        matches!(
+            b.name.as_str(),
+            "Window" | "Dialog" | "WindowItem" | "PopupWindow" | "ChildWindow"
+        )
     }) {
         return; // already a window, nothing to do
     }
@@ -41,6 +44,7 @@ pub fn ensure_window(
         repeated: Default::default(),
         states: Default::default(),
         transitions: Default::default(),
+        change_callbacks: Default::default(),
         child_of_layout: false,
         has_popup_child: false,
         layout_info_prop: Default::default(),
@@ -51,6 +55,7 @@ pub fn ensure_window(
         item_index_of_first_children: Default::default(),
         node: win_elem_mut.node.clone(),
         inline_depth: 0,
+        slot: None,
         is_legacy_syntax: false,
     };
     let new_root = Rc::new(RefCell::new(new_root));
@@ -0,0 +1,240 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! Pass that instantiates the generic components.
+//!
+//! A generic component such as `component Foo<T> { in property <[T]> items; }` is not compiled
+//! by itself. The types of its generic parameters are inferred from the bindings of each element
+//! that uses it, and the component is created again from its syntax node, with the generic
+//! parameters registered as these types. The element then uses that instance as its base, so
+//! that the other passes and the generators only see regular components.
+//!
+//! The body of the generic component is type-checked once, with placeholder types for the
+//! generic parameters, so that its errors are reported even if it isn't used.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::Expression;
+use crate::langtype::{ElementType, Type};
+use crate::lookup::LookupCtx;
+use crate::object_tree::{Component, Document, ElementRc, GenericComponentInfo};
+use crate::parser::{syntax_nodes, SyntaxKind, SyntaxNode};
+use std::rc::Rc;
+
+pub fn instantiate_generic_components(
+    doc: &Document,
+    type_loader: &crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    for generic in doc.generic_components.iter() {
+        check_generic_component(generic, type_loader, diag);
+    }
+
+    for component in doc.inner_components.iter() {
+        crate::object_tree::recurse_elem_no_borrow(
+            &component.root_element,
+            &vec![],
+            &mut |elem, scope: &Vec<ElementRc>| {
+                let mut new_scope = scope.clone();
+                new_scope.push(elem.clone());
+                let generic = match &elem.borrow().base_type {
+                    ElementType::Component(c) if c.generic.is_some() => c.clone(),
+                    _ => return new_scope,
+                };
+                let new_base = match infer_generic_types(elem, &generic, &new_scope, doc, diag) {
+                    Some(types) => {
+                        ElementType::Component(instantiate(&generic, types, type_loader, diag))
+                    }
+                    None => ElementType::Error,
+                };
+                elem.borrow_mut().base_type = new_base;
+                new_scope
+            },
+        );
+    }
+}
+
+/// Infer the types of the generic parameters from the type of the bindings of the properties
+/// whose declared type contains these parameters.
+fn infer_generic_types(
+    elem: &ElementRc,
+    generic: &Rc<Component>,
+    scope: &[ElementRc],
+    doc: &Document,
+    diag: &mut BuildDiagnostics,
+) -> Option<Vec<Type>> {
+    let info = generic.generic.as_ref().unwrap();
+    let mut types = vec![None; info.parameters.len()];
+
+    let bindings = elem
+        .borrow()
+        .bindings
+        .iter()
+        .filter_map(|(name, binding)| match &binding.borrow().expression {
+            Expression::Uncompiled(node) => Some((name.clone(), node.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for (name, node) in bindings {
+        let declared_type = generic.root_element.borrow().lookup_property(&name).property_type;
+        if !contains_generic_parameter(&declared_type, info) {
+            continue;
+        }
+        // The errors will be reported when the binding is resolved
+        let mut ignored_diag = BuildDiagnostics::default();
+        let mut lookup_ctx = LookupCtx::empty_context(&doc.local_registry, &mut ignored_diag);
+        lookup_ctx.property_name = Some(&name);
+        lookup_ctx.component_scope = scope;
+        let binding_node = node.clone();
+        let actual_type = match node.kind() {
+            SyntaxKind::BindingExpression => {
+                Expression::from_binding_expression_node(node, &mut lookup_ctx).ty()
+            }
+            SyntaxKind::TwoWayBinding => crate::passes::resolving::resolve_two_way_binding(
+                syntax_nodes::TwoWayBinding::from(node),
+                &mut lookup_ctx,
+            )
+            .map_or(Type::Invalid, |nr| nr.ty()),
+            _ => continue,
+        };
+        if let Err((index, conflicting)) = unify(&declared_type, &actual_type, info, &mut types) {
+            diag.push_error(
+                format!(
+                    "Conflicting types inferred for '{}' of '{}': {} and {}",
+                    info.parameters[index],
+                    generic.id,
+                    types[index].as_ref().unwrap(),
+                    conflicting
+                ),
+                &binding_node,
+            );
+            return None;
+        }
+    }
+
+    let mut result = Vec::with_capacity(types.len());
+    for (ty, name) in types.into_iter().zip(&info.parameters) {
+        match ty {
+            Some(ty) => result.push(ty),
+            None => {
+                let base_node: Option<SyntaxNode> =
+                    elem.borrow().node.as_ref().and_then(|n| n.QualifiedName()).map(Into::into);
+                diag.push_error(
+                    format!(
+                        "Cannot infer the type '{}' of '{}'. Set a property whose type uses '{}'",
+                        name, generic.id, name
+                    ),
+                    &base_node,
+                );
+                return None;
+            }
+        }
+    }
+    Some(result)
+}
+
+fn contains_generic_parameter(ty: &Type, info: &GenericComponentInfo) -> bool {
+    match ty {
+        Type::Array(inner) => contains_generic_parameter(inner, info),
+        Type::Struct { fields, .. } => {
+            info.parameter_index(ty).is_some()
+                || fields.values().any(|f| contains_generic_parameter(f, info))
+        }
+        _ => false,
+    }
+}
+
+/// Match the `declared` type, which may contain generic parameters, with the `actual` type,
+/// and record the types of the generic parameters that were not yet known.
+/// Returns the index of the parameter and the type that was found, if it differs from the type
+/// already inferred for that parameter.
+fn unify(
+    declared: &Type,
+    actual: &Type,
+    info: &GenericComponentInfo,
+    types: &mut [Option<Type>],
+) -> Result<(), (usize, Type)> {
+    if matches!(actual, Type::Invalid | Type::InferredProperty | Type::InferredCallback) {
+        return Ok(());
+    }
+    if let Some(index) = info.parameter_index(declared) {
+        match &types[index] {
+            None => types[index] = Some(actual.clone()),
+            Some(ty) if ty != actual => return Err((index, actual.clone())),
+            Some(_) => {}
+        }
+        return Ok(());
+    }
+    match (declared, actual) {
+        (Type::Array(declared), Type::Array(actual)) => unify(declared, actual, info, types)?,
+        (Type::Struct { fields: declared, .. }, Type::Struct { fields: actual, .. }) => {
+            for (name, declared) in declared {
+                if let Some(actual) = actual.get(name) {
+                    unify(declared, actual, info, types)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Returns the instance of the generic component for these types, creating it if needed.
+fn instantiate(
+    generic: &Rc<Component>,
+    types: Vec<Type>,
+    type_loader: &crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) -> Rc<Component> {
+    let info = generic.generic.as_ref().unwrap();
+    if let Some((_, instance)) = info.instances.borrow().iter().find(|(t, _)| *t == types) {
+        return instance.clone();
+    }
+
+    let type_register = info.type_register_for(&types);
+    // The errors of the object tree were already reported when creating the generic component
+    let instance = Component::instantiate_generic(
+        syntax_nodes::Component::from(generic.node.clone().unwrap()),
+        &mut BuildDiagnostics::default(),
+        &type_register,
+    );
+    info.instances.borrow_mut().push((types, instance.clone()));
+    run_passes(&instance, type_register, info, type_loader, diag);
+    instance
+}
+
+/// Type-check the body of the generic component, with placeholders for its generic parameters.
+fn check_generic_component(
+    generic: &Rc<Component>,
+    type_loader: &crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    let info = generic.generic.as_ref().unwrap();
+    run_passes(generic, info.placeholder_type_register(), info, type_loader, diag);
+}
+
+/// Run the passes on the generic component or one of its instances. The diagnostics come from
+/// the body of the generic component, so each message is reported once at each location: an
+/// instance doesn't report again what was found when checking the generic component.
+fn run_passes(
+    component: &Rc<Component>,
+    local_registry: crate::typeregister::TypeRegister,
+    info: &GenericComponentInfo,
+    type_loader: &crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    let doc = Document {
+        inner_components: vec![component.clone()],
+        root_component: component.clone(),
+        local_registry,
+        ..Default::default()
+    };
+    let mut component_diag = BuildDiagnostics::default();
+    crate::passes::run_import_passes(&doc, type_loader, &mut component_diag);
+    let mut reported = info.reported_diagnostics.borrow_mut();
+    for d in component_diag {
+        let key = format!("{:?}:{:?}:{}", d.source_file(), d.line_column(), d.message());
+        if reported.insert(key) {
+            diag.push_diagnostic(d.message().into(), &d, d.level());
+        }
+    }
+}
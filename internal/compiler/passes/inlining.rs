@@ -109,6 +109,28 @@ fn inline_element(
         }),
     );
 
+    // The children with `slot: name;` go to the element of the `@children(name)` placeholder
+    for (name, (insertion_element, _)) in
+        inlined_component.named_child_insertion_points.borrow().iter()
+    {
+        let insertion_element = match mapping.get(&element_key(insertion_element.clone())) {
+            Some(e) => e,
+            None => continue,
+        };
+        let (slotted, others): (Vec<_>, Vec<_>) = std::mem::take(&mut elem_mut.children)
+            .into_iter()
+            .partition(|c| c.borrow().slot.as_ref() == Some(name));
+        elem_mut.children = others;
+        for child in &slotted {
+            child.borrow_mut().slot = None;
+        }
+        if Rc::ptr_eq(elem, insertion_element) {
+            new_children.extend(slotted);
+        } else {
+            insertion_element.borrow_mut().children.extend(slotted);
+        }
+    }
+
     match inlined_component
         .child_insertion_point
         .borrow()
@@ -122,6 +144,11 @@ fn inline_element(
                     *cip = (insertion_element.clone(), cip_node.clone());
                 }
             };
+            for cip in root_component.named_child_insertion_points.borrow_mut().values_mut() {
+                if Rc::ptr_eq(&cip.0, elem) {
+                    cip.0 = insertion_element.clone();
+                }
+            }
         }
         _ => {
             new_children.append(&mut elem_mut.children);
@@ -164,6 +191,15 @@ fn inline_element(
         }
     }
 
+    for (k, val) in inlined_component.root_element.borrow().change_callbacks.iter() {
+        elem_mut
+            .change_callbacks
+            .entry(k.clone())
+            .or_default()
+            .get_mut()
+            .extend(val.borrow().iter().cloned());
+    }
+
     if let Some(orig) = &inlined_component.root_element.borrow().layout_info_prop {
         if let Some(_new) = &mut elem_mut.layout_info_prop {
             todo!("Merge layout infos");
@@ -207,6 +243,7 @@ fn inline_element(
     for p in root_component.popup_windows.borrow_mut().iter_mut() {
         fixup_reference(&mut p.x, &mapping);
         fixup_reference(&mut p.y, &mapping);
+        fixup_reference(&mut p.close_policy, &mapping);
     }
 }
 
@@ -243,6 +280,7 @@ fn duplicate_element_with_mapping(
             .iter()
             .map(|t| duplicate_transition(t, mapping, root_component, priority_delta))
             .collect(),
+        change_callbacks: elem.change_callbacks.clone(),
         child_of_layout: elem.child_of_layout,
         layout_info_prop: elem.layout_info_prop.clone(),
         default_fill_parent: elem.default_fill_parent,
@@ -254,6 +292,7 @@ fn duplicate_element_with_mapping(
         has_popup_child: elem.has_popup_child,
         is_legacy_syntax: elem.is_legacy_syntax,
         inline_depth: elem.inline_depth + 1,
+        slot: elem.slot.clone(),
     }));
     mapping.insert(element_key(element.clone()), new.clone());
     if let ElementType::Component(c) = &mut new.borrow_mut().base_type {
@@ -302,12 +341,15 @@ fn duplicate_sub_component(
         embedded_file_resources: component_to_duplicate.embedded_file_resources.clone(),
         root_constraints: component_to_duplicate.root_constraints.clone(),
         child_insertion_point: component_to_duplicate.child_insertion_point.clone(),
+        named_child_insertion_points: component_to_duplicate.named_child_insertion_points.clone(),
         init_code: component_to_duplicate.init_code.clone(),
+        local_variable_counter: component_to_duplicate.local_variable_counter.clone(),
         used_types: Default::default(),
         popup_windows: Default::default(),
         exported_global_names: component_to_duplicate.exported_global_names.clone(),
         is_root_component: Default::default(),
         private_properties: Default::default(),
+        generic: None,
     };
 
     let new_component = Rc::new(new_component);
@@ -324,6 +366,7 @@ fn duplicate_sub_component(
     for p in new_component.popup_windows.borrow_mut().iter_mut() {
         fixup_reference(&mut p.x, mapping);
         fixup_reference(&mut p.y, mapping);
+        fixup_reference(&mut p.close_policy, mapping);
     }
     new_component
         .root_constraints
@@ -344,6 +387,7 @@ fn duplicate_popup(
     PopupWindow {
         x: p.x.clone(),
         y: p.y.clone(),
+        close_policy: p.close_policy.clone(),
         component: duplicate_sub_component(&p.component, &parent, mapping, priority_delta),
         parent_element: mapping
             .get(&element_key(p.parent_element.clone()))
@@ -474,7 +518,9 @@ fn duplicate_transition(
 // Some components need to be inlined to avoid increased complexity in handling them
 // in the code generators and subsequent passes.
 fn component_requires_inlining(component: &Rc<Component>) -> bool {
-    if component.child_insertion_point.borrow().is_some() {
+    if component.child_insertion_point.borrow().is_some()
+        || !component.named_child_insertion_points.borrow().is_empty()
+    {
         return true;
     }
 
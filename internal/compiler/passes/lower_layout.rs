@@ -532,6 +532,8 @@ fn lower_dialog_layout(
                                         )),
                                         visibility: PropertyVisibility::InOut,
                                         pure: None,
+                                        is_const: false,
+                                        default_arguments: vec![],
                                     });
                             }
                         }
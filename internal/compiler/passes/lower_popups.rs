@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint-ui.com>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
-//! Passe that transform the PopupWindow element into a component
+//! Passe that transform the PopupWindow and ChildWindow elements into a component
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{Expression, NamedReference};
@@ -22,17 +22,27 @@ pub fn lower_popups(
         component,
         &None,
         &mut |elem, parent_element: &Option<ElementRc>| {
-            let is_popup = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "PopupWindow");
-            if is_popup {
-                lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
+            let popup_type = match &elem.borrow().base_type {
+                ElementType::Builtin(base_type)
+                    if matches!(base_type.name.as_str(), "PopupWindow" | "ChildWindow") =>
+                {
+                    Some(base_type.name.clone())
+                }
+                _ => None,
+            };
+            if let Some(popup_type) = popup_type {
+                lower_popup_window(elem, &popup_type, parent_element.as_ref(), &window_type, diag);
             }
             Some(elem.clone())
         },
     )
 }
 
+/// Lowers a `PopupWindow`, or a `ChildWindow` which is shown in its own window. `popup_type` is
+/// the name of the element, for the error messages.
 fn lower_popup_window(
     popup_window_element: &ElementRc,
+    popup_type: &str,
     parent_element: Option<&ElementRc>,
     window_type: &ElementType,
     diag: &mut BuildDiagnostics,
@@ -40,7 +50,7 @@ fn lower_popup_window(
     let parent_element = match parent_element {
         None => {
             diag.push_error(
-                "PopupWindow cannot be the top level".into(),
+                format!("{} cannot be the top level", popup_type),
                 &*popup_window_element.borrow(),
             );
             return;
@@ -51,7 +61,7 @@ fn lower_popup_window(
     let parent_component = popup_window_element.borrow().enclosing_component.upgrade().unwrap();
     if Rc::ptr_eq(&parent_component.root_element, popup_window_element) {
         diag.push_error(
-            "PopupWindow cannot be directly repeated or conditional".into(),
+            format!("{} cannot be directly repeated or conditional", popup_type),
             &*popup_window_element.borrow(),
         );
         return;
@@ -84,6 +94,7 @@ fn lower_popup_window(
     // FIXME: this is a hack that doesn't always work, perhaps should we store an item ref or something
     let coord_x = create_coordinate(&popup_comp, parent_element, "x");
     let coord_y = create_coordinate(&popup_comp, parent_element, "y");
+    let close_policy = create_close_policy(&popup_comp, parent_element);
 
     // Throw error when accessing the popup from outside
     // FIXME:
@@ -93,7 +104,7 @@ fn lower_popup_window(
     visit_all_named_references(&parent_component, &mut |nr| {
         if std::rc::Weak::ptr_eq(&nr.element().borrow().enclosing_component, &weak) {
             diag.push_error(
-                "Cannot access the inside of a PopupWindow from enclosing component".into(),
+                format!("Cannot access the inside of a {} from enclosing component", popup_type),
                 &*popup_window_element.borrow(),
             );
             // just set it to whatever is a valid NamedReference, otherwise we'll panic later
@@ -105,6 +116,7 @@ fn lower_popup_window(
         component: popup_comp,
         x: coord_x,
         y: coord_y,
+        close_policy,
         parent_element: parent_element.clone(),
     });
 }
@@ -132,3 +144,20 @@ fn create_coordinate(
         .insert(property_name.clone(), RefCell::new(expression.into()));
     NamedReference::new(parent_element, &property_name)
 }
+
+/// Move the `close-policy` binding of the popup to a property of the parent element, since the
+/// root of the popup component is a Window that doesn't have that property.
+fn create_close_policy(popup_comp: &Rc<Component>, parent_element: &ElementRc) -> NamedReference {
+    let enumeration = crate::typeregister::BUILTIN_ENUMS.with(|e| e.PopupClosePolicy.clone());
+    let binding =
+        popup_comp.root_element.borrow_mut().bindings.remove("close-policy").unwrap_or_else(|| {
+            RefCell::new(Expression::EnumerationValue(enumeration.clone().default_value()).into())
+        });
+    let property_name = format!("{}-popup-close-policy", popup_comp.root_element.borrow().id);
+    parent_element
+        .borrow_mut()
+        .property_declarations
+        .insert(property_name.clone(), Type::Enumeration(enumeration).into());
+    parent_element.borrow_mut().bindings.insert(property_name.clone(), binding);
+    NamedReference::new(parent_element, &property_name)
+}
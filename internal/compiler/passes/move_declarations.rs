@@ -31,6 +31,7 @@ fn do_move_declarations(component: &Rc<Component>) {
 
     let mut new_root_bindings = HashMap::new();
     let mut new_root_property_analysis = HashMap::new();
+    let mut new_root_change_callbacks = HashMap::new();
 
     let move_bindings_and_animations = &mut |elem: &ElementRc| {
         visit_all_named_references_in_element(elem, fixup_reference);
@@ -61,6 +62,18 @@ fn do_move_declarations(component: &Rc<Component>) {
         }
         elem.borrow_mut().bindings = new_bindings;
 
+        let change_callbacks = core::mem::take(&mut elem.borrow_mut().change_callbacks);
+        let mut new_change_callbacks = BTreeMap::default();
+        for (k, e) in change_callbacks {
+            let will_be_moved = elem.borrow().property_declarations.contains_key(&k);
+            if will_be_moved {
+                new_root_change_callbacks.insert(map_name(elem, k.as_str()), e);
+            } else {
+                new_change_callbacks.insert(k, e);
+            }
+        }
+        elem.borrow_mut().change_callbacks = new_change_callbacks;
+
         let property_analysis = elem.borrow().property_analysis.take();
         let mut new_property_analysis = HashMap::with_capacity(property_analysis.len());
         for (prop, a) in property_analysis {
@@ -81,6 +94,7 @@ fn do_move_declarations(component: &Rc<Component>) {
     component.popup_windows.borrow_mut().iter_mut().for_each(|p| {
         fixup_reference(&mut p.x);
         fixup_reference(&mut p.y);
+        fixup_reference(&mut p.close_policy);
         visit_all_named_references(&p.component, &mut fixup_reference)
     });
     component.init_code.borrow_mut().iter_mut().for_each(|expr| {
@@ -105,6 +119,7 @@ fn do_move_declarations(component: &Rc<Component>) {
         let mut r = component.root_element.borrow_mut();
         r.property_declarations = decl.property_declarations;
         r.bindings.extend(new_root_bindings.into_iter());
+        r.change_callbacks.extend(new_root_change_callbacks.into_iter());
         r.property_analysis.borrow_mut().extend(new_root_property_analysis.into_iter());
     }
 
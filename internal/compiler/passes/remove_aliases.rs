@@ -160,6 +160,27 @@ pub fn remove_aliases(doc: &Document, diag: &mut BuildDiagnostics) {
             }
         };
 
+        // Move the change callbacks. They can only be moved within the same component,
+        // otherwise the declaration is kept as an alias.
+        let mut has_change_callbacks = false;
+        if let Some(old_change_callbacks) = elem.borrow_mut().change_callbacks.remove(remove.name())
+        {
+            if same_component {
+                to_elem
+                    .borrow_mut()
+                    .change_callbacks
+                    .entry(to.name().to_owned())
+                    .or_default()
+                    .get_mut()
+                    .extend(old_change_callbacks.into_inner());
+            } else {
+                elem.borrow_mut()
+                    .change_callbacks
+                    .insert(remove.name().to_owned(), old_change_callbacks);
+                has_change_callbacks = true;
+            }
+        }
+
         // Remove the declaration
         {
             let mut elem = elem.borrow_mut();
@@ -169,7 +190,7 @@ pub fn remove_aliases(doc: &Document, diag: &mut BuildDiagnostics) {
                 .get(remove.name())
                 .map_or(false, |v| v.is_read_externally || v.is_set_externally);
             if let Some(d) = elem.property_declarations.get_mut(remove.name()) {
-                if d.expose_in_public_api || used_externally {
+                if d.expose_in_public_api || used_externally || has_change_callbacks {
                     d.is_alias = Some(to.clone());
                     drop(elem);
                     // one must mark the aliased property as settable from outside
@@ -39,6 +39,7 @@ fn create_repeater_components(component: &Rc<Component>) {
                 enclosing_component: Default::default(),
                 states: std::mem::take(&mut elem.states),
                 transitions: std::mem::take(&mut elem.transitions),
+                change_callbacks: std::mem::take(&mut elem.change_callbacks),
                 child_of_layout: elem.child_of_layout || is_listview.is_some(),
                 layout_info_prop: elem.layout_info_prop.take(),
                 default_fill_parent: elem.default_fill_parent,
@@ -49,6 +50,7 @@ fn create_repeater_components(component: &Rc<Component>) {
                 item_index_of_first_children: Default::default(),
                 is_legacy_syntax: elem.is_legacy_syntax,
                 inline_depth: 0,
+                slot: None,
             })),
             parent_element,
             ..Component::default()
@@ -73,6 +75,9 @@ fn create_repeater_components(component: &Rc<Component>) {
             }
 
             NamedReference::new(&comp.root_element, "y").mark_as_set();
+            listview.first_visible_row.mark_as_set();
+            listview.last_visible_row.mark_as_set();
+            listview.scroll_request_row.mark_as_set();
         }
 
         let weak = Rc::downgrade(&comp);
@@ -10,7 +10,7 @@
 
 use crate::diagnostics::{BuildDiagnostics, Spanned};
 use crate::expression_tree::*;
-use crate::langtype::{ElementType, Type};
+use crate::langtype::{ElementType, StructFunction, Type};
 use crate::lookup::{LookupCtx, LookupObject, LookupResult};
 use crate::object_tree::*;
 use crate::parser::{identifier_text, syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
@@ -39,9 +39,11 @@ fn resolve_expression(
             component_scope: &scope.0,
             diag,
             arguments: vec![],
+            local_variables: vec![],
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
+            local_variable_counter: Default::default(),
         };
 
         let new_expr = match node.kind() {
@@ -49,6 +51,10 @@ fn resolve_expression(
                 //FIXME: proper callback support (node is a codeblock)
                 Expression::from_callback_connection(node.clone().into(), &mut lookup_ctx)
             }
+            SyntaxKind::PropertyChangedCallback => Expression::from_codeblock_node(
+                syntax_nodes::PropertyChangedCallback::from(node.clone()).CodeBlock(),
+                &mut lookup_ctx,
+            ),
             SyntaxKind::Function => Expression::from_function(node.clone().into(), &mut lookup_ctx),
             SyntaxKind::Expression => {
                 //FIXME again: this happen for non-binding expression (i.e: model)
@@ -76,7 +82,9 @@ pub fn resolve_expressions(
     type_loader: &crate::typeloader::TypeLoader,
     diag: &mut BuildDiagnostics,
 ) {
+    resolve_struct_definitions(doc, type_loader, diag);
     resolve_two_way_bindings(doc, &doc.local_registry, diag);
+    resolve_default_arguments(doc, type_loader, diag);
 
     for component in doc.inner_components.iter() {
         let scope = ComponentScope(vec![]);
@@ -116,6 +124,207 @@ pub fn resolve_expressions(
     }
 }
 
+/// Resolve the default values of the arguments of the functions declared in the document, in the
+/// scope of their declaration, so that the calls can use them without resolving them again.
+fn resolve_default_arguments(
+    doc: &Document,
+    type_loader: &crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    for component in doc.inner_components.iter() {
+        recurse_elem(&component.root_element, &ComponentScope(vec![]), &mut |elem, scope| {
+            let mut new_scope = scope.clone();
+            new_scope.0.push(elem.clone());
+            let functions = elem
+                .borrow()
+                .property_declarations
+                .iter()
+                .filter_map(|(name, decl)| match &decl.property_type {
+                    Type::Function { args, .. } => Some((
+                        name.clone(),
+                        args.clone(),
+                        decl.node.clone().and_then(syntax_nodes::Function::new)?,
+                    )),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            for (name, args, node) in functions {
+                let default_arguments = node
+                    .ArgumentDeclaration()
+                    .zip(args.iter())
+                    .map(|(arg, ty)| {
+                        let default_value = arg.Expression()?;
+                        let mut expr = Expression::Uncompiled(default_value.clone().into());
+                        resolve_expression(
+                            &mut expr,
+                            Some(&name),
+                            ty.clone(),
+                            &new_scope,
+                            &doc.local_registry,
+                            type_loader,
+                            diag,
+                        );
+                        let mut is_constant = expr.is_constant();
+                        expr.visit_recursive(&mut |e| {
+                            if matches!(
+                                e,
+                                Expression::PropertyReference(_)
+                                    | Expression::FunctionReference(..)
+                            ) {
+                                is_constant = false;
+                            }
+                        });
+                        if !is_constant {
+                            diag.push_error(
+                                "The default value of an argument must be a constant expression"
+                                    .into(),
+                                &default_value,
+                            );
+                        }
+                        Some(expr)
+                    })
+                    .collect();
+                if let Some(decl) = elem.borrow_mut().property_declarations.get_mut(&name) {
+                    decl.default_arguments = default_arguments;
+                }
+            }
+            new_scope
+        })
+    }
+}
+
+/// Resolve the default values of the fields and the functions of the structs declared in the document
+fn resolve_struct_definitions(
+    doc: &Document,
+    type_loader: &crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    for ty in &doc.inner_types {
+        let Type::Struct { fields, node: Some(node), definition: Some(definition), .. } = ty else {
+            continue;
+        };
+
+        for member in node.ObjectTypeMember() {
+            let Some(default_value) = member.Expression() else { continue };
+            let name = identifier_text(&member).unwrap_or_default();
+            let field_ty = fields.get(&name).cloned().unwrap_or_default();
+            let mut ctx = LookupCtx::empty_context(&doc.local_registry, diag);
+            ctx.property_type = field_ty.clone();
+            ctx.type_loader = Some(type_loader);
+            let expr = Expression::from_expression_node(default_value.clone(), &mut ctx)
+                .maybe_convert_to(field_ty, &default_value, diag);
+            match crate::passes::const_propagation::constant_value(&expr)
+                .filter(is_struct_field_literal)
+            {
+                Some(value) => {
+                    definition.borrow_mut().field_defaults.insert(name, value);
+                }
+                None if expr.ty() != Type::Invalid => diag.push_error(
+                    "The default value of a field must be a constant expression".into(),
+                    &default_value,
+                ),
+                None => (),
+            }
+        }
+
+        for function in node.Function() {
+            let name = identifier_text(&function.DeclaredIdentifier()).unwrap_or_default();
+            if fields.contains_key(&name) || definition.borrow().function(&name).is_some() {
+                diag.push_error(
+                    format!("Cannot declare function '{}' because the struct already has a member with that name", name),
+                    &function.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            let args = function
+                .ArgumentDeclaration()
+                .map(|arg| {
+                    if let Some(default_value) = arg.Expression() {
+                        diag.push_error(
+                            "The arguments of a struct function cannot have a default value".into(),
+                            &default_value,
+                        );
+                    }
+                    (
+                        identifier_text(&arg.DeclaredIdentifier()).unwrap_or_default(),
+                        type_from_node(arg.Type(), diag, &doc.local_registry),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let return_type = function
+                .ReturnType()
+                .map_or(Type::Void, |r| type_from_node(r.Type(), diag, &doc.local_registry));
+
+            // The body is inlined where the function is called, so it can only return at the end
+            let code_block = function.CodeBlock();
+            let last_statement = code_block
+                .children()
+                .filter(|n| {
+                    matches!(n.kind(), SyntaxKind::Expression | SyntaxKind::ReturnStatement)
+                })
+                .last();
+            for n in
+                code_block.node.descendants().filter(|n| n.kind() == SyntaxKind::ReturnStatement)
+            {
+                if last_statement.as_ref().map_or(true, |last| last.node != n) {
+                    diag.push_error(
+                        "A struct function can only return with its last statement".into(),
+                        &SyntaxNode { node: n, source_file: code_block.source_file.clone() },
+                    );
+                }
+            }
+
+            let mut ctx = LookupCtx::empty_context(&doc.local_registry, diag);
+            ctx.property_type = Type::Function {
+                return_type: Box::new(return_type.clone()),
+                args: args.iter().map(|(_, ty)| ty.clone()).collect(),
+            };
+            ctx.type_loader = Some(type_loader);
+            let read_local = |name: &str, ty: &Type| Expression::ReadLocalVariable {
+                name: name.into(),
+                ty: ty.clone(),
+            };
+            ctx.local_variables.push(("self".into(), read_local("self", ty)));
+            ctx.local_variables
+                .extend(args.iter().map(|(name, ty)| (name.clone(), read_local(name, ty))));
+            let mut body = Expression::from_codeblock_node(code_block, &mut ctx);
+            if let Expression::CodeBlock(statements) = &mut body {
+                if let Some(Expression::ReturnStatement(value)) = statements.last_mut() {
+                    let value = value.take().map_or(Expression::CodeBlock(vec![]), |v| *v);
+                    *statements.last_mut().unwrap() = value;
+                }
+            }
+            let body = body.maybe_convert_to(return_type.clone(), &function, diag);
+
+            definition.borrow_mut().functions.push(StructFunction {
+                name,
+                args,
+                return_type,
+                body,
+                node: function,
+            });
+        }
+    }
+}
+
+/// The literals that the default value of a struct field can be folded to.
+/// The generators know how to write them without evaluation context.
+fn is_struct_field_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(_)
+        | Expression::NumberLiteral(..)
+        | Expression::BoolLiteral(_)
+        | Expression::EnumerationValue(_) => true,
+        Expression::Cast { from, to: Type::Int32 | Type::Float32 | Type::Color } => {
+            from.ty().as_unit_product().is_some() && is_struct_field_literal(from)
+        }
+        Expression::Cast { from, to: Type::Brush } => {
+            from.ty() == Type::Color && is_struct_field_literal(from)
+        }
+        _ => false,
+    }
+}
+
 /// To be used in [`Expression::from_qualified_name_node`] to specify if the lookup is performed
 /// for two ways binding (which happens before the models and other expressions are resolved),
 /// or after that.
@@ -241,6 +450,16 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
             .map(|n| Self::from_expression_node(n, ctx))
             .or_else(|| node.AtImageUrl().map(|n| Self::from_at_image_url_node(n, ctx)))
             .or_else(|| node.AtGradient().map(|n| Self::from_at_gradient(n, ctx)))
+            .or_else(|| node.AtTr().map(|n| Self::from_at_tr(n, ctx)))
+            .or_else(|| {
+                node.Lambda().map(|n| {
+                    ctx.diag.push_error(
+                        "Lambda expressions can only be used as argument of array functions such as 'filter' or 'map'".into(),
+                        &n,
+                    );
+                    Self::Invalid
+                })
+            })
             .or_else(|| {
                 node.QualifiedName().map(|n| {
                     let exp =
@@ -515,6 +734,69 @@ enum Stop {
         }
     }
 
+    fn from_at_tr(node: syntax_nodes::AtTr, ctx: &mut LookupCtx) -> Self {
+        let unescape = |node: &SyntaxNode, ctx: &mut LookupCtx| {
+            node.child_text(SyntaxKind::StringLiteral)
+                .and_then(|s| crate::literals::unescape_string(&s))
+                .unwrap_or_else(|| {
+                    ctx.diag.push_error("Cannot parse string literal".into(), node);
+                    Default::default()
+                })
+        };
+        let string = unescape(&node, ctx);
+        let context = node.TrContext().map(|n| unescape(&n, ctx)).unwrap_or_default();
+        let plural = node.TrPlural().map(|pl| {
+            let plural = unescape(&pl, ctx);
+            let n = pl.Expression();
+            let count = Self::from_expression_node(n.clone(), ctx).maybe_convert_to(
+                Type::Int32,
+                &n,
+                ctx.diag,
+            );
+            (plural, count)
+        });
+
+        let values = node
+            .Expression()
+            .map(|n| {
+                Self::from_expression_node(n.clone(), ctx).maybe_convert_to(
+                    Type::String,
+                    &n,
+                    ctx.diag,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if check_tr_format_string(&string, &node, values.len(), plural.is_some(), ctx.diag) {
+            if let Some((plural, _)) = &plural {
+                check_tr_format_string(plural, &node, values.len(), true, ctx.diag);
+            }
+        }
+
+        let domain = ctx
+            .type_loader
+            .and_then(|tl| tl.compiler_config.translation_domain.clone())
+            .unwrap_or_default();
+        let (plural, count) =
+            plural.unwrap_or_else(|| (String::new(), Expression::NumberLiteral(1., Unit::None)));
+
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::Translate,
+                Some(node.to_source_location()),
+            )),
+            arguments: vec![
+                Expression::StringLiteral(string),
+                Expression::StringLiteral(context),
+                Expression::StringLiteral(domain),
+                Expression::Array { element_ty: Type::String, values },
+                count,
+                Expression::StringLiteral(plural),
+            ],
+            source_location: Some(node.to_source_location()),
+        }
+    }
+
     /// Perform the lookup
     fn from_qualified_name_node(
         node: syntax_nodes::QualifiedName,
@@ -677,6 +959,45 @@ fn from_function_call_node(
                 .unwrap_or_else(|| Self::from_expression_node(n, ctx))
         });
 
+        let named_arguments = node.NamedArgument().collect::<Vec<_>>();
+        let declaration = match &function {
+            Expression::FunctionReference(nr, _) => function_declaration(nr),
+            _ => None,
+        };
+        if declaration.is_none() {
+            for n in &named_arguments {
+                ctx.diag.push_error(
+                    "Named arguments can only be used to call functions declared in .slint".into(),
+                    n,
+                );
+            }
+        }
+
+        if let Expression::MemberFunction { base, member, .. } = &function {
+            if let Expression::BuiltinMacroReference(
+                BuiltinMacroFunction::StructFunction(name),
+                _,
+            ) = &**member
+            {
+                return Self::from_struct_function_call(
+                    name,
+                    (**base).clone(),
+                    &node,
+                    sub_expr.collect(),
+                    ctx,
+                );
+            }
+            if let Expression::BuiltinMacroReference(mac, _) = &**member {
+                return Self::from_array_function_call(
+                    mac.clone(),
+                    (**base).clone(),
+                    &node,
+                    sub_expr.collect(),
+                    ctx,
+                );
+            }
+        }
+
         let sub_expr = sub_expr.map(|n| {
             (Self::from_expression_node(n.clone(), ctx), Some(NodeOrToken::from((*n).clone())))
         });
@@ -694,8 +1015,23 @@ fn from_function_call_node(
         };
         arguments.extend(sub_expr);
 
-        let arguments = match function.ty() {
-            Type::Function { args, .. } | Type::Callback { args, .. } => {
+        let arguments = match (function.ty(), declaration) {
+            (Type::Function { args, .. }, Some((declaration, default_arguments)))
+                if !named_arguments.is_empty()
+                    || (arguments.len() < args.len()
+                        && declaration.ArgumentDeclaration().any(|a| a.Expression().is_some())) =>
+            {
+                Self::function_arguments_with_defaults(
+                    &node,
+                    &declaration,
+                    &default_arguments,
+                    &args,
+                    arguments,
+                    named_arguments,
+                    ctx,
+                )
+            }
+            (Type::Function { args, .. } | Type::Callback { args, .. }, _) => {
                 if arguments.len() != args.len() {
                     ctx.diag.push_error(
                         format!(
@@ -727,6 +1063,311 @@ fn from_function_call_node(
         }
     }
 
+    /// Match the positional and named arguments of a call with the arguments of the function
+    /// `declaration`, and use the default values for the arguments that are not provided.
+    fn function_arguments_with_defaults(
+        node: &syntax_nodes::FunctionCallExpression,
+        declaration: &syntax_nodes::Function,
+        default_arguments: &[Option<Expression>],
+        args: &[Type],
+        positional: Vec<(Expression, Option<NodeOrToken>)>,
+        named: Vec<syntax_nodes::NamedArgument>,
+        ctx: &mut LookupCtx,
+    ) -> Vec<Expression> {
+        let function_name = identifier_text(&declaration.DeclaredIdentifier()).unwrap_or_default();
+        let declared = declaration.ArgumentDeclaration().collect::<Vec<_>>();
+        if positional.len() > args.len() {
+            ctx.diag.push_error(
+                format!(
+                    "The callback or function expects {} arguments, but {} are provided",
+                    args.len(),
+                    positional.len()
+                ),
+                node,
+            );
+            return positional.into_iter().map(|x| x.0).collect();
+        }
+        let mut values = positional.into_iter().map(Some).collect::<Vec<_>>();
+        values.resize(args.len(), None);
+        for n in named {
+            let name = identifier_text(&n.DeclaredIdentifier()).unwrap_or_default();
+            let index = match declared
+                .iter()
+                .position(|a| identifier_text(&a.DeclaredIdentifier()).as_ref() == Some(&name))
+            {
+                Some(index) => index,
+                None => {
+                    ctx.diag.push_error(
+                        format!("'{}' has no argument named '{}'", function_name, name),
+                        &n.DeclaredIdentifier(),
+                    );
+                    continue;
+                }
+            };
+            if values[index].is_some() {
+                ctx.diag.push_error(
+                    format!("The argument '{}' is provided more than once", name),
+                    &n.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            let expr = n.Expression();
+            values[index] = Some((
+                Self::from_expression_node(expr.clone(), ctx),
+                Some(NodeOrToken::from((*expr).clone())),
+            ));
+        }
+        values
+            .into_iter()
+            .zip(args.iter())
+            .zip(declared.iter())
+            .enumerate()
+            .map(|(index, ((value, ty), decl))| match (value, decl.Expression()) {
+                (Some((e, arg_node)), _) => e.maybe_convert_to(ty.clone(), &arg_node, ctx.diag),
+                // The errors in the default value were reported at the declaration
+                (None, Some(_)) => {
+                    default_arguments.get(index).cloned().flatten().unwrap_or(Expression::Invalid)
+                }
+                (None, None) => {
+                    ctx.diag.push_error(
+                        format!(
+                            "Missing value for the argument '{}' of '{}'",
+                            identifier_text(&decl.DeclaredIdentifier()).unwrap_or_default(),
+                            function_name
+                        ),
+                        node,
+                    );
+                    Expression::Invalid
+                }
+            })
+            .collect()
+    }
+
+    /// Resolve the call to one of the array functions (`filter`, `map`, ...) into an [`Expression::ArrayOperation`]
+    fn from_array_function_call(
+        function: BuiltinMacroFunction,
+        array: Expression,
+        node: &syntax_nodes::FunctionCallExpression,
+        args: Vec<syntax_nodes::Expression>,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let (name, op, arg_count) = match function {
+            BuiltinMacroFunction::ArrayFilter => ("filter", ArrayOperation::Filter, 1..=1),
+            BuiltinMacroFunction::ArrayMap => ("map", ArrayOperation::Map, 1..=1),
+            BuiltinMacroFunction::ArrayAny => ("any", ArrayOperation::Any, 1..=1),
+            BuiltinMacroFunction::ArrayAll => ("all", ArrayOperation::All, 1..=1),
+            BuiltinMacroFunction::ArraySum => ("sum", ArrayOperation::Sum, 0..=1),
+            BuiltinMacroFunction::ArrayIndexOf => ("index-of", ArrayOperation::IndexOf, 1..=1),
+            BuiltinMacroFunction::ArraySlice => ("slice", ArrayOperation::Filter, 2..=2),
+            _ => unreachable!("{function:?} is not an array function"),
+        };
+        if !arg_count.contains(&args.len()) {
+            ctx.diag.push_error(
+                format!(
+                    "'{}' expects {} argument{}, but {} are provided",
+                    name,
+                    arg_count.end(),
+                    if *arg_count.end() == 1 { "" } else { "s" },
+                    args.len()
+                ),
+                node,
+            );
+            return Expression::Invalid;
+        }
+        let element_ty = match array.ty() {
+            Type::Array(ty) => *ty,
+            _ => return Expression::Invalid,
+        };
+        let read_local =
+            |name: &str, ty: Type| Expression::ReadLocalVariable { name: name.into(), ty };
+        let is_number = |ty: &Type| ty.as_unit_product().is_some();
+
+        let (element, body, mut statements) = match (function, args.first()) {
+            (
+                BuiltinMacroFunction::ArrayFilter
+                | BuiltinMacroFunction::ArrayMap
+                | BuiltinMacroFunction::ArrayAny
+                | BuiltinMacroFunction::ArrayAll
+                | BuiltinMacroFunction::ArraySum,
+                Some(arg),
+            ) => {
+                let Some(lambda) = arg.Lambda() else {
+                    ctx.diag.push_error(
+                        format!("The argument of '{}' must be a lambda expression, such as `x => x > 0`", name),
+                        arg,
+                    );
+                    return Expression::Invalid;
+                };
+                let param = identifier_text(&lambda.DeclaredIdentifier()).unwrap_or_default();
+                let element = ctx.unique_local_name(&param);
+                ctx.local_variables.push((param, read_local(&element, element_ty.clone())));
+                let body = Self::from_expression_node(lambda.Expression(), ctx);
+                ctx.local_variables.pop();
+                let body = match op {
+                    ArrayOperation::Map => {
+                        if body.ty() == Type::Void {
+                            ctx.diag.push_error(
+                                "The lambda expression of 'map' must return a value".into(),
+                                &lambda,
+                            );
+                        }
+                        body
+                    }
+                    ArrayOperation::Sum => {
+                        let ty = body.ty();
+                        if !is_number(&ty) && ty != Type::Invalid {
+                            ctx.diag.push_error(
+                                format!(
+                                    "The lambda expression of 'sum' must return a number, not a {}",
+                                    ty
+                                ),
+                                &lambda,
+                            );
+                        }
+                        body
+                    }
+                    _ => body.maybe_convert_to(Type::Bool, &lambda, ctx.diag),
+                };
+                (element, body, vec![])
+            }
+            (BuiltinMacroFunction::ArraySum, None) => {
+                if !is_number(&element_ty) && element_ty != Type::Invalid {
+                    ctx.diag.push_error(
+                        format!("'sum' can only be called on an array of numbers, not an array of {}. Use `sum(x => ...)` to sum a field of the elements", element_ty),
+                        node,
+                    );
+                }
+                let element = ctx.unique_local_name("element");
+                (element.clone(), read_local(&element, element_ty), vec![])
+            }
+            (BuiltinMacroFunction::ArrayIndexOf, Some(arg)) => {
+                let value = Self::from_expression_node(arg.clone(), ctx).maybe_convert_to(
+                    element_ty.clone(),
+                    arg,
+                    ctx.diag,
+                );
+                let (element, value_name) =
+                    (ctx.unique_local_name("element"), ctx.unique_local_name("value"));
+                let body = Expression::BinaryExpression {
+                    lhs: Box::new(read_local(&element, element_ty.clone())),
+                    rhs: Box::new(read_local(&value_name, element_ty)),
+                    op: '=',
+                };
+                let store =
+                    Expression::StoreLocalVariable { name: value_name, value: Box::new(value) };
+                (element, body, vec![store])
+            }
+            (BuiltinMacroFunction::ArraySlice, _) => {
+                let mut statements = vec![];
+                let mut bound = |arg: &syntax_nodes::Expression, ctx: &mut LookupCtx| {
+                    let value = Self::from_expression_node(arg.clone(), ctx).maybe_convert_to(
+                        Type::Int32,
+                        arg,
+                        ctx.diag,
+                    );
+                    let name = ctx.unique_local_name("bound");
+                    statements.push(Expression::StoreLocalVariable {
+                        name: name.clone(),
+                        value: Box::new(value),
+                    });
+                    read_local(&name, Type::Int32)
+                };
+                let (start, end) = (bound(&args[0], ctx), bound(&args[1], ctx));
+                let element = ctx.unique_local_name("element");
+                let index = read_local(&format!("{}-index", element), Type::Int32);
+                let body = Expression::BinaryExpression {
+                    lhs: Box::new(Expression::BinaryExpression {
+                        lhs: Box::new(index.clone()),
+                        rhs: Box::new(start),
+                        op: '≥',
+                    }),
+                    rhs: Box::new(Expression::BinaryExpression {
+                        lhs: Box::new(index),
+                        rhs: Box::new(end),
+                        op: '<',
+                    }),
+                    op: '&',
+                };
+                (element, body, statements)
+            }
+            _ => unreachable!(),
+        };
+
+        let operation = Expression::ArrayOperation {
+            op,
+            array: Box::new(array),
+            index: format!("{}-index", element),
+            element,
+            body: Box::new(body),
+        };
+        if statements.is_empty() {
+            operation
+        } else {
+            statements.push(operation);
+            Expression::CodeBlock(statements)
+        }
+    }
+
+    /// Inline the call to the function `name` of the struct of `base`: the body of the function
+    /// is evaluated after storing `self` and the arguments in local variables
+    fn from_struct_function_call(
+        name: &str,
+        base: Expression,
+        node: &syntax_nodes::FunctionCallExpression,
+        args: Vec<syntax_nodes::Expression>,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let Some(function) = base.ty().struct_function(name) else {
+            return Expression::Invalid;
+        };
+        if args.len() != function.args.len() {
+            ctx.diag.push_error(
+                format!(
+                    "The function '{}' expects {} arguments, but {} are provided",
+                    name,
+                    function.args.len(),
+                    args.len()
+                ),
+                node,
+            );
+            return Expression::Invalid;
+        }
+        let suffix = ctx.unique_local_name("");
+        let local_name = |name: &str| format!("{}{}", name, suffix);
+
+        let mut statements = vec![Expression::StoreLocalVariable {
+            name: local_name("self"),
+            value: Box::new(base),
+        }];
+        for (arg, (arg_name, ty)) in args.iter().zip(function.args.iter()) {
+            let value = Self::from_expression_node(arg.clone(), ctx).maybe_convert_to(
+                ty.clone(),
+                arg,
+                ctx.diag,
+            );
+            statements.push(Expression::StoreLocalVariable {
+                name: local_name(arg_name),
+                value: Box::new(value),
+            });
+        }
+
+        let mut body = function.body;
+        body.visit_recursive_mut(&mut |e| match e {
+            Expression::ReadLocalVariable { name, .. }
+            | Expression::StoreLocalVariable { name, .. } => *name = local_name(name),
+            Expression::ArrayOperation { element, index, .. } => {
+                *element = local_name(element);
+                *index = local_name(index);
+            }
+            _ => {}
+        });
+        match body {
+            Expression::CodeBlock(body) => statements.extend(body),
+            body => statements.push(body),
+        }
+        Expression::CodeBlock(statements)
+    }
+
     fn from_member_access_node(
         node: syntax_nodes::MemberAccess,
         ctx: &mut LookupCtx,
@@ -799,6 +1440,14 @@ fn from_binary_expression_node(
         let lhs = Self::from_expression_node(lhs_n.clone(), ctx);
         let rhs = Self::from_expression_node(rhs_n.clone(), ctx);
 
+        if (lhs.ty() == Type::DateTime || rhs.ty() == Type::DateTime)
+            && lhs.ty() != Type::String
+            && rhs.ty() != Type::String
+            && operator_class(op) != OperatorClass::LogicalOp
+        {
+            return Self::from_date_time_binary_expression(lhs, lhs_n, rhs, rhs_n, op, &node, ctx);
+        }
+
         let expected_ty = match operator_class(op) {
             OperatorClass::ComparisonOp => {
                 Self::common_target_type_for_type_list([lhs.ty(), rhs.ty()].iter().cloned())
@@ -868,6 +1517,62 @@ fn from_binary_expression_node(
         }
     }
 
+    /// The operations on `date-time` are done on the `duration` since the Unix epoch
+    fn from_date_time_binary_expression(
+        lhs: Expression,
+        lhs_n: syntax_nodes::Expression,
+        rhs: Expression,
+        rhs_n: syntax_nodes::Expression,
+        op: char,
+        node: &syntax_nodes::BinaryExpression,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let as_duration =
+            |e: Expression| Expression::Cast { from: Box::new(e), to: Type::Duration };
+        match (op, lhs.ty(), rhs.ty()) {
+            (_, Type::Invalid, _) | (_, _, Type::Invalid) => Expression::Invalid,
+            ('=' | '!' | '<' | '>' | '≤' | '≥' | '-', Type::DateTime, Type::DateTime) => {
+                Expression::BinaryExpression {
+                    lhs: Box::new(as_duration(lhs)),
+                    rhs: Box::new(as_duration(rhs)),
+                    op,
+                }
+            }
+            ('+' | '-', Type::DateTime, ref other) | ('+', ref other, Type::DateTime)
+                if *other != Type::DateTime =>
+            {
+                let (date, duration, duration_n) =
+                    if lhs.ty() == Type::DateTime { (lhs, rhs, rhs_n) } else { (rhs, lhs, lhs_n) };
+                Expression::Cast {
+                    from: Box::new(Expression::BinaryExpression {
+                        lhs: Box::new(as_duration(date)),
+                        rhs: Box::new(duration.maybe_convert_to(
+                            Type::Duration,
+                            &duration_n,
+                            ctx.diag,
+                        )),
+                        op,
+                    }),
+                    to: Type::DateTime,
+                }
+            }
+            (_, lhs_ty, rhs_ty) => {
+                let op = match op {
+                    '=' => "==".into(),
+                    '!' => "!=".into(),
+                    '≤' => "<=".into(),
+                    '≥' => ">=".into(),
+                    op => op.to_string(),
+                };
+                ctx.diag.push_error(
+                    format!("Operator '{}' not supported between {} and {}", op, lhs_ty, rhs_ty),
+                    node,
+                );
+                Expression::Invalid
+            }
+        }
+    }
+
     fn from_unaryop_expression_node(
         node: syntax_nodes::UnaryOpExpression,
         ctx: &mut LookupCtx,
@@ -965,6 +1670,7 @@ fn from_object_literal_node(
             fields: values.iter().map(|(k, v)| (k.clone(), v.ty())).collect(),
             name: None,
             node: None,
+            definition: None,
         };
         Expression::Struct { ty, values }
     }
@@ -1026,8 +1732,14 @@ fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Type {
                             fields: mut result_fields,
                             name: result_name,
                             node: result_node,
+                            definition: result_definition,
+                        },
+                        Type::Struct {
+                            fields: elem_fields,
+                            name: elem_name,
+                            node: elem_node,
+                            definition: elem_definition,
                         },
-                        Type::Struct { fields: elem_fields, name: elem_name, node: elem_node },
                     ) => {
                         for (elem_name, elem_ty) in elem_fields.into_iter() {
                             match result_fields.entry(elem_name) {
@@ -1048,6 +1760,7 @@ fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Type {
                             name: result_name.or(elem_name),
                             fields: result_fields,
                             node: result_node.or(elem_node),
+                            definition: result_definition.or(elem_definition),
                         }
                     }
                     (Type::Color, Type::Brush) | (Type::Brush, Type::Color) => Type::Brush,
@@ -1071,6 +1784,76 @@ fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Type {
     }
 }
 
+/// Check the placeholders of the format string of a `@tr(...)`, and return false if there was an error
+fn check_tr_format_string(
+    format: &str,
+    node: &SyntaxNode,
+    arg_count: usize,
+    has_plural: bool,
+    diag: &mut BuildDiagnostics,
+) -> bool {
+    let mut next_arg = 0;
+    let mut max_positional = 0;
+    let mut pos = 0;
+    while let Some(p) = format[pos..].find(|c| c == '{' || c == '}') {
+        let p = p + pos;
+        if format[p..].starts_with("{{") || format[p..].starts_with("}}") {
+            pos = p + 2;
+            continue;
+        }
+        if format[p..].starts_with('}') {
+            diag.push_error("Unescaped '}' in format string. Escape '}' with '}}'".into(), node);
+            return false;
+        }
+        let Some(end) = format[p..].find('}').map(|end| end + p) else {
+            diag.push_error(
+                "Unterminated placeholder in format string. '{' must be escaped with '{{'".into(),
+                node,
+            );
+            return false;
+        };
+        let argument = &format[p + 1..end];
+        if argument.is_empty() {
+            next_arg += 1;
+        } else if let Ok(n) = argument.parse::<u16>() {
+            max_positional = max_positional.max(n as usize + 1);
+        } else if argument == "n" {
+            if !has_plural {
+                diag.push_error("`{n}` placeholder can only be found in plural form".into(), node);
+                return false;
+            }
+        } else {
+            diag.push_error(
+                "Invalid '{...}' placeholder in format string. The placeholder must be a number, or braces must be escaped with '{{' and '}}'".into(),
+                node,
+            );
+            return false;
+        }
+        pos = end + 1;
+    }
+    if next_arg > 0 && max_positional > 0 {
+        diag.push_error(
+            "Cannot mix positional and non-positional placeholder in format string".into(),
+            node,
+        );
+        false
+    } else if next_arg.max(max_positional) > arg_count {
+        let note = if has_plural { ". Note: use `{n}` for the argument after '%'" } else { "" };
+        diag.push_error(
+            format!(
+                "Format string contains {} placeholders, but only {} extra arguments were given{}",
+                next_arg.max(max_positional),
+                arg_count,
+                note
+            ),
+            node,
+        );
+        false
+    } else {
+        true
+    }
+}
+
 fn continue_lookup_within_element(
     elem: &ElementRc,
     it: &mut impl Iterator<Item = crate::parser::SyntaxToken>,
@@ -1275,6 +2058,7 @@ fn resolve_two_way_bindings(
                             component_scope: &new_scope.0,
                             diag,
                             arguments: vec![],
+                            local_variables: vec![],
                             type_register,
                             type_loader: None,
                             current_token: Some(node.clone().into()),
@@ -1422,3 +2206,24 @@ pub fn resolve_two_way_binding(
         }
     }
 }
+
+/// Returns the declaration of the function in the .slint file, following the base components
+fn function_declaration(
+    nr: &NamedReference,
+) -> Option<(syntax_nodes::Function, Vec<Option<Expression>>)> {
+    let mut element = nr.element();
+    loop {
+        let base = {
+            let e = element.borrow();
+            if let Some(decl) = e.property_declarations.get(nr.name()) {
+                let node = decl.node.clone().and_then(syntax_nodes::Function::new)?;
+                return Some((node, decl.default_arguments.clone()));
+            }
+            match &e.base_type {
+                ElementType::Component(c) => c.root_element.clone(),
+                _ => return None,
+            }
+        };
+        element = base;
+    }
+}
@@ -53,6 +53,7 @@ fn new() -> Self {
                         name: stringify!($Name).replace('_', "-"),
                         values: vec![$(crate::generator::to_kebab_case(stringify!($Value).trim_start_matches("r#"))),*],
                         default_value: 0,
+                        node: None,
                     })),*
                 }
             }
@@ -185,7 +186,7 @@ pub fn reserved_member_function(name: &str) -> Option<BuiltinFunction> {
     None
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct TypeRegister {
     /// The set of property types.
     types: HashMap<String, Type>,
@@ -221,6 +222,7 @@ pub fn builtin() -> Rc<RefCell<Self>> {
         register.insert_type(Type::LogicalLength);
         register.insert_type(Type::Color);
         register.insert_type(Type::Duration);
+        register.insert_type(Type::DateTime);
         register.insert_type(Type::Image);
         register.insert_type(Type::Bool);
         register.insert_type(Type::Model);
@@ -263,6 +265,20 @@ pub fn builtin() -> Rc<RefCell<Self>> {
             _ => unreachable!(),
         };
 
+        match &mut register.elements.get_mut("ChildWindow").unwrap() {
+            ElementType::Builtin(ref mut b) => {
+                let b = Rc::get_mut(b).unwrap();
+                for (name, function) in [
+                    ("show", BuiltinFunction::ShowChildWindow),
+                    ("hide", BuiltinFunction::HideChildWindow),
+                ] {
+                    b.properties.insert(name.into(), BuiltinPropertyInfo::new(function.ty()));
+                    b.member_functions.insert(name.into(), function);
+                }
+            }
+            _ => unreachable!(),
+        };
+
         Rc::new(RefCell::new(register))
     }
 
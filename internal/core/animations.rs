@@ -5,7 +5,10 @@
 //! The animation system
 
 use alloc::boxed::Box;
-use core::cell::Cell;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::pin::Pin;
 
 mod cubic_bezier {
     //! This is a copy from lyon_algorithms::geom::cubic_bezier implementation
@@ -145,9 +148,37 @@ pub enum EasingCurve {
     Linear,
     /// A Cubic bezier curve, with its 4 parameter
     CubicBezier([f32; 4]),
+    /// An elastic curve that oscillates around the start value before leaving it
+    EaseInElastic,
+    /// An elastic curve that overshoots the end value and oscillates around it
+    EaseOutElastic,
+    /// The combination of [`EasingCurve::EaseInElastic`] and [`EasingCurve::EaseOutElastic`]
+    EaseInOutElastic,
+    /// A curve that bounces off the start value
+    EaseInBounce,
+    /// A curve that bounces off the end value
+    EaseOutBounce,
+    /// The combination of [`EasingCurve::EaseInBounce`] and [`EasingCurve::EaseOutBounce`]
+    EaseInOutBounce,
+    /// A physics based spring, with its stiffness, damping and mass.
+    ///
+    /// The duration of the animation is ignored, the animation lasts until the spring settles.
+    Spring([f32; 3]),
+    /// The progress goes linearly through each of the keyframes, sorted by position
+    Keyframes(crate::slice::Slice<'static, EasingKeyframe>),
     //Custom(Box<dyn Fn(f32) -> f32>),
 }
 
+/// A keyframe of an [`EasingCurve::Keyframes`] curve
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EasingKeyframe {
+    /// The position of the keyframe within the animation, between 0 and 1
+    pub position: f32,
+    /// The progress of the animation at this keyframe. 0 is the start value and 1 the end value
+    pub progress: f32,
+}
+
 /// Represent an instant, in milliseconds since the AnimationDriver's initial_instant
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Ord, PartialOrd, Eq)]
@@ -212,6 +243,9 @@ pub struct AnimationDriver {
     /// Indicate whether there are any active animations that require a future call to update_animations.
     active_animations: Cell<bool>,
     global_instant: core::pin::Pin<Box<crate::Property<Instant>>>,
+    /// Animation events that were emitted while evaluating bindings, and that are delivered
+    /// on the next call to update_animations
+    pending_events: RefCell<Vec<Box<dyn FnOnce()>>>,
 }
 
 impl Default for AnimationDriver {
@@ -222,6 +256,7 @@ fn default() -> Self {
                 Instant::default(),
                 "i_slint_core::AnimationDriver::global_instant",
             )),
+            pending_events: Default::default(),
         }
     }
 }
@@ -234,6 +269,11 @@ pub fn update_animations(&self, new_tick: Instant) {
             self.active_animations.set(false);
             self.global_instant.as_ref().set(new_tick);
         }
+        // Take the events out first, as the handlers might cause new events to be queued
+        let pending_events = core::mem::take(&mut *self.pending_events.borrow_mut());
+        for event in pending_events {
+            event();
+        }
     }
 
     /// Returns true if there are any active or ready animations. This is used by the windowing system to determine
@@ -251,6 +291,14 @@ pub fn set_has_active_animations(&self) {
     pub fn current_tick(&self) -> Instant {
         self.global_instant.as_ref().get()
     }
+
+    /// Queue a callback to be invoked on the next call to update_animations.
+    /// This is used to report animation events, since they are detected while evaluating
+    /// a binding, where it's not possible to run arbitrary code.
+    fn queue_event(&self, event: Box<dyn FnOnce()>) {
+        self.pending_events.borrow_mut().push(event);
+        self.set_has_active_animations();
+    }
 }
 
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
@@ -277,10 +325,213 @@ pub fn animation_tick() -> u64 {
     })
 }
 
+/// The events reported by an [`AnimationController`] to its event handler
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnimationEvent {
+    /// The animation started, after its delay
+    Started,
+    /// The animation reached its end, or was cancelled
+    Finished,
+}
+
+struct AnimationControllerInner {
+    /// Changed every time the animation is controlled, so that the animated binding gets re-evaluated
+    revision: Pin<Box<crate::Property<u32>>>,
+    running: Cell<bool>,
+    progress: Cell<f32>,
+    paused_at: Cell<Option<Instant>>,
+    /// The total time spent in pause, not counting the current pause
+    paused_total: Cell<core::time::Duration>,
+    seek_request: Cell<Option<core::time::Duration>>,
+    cancel_request: Cell<bool>,
+    event_handler: RefCell<Option<Rc<dyn Fn(AnimationEvent)>>>,
+}
+
+/// A handle to query and control the animation of a property.
+///
+/// The controller is obtained from a property that has an `animate` declaration, and
+/// stays valid for the successive animations of that property: when the property gets a
+/// new value, a new animation starts and is controlled by the same controller.
+///
+/// Cloning the controller gives another handle to the same animation.
+#[derive(Clone)]
+pub struct AnimationController(Rc<AnimationControllerInner>);
+
+impl Default for AnimationController {
+    fn default() -> Self {
+        Self(Rc::new(AnimationControllerInner {
+            revision: Box::pin(crate::Property::new_named(
+                0,
+                "i_slint_core::AnimationController::revision",
+            )),
+            running: Cell::new(false),
+            progress: Cell::new(0.),
+            paused_at: Cell::new(None),
+            paused_total: Cell::new(Default::default()),
+            seek_request: Cell::new(None),
+            cancel_request: Cell::new(false),
+            event_handler: Default::default(),
+        }))
+    }
+}
+
+impl core::fmt::Debug for AnimationController {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimationController")
+            .field("running", &self.0.running.get())
+            .field("paused", &self.is_paused())
+            .field("progress", &self.0.progress.get())
+            .finish()
+    }
+}
+
+impl PartialEq for AnimationController {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl AnimationController {
+    /// Returns true if the animation has started and has not finished yet.
+    /// A paused animation is still running.
+    pub fn is_running(&self) -> bool {
+        self.0.running.get()
+    }
+
+    /// Returns true if the animation is paused
+    pub fn is_paused(&self) -> bool {
+        self.0.paused_at.get().is_some()
+    }
+
+    /// Returns the progress of the current iteration of the animation, between 0 and 1,
+    /// before the easing curve is applied.
+    pub fn progress(&self) -> f32 {
+        self.0.progress.get()
+    }
+
+    /// Pause the animation. The property keeps its current value until [`Self::resume()`] is called.
+    pub fn pause(&self) {
+        if self.0.paused_at.get().is_none() {
+            self.0.paused_at.set(Some(untracked_tick()));
+            self.changed();
+        }
+    }
+
+    /// Resume an animation that was paused with [`Self::pause()`]
+    pub fn resume(&self) {
+        if let Some(paused_at) = self.0.paused_at.take() {
+            let paused = untracked_tick().0.saturating_sub(paused_at.0);
+            self.0
+                .paused_total
+                .set(self.0.paused_total.get() + core::time::Duration::from_millis(paused));
+            self.changed();
+        }
+    }
+
+    /// Move the animation to the given position from its start, including its delay.
+    /// If the animation had already finished, it is started again from that position.
+    pub fn seek(&self, position: core::time::Duration) {
+        self.0.seek_request.set(Some(position));
+        self.changed();
+    }
+
+    /// Stop the animation: the property jumps to its final value, and the animation is
+    /// reported as finished.
+    pub fn cancel(&self) {
+        self.0.cancel_request.set(true);
+        self.changed();
+    }
+
+    /// Set the function that is called when the animation starts or finishes.
+    ///
+    /// The handler is called on the next animation frame after the event happened.
+    pub fn set_event_handler(&self, handler: impl Fn(AnimationEvent) + 'static) {
+        *self.0.event_handler.borrow_mut() = Some(Rc::new(handler));
+    }
+
+    fn changed(&self) {
+        let revision = self.0.revision.as_ref();
+        revision.set(revision.get_untracked().wrapping_add(1));
+        CURRENT_ANIMATION_DRIVER.with(|driver| driver.set_has_active_animations());
+    }
+
+    /// Register the current binding as depending on this controller
+    pub(crate) fn track(&self) {
+        self.0.revision.as_ref().get();
+    }
+
+    /// The total duration the animation was paused, up to the given instant
+    pub(crate) fn paused_duration(&self, now: Instant) -> core::time::Duration {
+        let current = self.0.paused_at.get().map_or(0, |p| now.0.saturating_sub(p.0));
+        self.0.paused_total.get() + core::time::Duration::from_millis(current)
+    }
+
+    pub(crate) fn has_seek_request(&self) -> bool {
+        self.0.seek_request.get().is_some()
+    }
+
+    pub(crate) fn take_seek_request(&self) -> Option<core::time::Duration> {
+        self.0.seek_request.take()
+    }
+
+    pub(crate) fn take_cancel_request(&self) -> bool {
+        self.0.cancel_request.take()
+    }
+
+    pub(crate) fn set_progress(&self, progress: f32) {
+        self.0.progress.set(progress);
+    }
+
+    /// Update the running state and queue the event for the event handler
+    pub(crate) fn report(&self, event: AnimationEvent) {
+        self.0.running.set(event == AnimationEvent::Started);
+        if self.0.event_handler.borrow().is_none() {
+            return;
+        }
+        let this = self.clone();
+        CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.queue_event(Box::new(move || {
+                // Clone the handler, so that it can replace itself
+                let handler = this.0.event_handler.borrow().clone();
+                if let Some(handler) = handler {
+                    handler(event)
+                }
+            }))
+        });
+    }
+}
+
+fn untracked_tick() -> Instant {
+    CURRENT_ANIMATION_DRIVER.with(|driver| driver.global_instant.as_ref().get_untracked())
+}
+
 /// map a value between 0 and 1 to another value between 0 and 1 according to the curve
+///
+/// [`EasingCurve::Spring`] doesn't depend on the duration and is not handled by this function,
+/// see [`spring_curve`] instead.
 pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
+    #[cfg(not(feature = "std"))]
+    use num_traits::Float;
+    const ELASTIC_PERIOD: f32 = 2. * core::f32::consts::PI / 3.;
+    fn ease_out_bounce(x: f32) -> f32 {
+        const N: f32 = 7.5625;
+        const D: f32 = 2.75;
+        if x < 1. / D {
+            N * x * x
+        } else if x < 2. / D {
+            let x = x - 1.5 / D;
+            N * x * x + 0.75
+        } else if x < 2.5 / D {
+            let x = x - 2.25 / D;
+            N * x * x + 0.9375
+        } else {
+            let x = x - 2.625 / D;
+            N * x * x + 0.984375
+        }
+    }
     match curve {
-        EasingCurve::Linear => value,
+        EasingCurve::Linear | EasingCurve::Spring(_) => value,
         EasingCurve::CubicBezier([a, b, c, d]) => {
             if !(0.0..=1.0).contains(a) && !(0.0..=1.0).contains(c) {
                 return value;
@@ -293,9 +544,105 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
             };
             curve.y(curve.solve_t_for_x(value, 0.0..1.0, 0.01))
         }
+        EasingCurve::EaseInElastic => {
+            if value <= 0. || value >= 1. {
+                return value;
+            }
+            -2f32.powf(10. * value - 10.) * ((value * 10. - 10.75) * ELASTIC_PERIOD).sin()
+        }
+        EasingCurve::EaseOutElastic => {
+            if value <= 0. || value >= 1. {
+                return value;
+            }
+            2f32.powf(-10. * value) * ((value * 10. - 0.75) * ELASTIC_PERIOD).sin() + 1.
+        }
+        EasingCurve::EaseInOutElastic => {
+            if value <= 0. || value >= 1. {
+                return value;
+            }
+            let period = 2. * core::f32::consts::PI / 4.5;
+            if value < 0.5 {
+                -(2f32.powf(20. * value - 10.) * ((20. * value - 11.125) * period).sin()) / 2.
+            } else {
+                2f32.powf(-20. * value + 10.) * ((20. * value - 11.125) * period).sin() / 2. + 1.
+            }
+        }
+        EasingCurve::EaseInBounce => 1. - ease_out_bounce(1. - value),
+        EasingCurve::EaseOutBounce => ease_out_bounce(value),
+        EasingCurve::EaseInOutBounce => {
+            if value < 0.5 {
+                (1. - ease_out_bounce(1. - 2. * value)) / 2.
+            } else {
+                (1. + ease_out_bounce(2. * value - 1.)) / 2.
+            }
+        }
+        EasingCurve::Keyframes(keyframes) => {
+            let mut previous = EasingKeyframe { position: 0., progress: 0. };
+            for keyframe in keyframes.iter() {
+                if value <= keyframe.position {
+                    let length = keyframe.position - previous.position;
+                    if length <= 0. {
+                        return keyframe.progress;
+                    }
+                    let t = (value - previous.position) / length;
+                    return previous.progress + t * (keyframe.progress - previous.progress);
+                }
+                previous = *keyframe;
+            }
+            let length = 1. - previous.position;
+            if length <= 0. {
+                return 1.;
+            }
+            previous.progress + (value - previous.position) / length * (1. - previous.progress)
+        }
     }
 }
 
+/// Compute the progress and the velocity (in progress per second) of a spring with the given
+/// `[stiffness, damping, mass]` parameters, `elapsed_seconds` after it was released at progress 0
+/// with the `initial_velocity` (also in progress per second). The spring pulls toward a progress of 1.
+pub fn spring_curve(
+    parameters: [f32; 3],
+    initial_velocity: f32,
+    elapsed_seconds: f32,
+) -> (f32, f32) {
+    #[cfg(not(feature = "std"))]
+    use num_traits::Float;
+    let [stiffness, damping, mass] = parameters;
+    let (stiffness, damping, mass) = (stiffness.max(0.001), damping.max(0.), mass.max(0.001));
+    let omega0 = (stiffness / mass).sqrt();
+    let zeta = damping / (2. * (stiffness * mass).sqrt());
+    let t = elapsed_seconds;
+    // x is the displacement relative to the target, so it starts at -1
+    let x0 = -1.;
+    let v0 = initial_velocity;
+    let (x, v) = if zeta < 1. {
+        let omega_d = omega0 * (1. - zeta * zeta).sqrt();
+        let a = x0;
+        let b = (v0 + zeta * omega0 * x0) / omega_d;
+        let envelope = (-zeta * omega0 * t).exp();
+        let (sin, cos) = (omega_d * t).sin_cos();
+        let x = envelope * (a * cos + b * sin);
+        let v = -zeta * omega0 * x + envelope * (-a * omega_d * sin + b * omega_d * cos);
+        (x, v)
+    } else if zeta == 1. {
+        let b = v0 + omega0 * x0;
+        let envelope = (-omega0 * t).exp();
+        let x = envelope * (x0 + b * t);
+        let v = envelope * (b - omega0 * (x0 + b * t));
+        (x, v)
+    } else {
+        let root = (zeta * zeta - 1.).sqrt();
+        let r1 = -omega0 * (zeta - root);
+        let r2 = -omega0 * (zeta + root);
+        let c2 = (v0 - r1 * x0) / (r2 - r1);
+        let c1 = x0 - c2;
+        let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+        (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+    };
+    (1. + x, v)
+}
+
 /*
 #[test]
 fn easing_test() {
@@ -12,6 +12,7 @@
 
 use crate::component::ComponentVTable;
 use crate::input::{KeyEventType, KeyInputEvent, MouseEvent};
+use crate::items::DropEvent;
 use crate::window::{WindowAdapter, WindowInner};
 
 /// A position represented in the coordinate space of logical pixels. That is the space before applying
@@ -370,6 +371,7 @@ pub fn on_close_requested(&self, callback: impl FnMut() -> CloseRequestResponse
     /// This function issues a request to the windowing system to redraw the contents of the window.
     pub fn request_redraw(&self) {
         self.0.window_adapter().request_redraw();
+        crate::platform::wake_event_loop();
     }
 
     /// This function returns the scale factor that allows converting between logical and
@@ -411,6 +413,51 @@ pub fn set_size(&self, size: impl Into<WindowSize>) {
         }
     }
 
+    /// Returns true if the window is in full screen mode.
+    ///
+    /// This is the value of the `full-screen` property of the `Window` element, which is also updated
+    /// when the user leaves the full screen mode.
+    pub fn is_fullscreen(&self) -> bool {
+        self.0.window_item().map_or(false, |window_item| window_item.as_pin_ref().full_screen())
+    }
+
+    /// Shows the window in full screen mode, or leaves the full screen mode.
+    pub fn set_fullscreen(&self, fullscreen: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().full_screen.set(fullscreen);
+        }
+    }
+
+    /// Returns true if the window is maximized.
+    ///
+    /// This is the value of the `maximized` property of the `Window` element, which is also updated
+    /// when the user maximizes or restores the window.
+    pub fn is_maximized(&self) -> bool {
+        self.0.window_item().map_or(false, |window_item| window_item.as_pin_ref().maximized())
+    }
+
+    /// Maximizes the window, or restores it to its previous size.
+    pub fn set_maximized(&self, maximized: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().maximized.set(maximized);
+        }
+    }
+
+    /// Returns true if the window is minimized.
+    ///
+    /// This is the value of the `minimized` property of the `Window` element, which is also updated
+    /// when the user minimizes or restores the window.
+    pub fn is_minimized(&self) -> bool {
+        self.0.window_item().map_or(false, |window_item| window_item.as_pin_ref().minimized())
+    }
+
+    /// Minimizes the window, or restores it.
+    pub fn set_minimized(&self, minimized: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().minimized.set(minimized);
+        }
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -463,6 +510,61 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                     ..Default::default()
                 })
             }
+            crate::platform::WindowEvent::Resized { size } => {
+                self.0.set_window_item_geometry(size.to_euclid());
+                self.0.inner_size.set(size.to_physical(self.scale_factor()));
+            }
+            crate::platform::WindowEvent::WindowStateChanged {
+                minimized,
+                maximized,
+                full_screen,
+            } => {
+                self.0.set_window_item_state(minimized, maximized, full_screen);
+            }
+            crate::platform::WindowEvent::ScaleFactorChanged { scale_factor } => {
+                self.0.set_scale_factor(scale_factor);
+            }
+            crate::platform::WindowEvent::WindowActiveChanged(active) => {
+                // Popups aren't separate windows yet, so the focus follows the activation
+                self.0.set_active(active);
+                self.0.set_focus(active);
+            }
+            crate::platform::WindowEvent::CloseRequested => {
+                if self.0.request_close() {
+                    if let Err(err) = self.hide() {
+                        crate::debug_log!("Error hiding the window after a close request: {}", err);
+                    }
+                }
+            }
+            crate::platform::WindowEvent::Preedit { text, selection_start, selection_end } => {
+                self.0.process_key_input(KeyInputEvent {
+                    text,
+                    event_type: KeyEventType::UpdateComposition,
+                    preedit_selection_start: selection_start,
+                    preedit_selection_end: selection_end,
+                    ..Default::default()
+                })
+            }
+            crate::platform::WindowEvent::Commit { text } => {
+                self.0.process_key_input(KeyInputEvent {
+                    text,
+                    event_type: KeyEventType::CommitComposition,
+                    ..Default::default()
+                })
+            }
+            crate::platform::WindowEvent::DragMoved { position, mime_type, data } => {
+                self.0.start_drag(DropEvent { mime_type, data, position: Default::default() });
+                self.0.process_mouse_input(MouseEvent::DragMove {
+                    position: position.to_euclid().cast(),
+                });
+            }
+            crate::platform::WindowEvent::DragExited => self.0.cancel_drag(),
+            crate::platform::WindowEvent::Dropped { position, mime_type, data } => {
+                self.0.start_drag(DropEvent { mime_type, data, position: Default::default() });
+                self.0.process_mouse_input(MouseEvent::Drop {
+                    position: position.to_euclid().cast(),
+                });
+            }
         }
     }
 
@@ -477,8 +579,44 @@ pub fn has_active_animations(&self) -> bool {
     pub fn is_visible(&self) -> bool {
         self.0.window_adapter().is_visible()
     }
+
+    /// Returns a future that completes after the next frame of this window was rendered.
+    /// It requests the window to be redrawn.
+    ///
+    /// This is useful in a future spawned with [`spawn_local()`], to let the window show an
+    /// intermediate state before continuing.
+    ///
+    /// No frame is rendered while the window is hidden: the future only completes after the
+    /// window is shown again and rendered, or after the window is destroyed.
+    pub fn next_frame(&self) -> impl core::future::Future<Output = ()> + 'static {
+        let window_adapter = alloc::rc::Rc::downgrade(&self.0.window_adapter());
+        let mut frame_count = None;
+        core::future::poll_fn(move |cx| {
+            let window_adapter = match window_adapter.upgrade() {
+                Some(window_adapter) => window_adapter,
+                // The window was destroyed, there won't be any frame
+                None => return core::task::Poll::Ready(()),
+            };
+            let window = WindowInner::from_pub(window_adapter.window());
+            let current_count = window.frame_count();
+            match frame_count {
+                Some(count) if current_count != count => core::task::Poll::Ready(()),
+                _ => {
+                    if frame_count.is_none() {
+                        frame_count = Some(current_count);
+                        window_adapter.request_redraw();
+                    }
+                    window.wake_after_next_frame(cx.waker().clone());
+                    core::task::Poll::Pending
+                }
+            }
+        })
+    }
 }
 
+pub use crate::clipboard::{clipboard_content, set_clipboard_content, ClipboardContent};
+#[cfg(feature = "std")]
+pub use crate::future::{sleep, spawn_local, JoinHandle};
 pub use crate::SharedString;
 
 /// This trait is used to obtain references to global singletons exported in `.slint`
@@ -718,7 +856,9 @@ unsafe impl<T: ComponentHandle> Send for Weak<T> {}
 pub fn invoke_from_event_loop(func: impl FnOnce() + Send + 'static) -> Result<(), EventLoopError> {
     crate::platform::event_loop_proxy()
         .ok_or(EventLoopError::NoEventLoopProvider)?
-        .invoke_from_event_loop(alloc::boxed::Box::new(func))
+        .invoke_from_event_loop(alloc::boxed::Box::new(func))?;
+    crate::platform::wake_event_loop();
+    Ok(())
 }
 
 /// Schedules the main event loop for termination. This function is meant
@@ -728,7 +868,9 @@ pub fn invoke_from_event_loop(func: impl FnOnce() + Send + 'static) -> Result<()
 pub fn quit_event_loop() -> Result<(), EventLoopError> {
     crate::platform::event_loop_proxy()
         .ok_or(EventLoopError::NoEventLoopProvider)?
-        .quit_event_loop()
+        .quit_event_loop()?;
+    crate::platform::wake_event_loop();
+    Ok(())
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
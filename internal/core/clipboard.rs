@@ -0,0 +1,152 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+This module contains the content of the clipboard and the functions to access it.
+*/
+
+#![warn(missing_docs)]
+
+use crate::graphics::{Image, Rgba8Pixel, SharedPixelBuffer};
+use crate::platform::Clipboard;
+use crate::SharedString;
+use alloc::vec::Vec;
+
+/// The content of the clipboard, in one or several formats.
+///
+/// Several formats can be provided at once, so that the application receiving the content
+/// can use the richest one it supports. For example HTML, with the plain text as fallback:
+///
+/// ```rust
+/// # use i_slint_core::api::ClipboardContent;
+/// let content = ClipboardContent::from_text("Hello")
+///     .with_html("<b>Hello</b>")
+///     .with_data("application/x-my-app", b"hello".to_vec());
+/// assert_eq!(content.data("text/html"), Some("<b>Hello</b>".as_bytes()));
+/// ```
+///
+/// Which formats reach the other applications depends on the backend:
+///  - Qt: all the formats, with the custom formats under their MIME type.
+///  - winit: the HTML with the text as fallback, or the text, or else the image. The formats that
+///    don't fit, such as the image of a content that also has text, and the custom formats, are
+///    only available to this application, as long as the clipboard contains what it put in it.
+///    On the web, Android, and iOS, as well as for the selection clipboard, only the text.
+///  - Backends that only implement [`Platform::set_clipboard_text`](crate::platform::Platform::set_clipboard_text):
+///    only the text.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ClipboardContent {
+    /// The plain text.
+    pub text: Option<SharedString>,
+    /// The content as HTML.
+    pub html: Option<SharedString>,
+    /// An image.
+    pub image: Option<SharedPixelBuffer<Rgba8Pixel>>,
+    /// The data in other formats, as pairs of MIME type and data.
+    pub custom: Vec<(SharedString, Vec<u8>)>,
+}
+
+impl ClipboardContent {
+    /// Creates a clipboard content with the given plain text.
+    pub fn from_text(text: impl Into<SharedString>) -> Self {
+        Self { text: Some(text.into()), ..Default::default() }
+    }
+
+    /// Creates a clipboard content with the given image.
+    pub fn from_image(image: SharedPixelBuffer<Rgba8Pixel>) -> Self {
+        Self { image: Some(image), ..Default::default() }
+    }
+
+    /// Adds the HTML representation of the content.
+    pub fn with_html(mut self, html: impl Into<SharedString>) -> Self {
+        self.html = Some(html.into());
+        self
+    }
+
+    /// Adds the data in the format of the given MIME type, such as `application/x-my-app`.
+    pub fn with_data(mut self, mime_type: impl Into<SharedString>, data: Vec<u8>) -> Self {
+        let mime_type = mime_type.into();
+        self.custom.retain(|(t, _)| *t != mime_type);
+        self.custom.push((mime_type, data));
+        self
+    }
+
+    /// Returns the data in the format of the given MIME type, if available.
+    ///
+    /// `text/plain` and `text/html` return the UTF-8 encoded text and HTML.
+    pub fn data(&self, mime_type: &str) -> Option<&[u8]> {
+        match mime_type {
+            "text/plain" => self.text.as_ref().map(|t| t.as_bytes()),
+            "text/html" => self.html.as_ref().map(|t| t.as_bytes()),
+            _ => self.custom.iter().find(|(t, _)| t == mime_type).map(|(_, data)| data.as_slice()),
+        }
+    }
+
+    /// Returns the MIME types of the formats available, with `image/png` for the image.
+    pub fn mime_types(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .as_ref()
+            .map(|_| "text/plain")
+            .into_iter()
+            .chain(self.html.as_ref().map(|_| "text/html"))
+            .chain(self.image.as_ref().map(|_| "image/png"))
+            .chain(self.custom.iter().map(|(t, _)| t.as_str()))
+    }
+}
+
+/// Puts the content into the system clipboard, replacing its previous content.
+///
+/// Does nothing if the platform doesn't support the clipboard.
+pub fn set_clipboard_content(content: ClipboardContent) {
+    crate::platform::PLATFORM_INSTANCE.with(|p| {
+        if let Some(platform) = p.get() {
+            platform.set_clipboard_content(content, Clipboard::DefaultClipboard)
+        }
+    })
+}
+
+/// Returns a copy of the content of the system clipboard, if any.
+pub fn clipboard_content() -> Option<ClipboardContent> {
+    crate::platform::PLATFORM_INSTANCE
+        .with(|p| p.get().and_then(|p| p.clipboard_content(Clipboard::DefaultClipboard)))
+}
+
+/// Implementation of `Clipboard.text()`
+pub fn clipboard_text() -> SharedString {
+    clipboard_content().and_then(|content| content.text).unwrap_or_default()
+}
+
+/// Implementation of `Clipboard.set-text(text)`
+pub fn set_clipboard_text(text: SharedString) {
+    set_clipboard_content(ClipboardContent::from_text(text))
+}
+
+/// Implementation of `Clipboard.image()`
+pub fn clipboard_image() -> Image {
+    clipboard_content().and_then(|content| content.image).map(Image::from_rgba8).unwrap_or_default()
+}
+
+/// Implementation of `Clipboard.set-image(image)`
+pub fn set_clipboard_image(image: &Image) {
+    if let Some(buffer) = image.to_rgba8() {
+        set_clipboard_content(ClipboardContent::from_image(buffer))
+    }
+}
+
+#[cfg(feature = "ffi")]
+pub(crate) mod ffi {
+    #![allow(unsafe_code)]
+    use super::*;
+
+    /// Set `out` to the text of the clipboard
+    #[no_mangle]
+    pub extern "C" fn slint_clipboard_text(out: &mut SharedString) {
+        *out = clipboard_text();
+    }
+
+    /// Put the text into the clipboard
+    #[no_mangle]
+    pub extern "C" fn slint_set_clipboard_text(text: &SharedString) {
+        set_clipboard_text(text.clone());
+    }
+}
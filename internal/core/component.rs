@@ -145,6 +145,8 @@ pub fn unregister_component<Base>(
     ).expect("Fatal error encountered when freeing graphics resources while destroying Slint component");
     window_adapter
         .unregister_component(component, &mut item_array.iter().map(|item| item.apply_pin(base)));
+    // The child windows declared in the component are destroyed with it
+    crate::window::WindowInner::from_pub(window_adapter.window()).drop_orphan_child_windows();
 }
 
 #[cfg(feature = "ffi")]
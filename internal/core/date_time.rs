@@ -0,0 +1,240 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! This module contains the [`DateTime`] type, corresponding to the `date-time` type of the .slint language.
+
+#![warn(missing_docs)]
+
+use crate::SharedString;
+
+const MILLIS_PER_SECOND: i64 = 1000;
+const MILLIS_PER_DAY: i64 = 24 * 60 * 60 * MILLIS_PER_SECOND;
+
+/// A point in time, with a precision of a millisecond, represented as the number of milliseconds
+/// since the Unix epoch (1970-01-01T00:00:00Z).
+///
+/// The calendar components such as [`year()`](Self::year) or [`hour()`](Self::hour) are
+/// computed in UTC, using the proleptic Gregorian calendar.
+///
+/// This type corresponds to the `date-time` type in the .slint language.
+///
+/// ```rust
+/// # use i_slint_core::date_time::DateTime;
+/// let date = DateTime::from_components(2023, 5, 4, 12, 30, 0);
+/// assert_eq!(date.year(), 2023);
+/// assert_eq!(date.month(), 5);
+/// assert_eq!(date.to_iso_string(), "2023-05-04T12:30:00Z");
+/// assert_eq!(DateTime::from_timestamp(date.timestamp()), date);
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    timestamp_ms: i64,
+}
+
+impl DateTime {
+    /// Creates a DateTime from a number of seconds since the Unix epoch.
+    pub const fn from_timestamp(seconds: i64) -> Self {
+        Self { timestamp_ms: seconds * MILLIS_PER_SECOND }
+    }
+
+    /// Creates a DateTime from a number of milliseconds since the Unix epoch.
+    pub const fn from_timestamp_millis(milliseconds: i64) -> Self {
+        Self { timestamp_ms: milliseconds }
+    }
+
+    /// Creates a DateTime from its calendar components, in UTC.
+    ///
+    /// `month` and `day` start at 1. Values out of their range overflow into the next
+    /// component, so that for example the 32nd of January is the first of February.
+    pub fn from_components(
+        year: i32,
+        month: i32,
+        day: i32,
+        hour: i32,
+        minute: i32,
+        second: i32,
+    ) -> Self {
+        let month = month as i64 - 1;
+        let year = year as i64 + month.div_euclid(12);
+        let days = days_from_civil(year, month.rem_euclid(12) as u32 + 1, 1) + day as i64 - 1;
+        let seconds = hour as i64 * 3600 + minute as i64 * 60 + second as i64;
+        Self { timestamp_ms: days * MILLIS_PER_DAY + seconds * MILLIS_PER_SECOND }
+    }
+
+    /// Returns the current date and time, from the system clock.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        std::time::SystemTime::now().into()
+    }
+
+    /// Returns the number of seconds since the Unix epoch, rounded down.
+    pub const fn timestamp(&self) -> i64 {
+        self.timestamp_ms.div_euclid(MILLIS_PER_SECOND)
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch.
+    pub const fn timestamp_millis(&self) -> i64 {
+        self.timestamp_ms
+    }
+
+    fn civil(&self) -> (i64, u32, u32) {
+        civil_from_days(self.timestamp_ms.div_euclid(MILLIS_PER_DAY))
+    }
+
+    fn millis_of_day(&self) -> i64 {
+        self.timestamp_ms.rem_euclid(MILLIS_PER_DAY)
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.civil().0 as i32
+    }
+
+    /// Returns the month, between 1 and 12.
+    pub fn month(&self) -> i32 {
+        self.civil().1 as i32
+    }
+
+    /// Returns the day of the month, between 1 and 31.
+    pub fn day(&self) -> i32 {
+        self.civil().2 as i32
+    }
+
+    /// Returns the hour, between 0 and 23.
+    pub fn hour(&self) -> i32 {
+        (self.millis_of_day() / (3600 * MILLIS_PER_SECOND)) as i32
+    }
+
+    /// Returns the minute, between 0 and 59.
+    pub fn minute(&self) -> i32 {
+        (self.millis_of_day() / (60 * MILLIS_PER_SECOND) % 60) as i32
+    }
+
+    /// Returns the second, between 0 and 59.
+    pub fn second(&self) -> i32 {
+        (self.millis_of_day() / MILLIS_PER_SECOND % 60) as i32
+    }
+
+    /// Returns the day of the week, between 0 (Sunday) and 6 (Saturday).
+    pub fn day_of_week(&self) -> i32 {
+        // The Unix epoch was a Thursday
+        ((self.timestamp_ms.div_euclid(MILLIS_PER_DAY) + 4).rem_euclid(7)) as i32
+    }
+
+    /// Returns the date and time formatted according to ISO 8601, such as `2023-05-04T12:30:00Z`.
+    pub fn to_iso_string(&self) -> SharedString {
+        let (year, month, day) = self.civil();
+        crate::format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::time::SystemTime> for DateTime {
+    fn from(time: std::time::SystemTime) -> Self {
+        let timestamp_ms = match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => d.as_millis() as i64,
+            Err(e) => -(e.duration().as_millis() as i64),
+        };
+        Self { timestamp_ms }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<DateTime> for std::time::SystemTime {
+    fn from(date: DateTime) -> Self {
+        let offset = core::time::Duration::from_millis(date.timestamp_ms.unsigned_abs());
+        if date.timestamp_ms < 0 {
+            std::time::UNIX_EPOCH - offset
+        } else {
+            std::time::UNIX_EPOCH + offset
+        }
+    }
+}
+
+/// Number of days since 1970-01-01 of the given date.
+/// Algorithm from <http://howardhinnant.github.io/date_algorithms.html>
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The (year, month, day) of the given number of days since 1970-01-01.
+/// This is the inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[test]
+fn test_date_time_components() {
+    let epoch = DateTime::default();
+    assert_eq!((epoch.year(), epoch.month(), epoch.day()), (1970, 1, 1));
+    assert_eq!(epoch.day_of_week(), 4);
+    assert_eq!(epoch.to_iso_string(), "1970-01-01T00:00:00Z");
+
+    let date = DateTime::from_timestamp(1_683_203_400);
+    assert_eq!(date, DateTime::from_components(2023, 5, 4, 12, 30, 0));
+    assert_eq!((date.hour(), date.minute(), date.second()), (12, 30, 0));
+    assert_eq!(date.day_of_week(), 4);
+
+    // Leap years
+    assert_eq!(
+        DateTime::from_components(2024, 2, 29, 0, 0, 0).to_iso_string(),
+        "2024-02-29T00:00:00Z"
+    );
+    assert_eq!(
+        DateTime::from_components(2023, 2, 29, 0, 0, 0).to_iso_string(),
+        "2023-03-01T00:00:00Z"
+    );
+    assert_eq!(DateTime::from_components(2000, 2, 29, 0, 0, 0).day(), 29);
+    assert_eq!(DateTime::from_components(1900, 2, 29, 0, 0, 0).day(), 1);
+
+    // Overflow and negative values
+    assert_eq!(
+        DateTime::from_components(2022, 13, 1, 0, 0, 0),
+        DateTime::from_components(2023, 1, 1, 0, 0, 0)
+    );
+    assert_eq!(
+        DateTime::from_components(2023, 1, 0, 0, 0, 0),
+        DateTime::from_components(2022, 12, 31, 0, 0, 0)
+    );
+    assert_eq!(
+        DateTime::from_components(2023, 1, 1, -1, 0, 0).to_iso_string(),
+        "2022-12-31T23:00:00Z"
+    );
+    let before_epoch = DateTime::from_timestamp_millis(-1);
+    assert_eq!(before_epoch.to_iso_string(), "1969-12-31T23:59:59Z");
+    assert_eq!(before_epoch.timestamp(), -1);
+    assert_eq!(before_epoch.day_of_week(), 3);
+}
+
+#[test]
+fn test_civil_round_trip() {
+    for days in -800_000..800_000 {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+This module contains the support for running futures in the Slint event loop.
+
+The futures are polled from the event loop thread, and woken up with the
+[`EventLoopProxy`](crate::platform::EventLoopProxy) of the platform.
+*/
+
+#![warn(missing_docs)]
+
+use crate::api::EventLoopError;
+use crate::timers::Timer;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+type SpawnedFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+thread_local! {
+    /// The futures spawned in this thread. A slot is `None` while its future is being polled.
+    static SPAWNED_FUTURES: RefCell<slab::Slab<Option<SpawnedFuture>>> = Default::default();
+}
+
+/// Polls the spawned future with the given id, if it's still running in this thread.
+fn poll_spawned_future(id: usize) {
+    // Take the future out of the slab while polling it, as it may spawn or abort other futures.
+    let mut future = match SPAWNED_FUTURES
+        .with(|futures| futures.borrow_mut().get_mut(id).and_then(Option::take))
+    {
+        Some(future) => future,
+        None => return,
+    };
+    let waker = Waker::from(Arc::new(SpawnedFutureWaker(id)));
+    let poll = future.as_mut().poll(&mut Context::from_waker(&waker));
+    SPAWNED_FUTURES.with(|futures| {
+        let mut futures = futures.borrow_mut();
+        match poll {
+            Poll::Ready(()) => {
+                futures.remove(id);
+            }
+            // Unless the future was aborted while it was polled
+            Poll::Pending => {
+                if let Some(slot @ None) = futures.get_mut(id) {
+                    *slot = Some(future);
+                }
+            }
+        }
+    });
+}
+
+/// The waker of the spawned futures. It only holds the id of the future, so it can be sent to
+/// other threads: waking it polls the future in the thread of the event loop.
+struct SpawnedFutureWaker(usize);
+
+impl Wake for SpawnedFutureWaker {
+    fn wake(self: Arc<Self>) {
+        let id = self.0;
+        // If the event loop was terminated, the future will never be polled again.
+        crate::api::invoke_from_event_loop(move || poll_spawned_future(id)).ok();
+    }
+}
+
+enum JoinState<T> {
+    /// Holds the wakers of the tasks waiting on the JoinHandle
+    Running(Vec<Waker>),
+    Finished(Option<T>),
+    Aborted,
+}
+
+/// The handle of a future spawned with [`spawn_local()`].
+///
+/// It's a future that completes with the result of the spawned future. Dropping the handle
+/// doesn't stop the spawned future, use [`Self::abort()`] for that.
+pub struct JoinHandle<T> {
+    id: usize,
+    state: Rc<RefCell<JoinState<T>>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Stops the spawned future. It's dropped and won't be polled anymore.
+    pub fn abort(self) {
+        let previous = core::mem::replace(&mut *self.state.borrow_mut(), JoinState::Aborted);
+        // Once finished, the id may already be used by another future
+        if matches!(previous, JoinState::Running(_)) {
+            // Drop the future outside of the borrow, in case it owns other JoinHandles
+            let future = SPAWNED_FUTURES.with(|futures| {
+                let mut futures = futures.borrow_mut();
+                futures.contains(self.id).then(|| futures.remove(self.id))
+            });
+            drop(future);
+        }
+    }
+
+    /// Returns true if the spawned future has completed.
+    pub fn is_finished(&self) -> bool {
+        !matches!(*self.state.borrow(), JoinState::Running(_))
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    /// Panics if the future was polled after completion, or if the future was aborted.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        match &mut *self.state.borrow_mut() {
+            JoinState::Running(join_wakers) => {
+                if !join_wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                    join_wakers.push(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+            JoinState::Finished(value) => {
+                Poll::Ready(value.take().expect("JoinHandle polled after completion"))
+            }
+            JoinState::Aborted => panic!("JoinHandle polled after abort"),
+        }
+    }
+}
+
+/// Spawns a [`Future`] to run in the Slint event loop.
+///
+/// The future doesn't need to be [`Send`], so it can hold and update the components directly.
+/// It's first polled the next time the event loop processes events, and then each time it's woken up.
+///
+/// This function must be called from the thread of the Slint event loop, after the platform is
+/// initialized, for example after creating a component. It returns an error if the platform
+/// doesn't provide an event loop proxy.
+///
+/// ## Example
+/// ```rust,no_run
+/// # i_slint_backend_testing::init();
+/// slint::slint!{ export component MyApp inherits Window { in property <string> status; } }
+/// let ui = MyApp::new().unwrap();
+/// let ui_weak = ui.as_weak();
+/// slint::spawn_local(async move {
+///     ui_weak.unwrap().set_status("Loading...".into());
+///     slint::sleep(std::time::Duration::from_secs(1)).await;
+///     ui_weak.unwrap().set_status("Done".into());
+/// })
+/// .unwrap();
+/// ui.run().unwrap();
+/// ```
+pub fn spawn_local<F: Future + 'static>(
+    future: F,
+) -> Result<JoinHandle<F::Output>, EventLoopError> {
+    let has_platform = crate::platform::PLATFORM_INSTANCE.with(|p| p.get().is_some());
+    if !has_platform || crate::platform::event_loop_proxy().is_none() {
+        return Err(EventLoopError::NoEventLoopProvider);
+    }
+    let state = Rc::new(RefCell::new(JoinState::Running(Vec::new())));
+    let future_state = state.clone();
+    let future: SpawnedFuture = Box::pin(async move {
+        let value = future.await;
+        let previous =
+            core::mem::replace(&mut *future_state.borrow_mut(), JoinState::Finished(Some(value)));
+        if let JoinState::Running(join_wakers) = previous {
+            join_wakers.into_iter().for_each(Waker::wake);
+        }
+    });
+    let id = SPAWNED_FUTURES.with(|futures| futures.borrow_mut().insert(Some(future)));
+    if let Err(err) = crate::api::invoke_from_event_loop(move || poll_spawned_future(id)) {
+        SPAWNED_FUTURES.with(|futures| futures.borrow_mut().remove(id));
+        return Err(err);
+    }
+    Ok(JoinHandle { id, state })
+}
+
+#[derive(Default)]
+struct SleepState {
+    elapsed: bool,
+    waker: Option<Waker>,
+}
+
+/// Returns a future that completes after the given duration, measured with a [`Timer`].
+///
+/// Like the timers, it must be awaited in the thread of the Slint event loop, for example in
+/// a future spawned with [`spawn_local()`].
+pub fn sleep(duration: core::time::Duration) -> impl Future<Output = ()> {
+    let state = Rc::new(RefCell::new(SleepState::default()));
+    let timer = Timer::default();
+    let mut started = false;
+    core::future::poll_fn(move |cx| {
+        let mut s = state.borrow_mut();
+        if s.elapsed {
+            return Poll::Ready(());
+        }
+        s.waker = Some(cx.waker().clone());
+        if !started {
+            started = true;
+            let state = state.clone();
+            timer.start(crate::timers::TimerMode::SingleShot, duration, move || {
+                let waker = {
+                    let mut s = state.borrow_mut();
+                    s.elapsed = true;
+                    s.waker.take()
+                };
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            });
+        }
+        Poll::Pending
+    })
+}
+
+#[test]
+fn spawn_local_and_sleep() {
+    use crate::platform::test_platform::process_events;
+    use core::cell::Cell;
+    use std::sync::Mutex;
+
+    assert!(matches!(spawn_local(async {}), Err(EventLoopError::NoEventLoopProvider)));
+    let _guard = crate::platform::test_platform::init();
+
+    // A value sent from another thread
+    let sent = Arc::new(Mutex::new((None, None::<Waker>)));
+    let received = sent.clone();
+    let doubled = spawn_local(core::future::poll_fn(move |cx| {
+        let mut received = received.lock().unwrap();
+        match received.0 {
+            Some(value) => Poll::Ready(value * 2),
+            None => {
+                received.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }))
+    .unwrap();
+
+    let result = Rc::new(Cell::new(0));
+    let result_clone = result.clone();
+    spawn_local(async move {
+        let value = doubled.await;
+        sleep(core::time::Duration::ZERO).await;
+        result_clone.set(value);
+    })
+    .unwrap();
+
+    let forever = spawn_local(core::future::pending::<()>()).unwrap();
+
+    process_events();
+    assert_eq!(result.get(), 0);
+    assert!(!forever.is_finished());
+    forever.abort();
+
+    std::thread::spawn(move || {
+        let mut sent = sent.lock().unwrap();
+        sent.0 = Some(21);
+        sent.1.take().unwrap().wake();
+    })
+    .join()
+    .unwrap();
+    // The first round polls the futures, the second one fires the timer of the sleep
+    process_events();
+    assert_eq!(result.get(), 0);
+    process_events();
+    assert_eq!(result.get(), 42);
+}
+
+#[test]
+fn next_frame_of_destroyed_window() {
+    use crate::platform::test_platform::process_events;
+    use crate::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+    use core::cell::Cell;
+
+    let _guard = crate::platform::test_platform::init();
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+    let next_frame = window.next_frame();
+    let done = Rc::new(Cell::new(false));
+    let done_clone = done.clone();
+    spawn_local(async move {
+        next_frame.await;
+        done_clone.set(true);
+    })
+    .unwrap();
+    process_events();
+    assert!(!done.get());
+
+    // No frame will ever be rendered, so the future completes when the window is destroyed
+    drop(window);
+    process_events();
+    assert!(done.get());
+}
@@ -628,6 +628,47 @@ pub fn size(&self) -> IntSize {
         }
     }
 
+    /// Returns the pixels of the image, where each pixel has four color channels (red, green,
+    /// blue and alpha) encoded as u8. Scalable images are rendered at their original size.
+    ///
+    /// Returns None if the pixels aren't accessible, for example when the image is stored
+    /// in the graphics memory by the backend.
+    pub fn to_rgba8(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+        let size = self.size();
+        let buffer = self.0.render_to_buffer(Some(euclid::size2(size.width, size.height)))?;
+        Some(match buffer {
+            SharedImageBuffer::RGB8(buffer) => SharedPixelBuffer {
+                width: buffer.width,
+                height: buffer.height,
+                data: buffer.data.iter().map(|p| Rgba8Pixel::new(p.r, p.g, p.b, 255)).collect(),
+            },
+            SharedImageBuffer::RGBA8(buffer) => buffer,
+            SharedImageBuffer::RGBA8Premultiplied(buffer) => SharedPixelBuffer {
+                width: buffer.width,
+                height: buffer.height,
+                data: buffer
+                    .data
+                    .iter()
+                    .map(|p| {
+                        let unpremultiply = |c: u8| {
+                            if p.a == 0 {
+                                0
+                            } else {
+                                (c as u16 * 255 / p.a as u16).min(255) as u8
+                            }
+                        };
+                        Rgba8Pixel::new(
+                            unpremultiply(p.r),
+                            unpremultiply(p.g),
+                            unpremultiply(p.b),
+                            p.a,
+                        )
+                    })
+                    .collect(),
+            },
+        })
+    }
+
     #[cfg(feature = "std")]
     /// Returns the path of the image on disk, if it was constructed via [`Self::load_from_path`].
     ///
@@ -674,6 +715,22 @@ fn test_image_size_from_buffer_without_backend() {
     }
 }
 
+#[test]
+fn test_image_to_rgba8() {
+    let mut buffer = SharedPixelBuffer::<Rgb8Pixel>::new(2, 1);
+    buffer.make_mut_slice()[1] = Rgb8Pixel::new(1, 2, 3);
+    let rgba = Image::from_rgb8(buffer).to_rgba8().unwrap();
+    assert_eq!(rgba.size(), [2, 1].into());
+    assert_eq!(rgba.as_slice(), &[Rgba8Pixel::new(0, 0, 0, 255), Rgba8Pixel::new(1, 2, 3, 255)]);
+
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(1, 1);
+    buffer.make_mut_slice()[0] = Rgba8Pixel::new(50, 25, 0, 127);
+    let rgba = Image::from_rgba8_premultiplied(buffer).to_rgba8().unwrap();
+    assert_eq!(rgba.as_slice(), &[Rgba8Pixel::new(100, 50, 0, 127)]);
+
+    assert!(Image::default().to_rgba8().is_none());
+}
+
 #[cfg(feature = "svg")]
 #[test]
 fn test_image_size_from_svg() {
@@ -780,4 +837,14 @@ struct Rgba8Pixel {
     ) {
         core::ptr::write(image, Image::from(ImageInner::StaticTextures(textures)));
     }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_clipboard_image(image: *mut Image) {
+        core::ptr::write(image, crate::clipboard::clipboard_image());
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_set_clipboard_image(image: &Image) {
+        crate::clipboard::set_clipboard_image(image);
+    }
 }
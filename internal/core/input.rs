@@ -46,6 +46,11 @@ pub enum MouseEvent {
     Wheel { position: LogicalPoint, delta_x: f32, delta_y: f32 },
     /// The mouse exited the item or component
     Exit,
+    /// Data is being dragged over the item. The dragged data is provided by
+    /// [`WindowInner::drag_data()`].
+    DragMove { position: LogicalPoint },
+    /// The dragged data was dropped at the given position.
+    Drop { position: LogicalPoint },
 }
 
 impl MouseEvent {
@@ -57,6 +62,8 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
             MouseEvent::Exit => None,
+            MouseEvent::DragMove { position } => Some(*position),
+            MouseEvent::Drop { position } => Some(*position),
         }
     }
 
@@ -68,6 +75,8 @@ pub fn translate(&mut self, vec: LogicalVector) {
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
             MouseEvent::Exit => None,
+            MouseEvent::DragMove { position } => Some(position),
+            MouseEvent::Drop { position } => Some(position),
         };
         if let Some(pos) = pos {
             *pos += vec;
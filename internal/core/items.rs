@@ -42,6 +42,8 @@
 use i_slint_core_macros::*;
 use vtable::*;
 
+mod drag_n_drop;
+pub use drag_n_drop::*;
 mod flickable;
 pub use flickable::*;
 mod text;
@@ -62,6 +64,7 @@
 pub type KeyEventArg = (KeyEvent,);
 type PointerEventArg = (PointerEvent,);
 type PointArg = (Point,);
+type DropEventArg = (DropEvent,);
 
 #[cfg(all(feature = "ffi", windows))]
 #[macro_export]
@@ -493,7 +496,8 @@ fn input_event_filter_before_children(
         window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> InputEventFilterResult {
-        if !self.enabled() {
+        if !self.enabled() || matches!(event, MouseEvent::DragMove { .. } | MouseEvent::Drop { .. })
+        {
             return InputEventFilterResult::ForwardAndIgnore;
         }
         if let Some(pos) = event.position() {
@@ -583,6 +587,9 @@ fn input_event(
                     InputEventResult::EventAccepted
                 }
             }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                return InputEventResult::EventIgnored;
+            }
         };
         result
     }
@@ -1164,13 +1171,21 @@ pub struct PropertyAnimation {
     pub iteration_count: f32,
     #[rtti_field]
     pub easing: crate::animations::EasingCurve,
+    #[rtti_field]
+    pub direction: AnimationDirection,
 }
 
 impl Default for PropertyAnimation {
     fn default() -> Self {
         // Defaults for PropertyAnimation are defined here (for internal Rust code doing programmatic animations)
         // as well as in `builtins.slint` (for generated C++ and Rust code)
-        Self { delay: 0, duration: 0, iteration_count: 1., easing: Default::default() }
+        Self {
+            delay: 0,
+            duration: 0,
+            iteration_count: 1.,
+            easing: Default::default(),
+            direction: Default::default(),
+        }
     }
 }
 
@@ -1185,6 +1200,10 @@ pub struct WindowItem {
     pub title: Property<SharedString>,
     pub no_frame: Property<bool>,
     pub always_on_top: Property<bool>,
+    pub full_screen: Property<bool>,
+    pub maximized: Property<bool>,
+    pub minimized: Property<bool>,
+    pub resizable: Property<bool>,
     pub icon: Property<crate::graphics::Image>,
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
@@ -1438,3 +1457,14 @@ pub struct PointerEvent {
     pub button: PointerEventButton,
     pub kind: PointerEventKind,
 }
+
+/// Represents the data of a drag and drop operation, passed to the callbacks of the `DropArea`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[repr(C)]
+pub struct DropEvent {
+    /// The type of the data, such as `text/plain` or `text/uri-list`
+    pub mime_type: SharedString,
+    pub data: SharedString,
+    /// The position of the pointer, relative to the `DropArea`
+    pub position: Point,
+}
@@ -0,0 +1,320 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! The `DragArea` and `DropArea` items
+
+use super::{
+    DropEvent, DropEventArg, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult,
+    MouseCursor, PointerEventButton, RenderingResult,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The distance the mouse needs to move while pressed before a drag operation starts.
+const DRAG_THRESHOLD: LogicalLength = LogicalLength::new(8 as _);
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DragArea {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    pub cached_rendering_data: CachedRenderingData,
+    /// The position where the left button was pressed, if it's still pressed
+    pressed_position: Cell<Option<LogicalPoint>>,
+}
+
+impl DragArea {
+    /// Returns true if the mouse was moved far enough from the position where it was pressed
+    /// to start dragging.
+    fn exceeds_threshold(self: Pin<&Self>, position: LogicalPoint) -> bool {
+        self.pressed_position.get().map_or(false, |pressed_position| {
+            (position - pressed_position).square_length()
+                > DRAG_THRESHOLD.get() * DRAG_THRESHOLD.get()
+        })
+    }
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.pressed_position.set(None);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed_position.set(Some(position));
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Moved { position } if self.pressed_position.get().is_some() => {
+                // Take the mouse away from the children once the mouse moved far enough
+                if self.exceeds_threshold(position) {
+                    InputEventFilterResult::Intercept
+                } else {
+                    InputEventFilterResult::ForwardAndInterceptGrab
+                }
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } | MouseEvent::Exit => {
+                self.pressed_position.set(None);
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            _ => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !self.enabled() {
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } => {
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { position } if self.pressed_position.get().is_some() => {
+                if !self.exceeds_threshold(position) {
+                    return InputEventResult::GrabMouse;
+                }
+                self.pressed_position.set(None);
+                WindowInner::from_pub(window_adapter.window()).start_drag(DropEvent {
+                    mime_type: self.mime_type(),
+                    data: self.data(),
+                    position: Default::default(),
+                });
+                // Releasing the grab lets the window deliver the following events to the drop areas
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } | MouseEvent::Exit => {
+                self.pressed_position.set(None);
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DragArea,
+        CachedRenderingData,
+    > = DragArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DropArea {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl DropArea {
+    /// Returns the data being dragged, with the position relative to this item
+    fn drop_event(
+        window_adapter: &Rc<dyn WindowAdapter>,
+        position: LogicalPoint,
+    ) -> Option<DropEvent> {
+        let mut event = WindowInner::from_pub(window_adapter.window()).drag_data()?;
+        event.position = position.to_untyped();
+        Some(event)
+    }
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !self.enabled() {
+            self.contains_drag.set(false);
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::DragMove { position } => {
+                let accepted = Self::drop_event(window_adapter, position).map_or(false, |event| {
+                    Self::FIELD_OFFSETS.can_drop.apply_pin(self).call(&(event,))
+                });
+                self.contains_drag.set(accepted);
+                if accepted {
+                    window_adapter.set_mouse_cursor(MouseCursor::Copy);
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Drop { position } => {
+                let was_accepted = self.contains_drag();
+                self.contains_drag.set(false);
+                match Self::drop_event(window_adapter, position) {
+                    // Check again, the drag may not have been moved over the area before the drop
+                    Some(event)
+                        if was_accepted
+                            || Self::FIELD_OFFSETS
+                                .can_drop
+                                .apply_pin(self)
+                                .call(&(event.clone(),)) =>
+                    {
+                        Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(event,));
+                        InputEventResult::EventAccepted
+                    }
+                    _ => InputEventResult::EventIgnored,
+                }
+            }
+            MouseEvent::Exit => {
+                self.contains_drag.set(false);
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DropArea,
+        CachedRenderingData,
+    > = DropArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
@@ -276,6 +276,9 @@ pub fn handle_mouse_filter(
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                InputEventFilterResult::ForwardAndIgnore
+            }
         }
     }
 
@@ -363,6 +366,7 @@ pub fn handle_mouse(
                     .set(new_pos.y_length());
                 InputEventResult::EventAccepted
             }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -20,7 +20,11 @@
 pub mod animations;
 pub mod api;
 pub mod callbacks;
+pub mod clipboard;
 pub mod component;
+pub mod date_time;
+#[cfg(feature = "std")]
+pub mod future;
 pub mod graphics;
 pub mod input;
 pub mod item_focus;
@@ -40,6 +44,7 @@
 pub mod tests;
 pub mod textlayout;
 pub mod timers;
+pub mod translations;
 pub mod window;
 
 #[cfg(feature = "rtti")]
@@ -108,6 +113,7 @@ pub fn use_modules() -> usize {
     {
         tests::slint_mock_elapsed_time as usize
             + callbacks::ffi::slint_callback_init as usize
+            + clipboard::ffi::slint_clipboard_text as usize
             + sharedvector::ffi::slint_shared_vector_empty as usize
             + layout::ffi::slint_solve_grid_layout as usize
             + item_tree::ffi::slint_visit_item_tree as usize
@@ -117,6 +123,7 @@ pub fn use_modules() -> usize {
             + window::ffi::slint_windowrc_drop as usize
             + component::ffi::slint_register_component as usize
             + timers::ffi::slint_timer_start as usize
+            + translations::ffi::slint_translate as usize
             + graphics::color::ffi::slint_color_brighter as usize
             + graphics::image::ffi::slint_image_size as usize
     }
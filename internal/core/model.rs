@@ -7,7 +7,7 @@
 
 use crate::component::ComponentVTable;
 use crate::item_tree::TraversalOrder;
-use crate::items::{ItemRef, SortOrder};
+use crate::items::{ItemRef, ScrollAlignment, SortOrder};
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
@@ -883,6 +883,8 @@ fn ensure_updated_impl(
     }
 
     /// Same as `Self::ensuer_updated` but for a ListView
+    ///
+    /// Returns the range of the rows that are visible in the ListView
     pub fn ensure_updated_listview(
         self: Pin<&Self>,
         init: impl Fn() -> ComponentRc<C>,
@@ -891,7 +893,7 @@ pub fn ensure_updated_listview(
         viewport_y: Pin<&Property<LogicalLength>>,
         listview_width: LogicalLength,
         listview_height: Pin<&Property<LogicalLength>>,
-    ) {
+    ) -> core::ops::Range<usize> {
         viewport_width.set(listview_width);
         let model = self.model();
         let row_count = model.row_count();
@@ -900,7 +902,7 @@ pub fn ensure_updated_listview(
             viewport_height.set(LogicalLength::zero());
             viewport_y.set(LogicalLength::zero());
 
-            return;
+            return 0..0;
         }
 
         let listview_height = listview_height.get();
@@ -1081,7 +1083,7 @@ pub fn ensure_updated_listview(
             }
 
             if inner.components.is_empty() {
-                break;
+                break 0..0;
             }
 
             // Now re-compute some coordinate such a way that the scrollbar are adjusted.
@@ -1091,10 +1093,83 @@ pub fn ensure_updated_listview(
             let new_viewport_y = -inner.anchor_y + vp_y + new_offset_y;
             viewport_y.set(new_viewport_y);
             inner.previous_viewport_y = new_viewport_y;
-            break;
+            break new_offset..idx;
         }
     }
 
+    /// Scroll the viewport of a ListView such that `row` is visible and placed according to `alignment`.
+    ///
+    /// The position of the rows that are not instantiated is estimated with the same average item
+    /// height that is used by [`Self::ensure_updated_listview`].
+    /// Returns false if the ListView was never laid out, in which case there is no estimation yet and
+    /// the call should be repeated after the next call to `ensure_updated_listview`.
+    pub fn listview_scroll_to(
+        self: Pin<&Self>,
+        row: usize,
+        alignment: ScrollAlignment,
+        viewport_y: Pin<&Property<LogicalLength>>,
+        listview_height: LogicalLength,
+    ) -> bool {
+        let row_count = self.model().row_count();
+        if row_count == 0 {
+            return true;
+        }
+        let row = row.min(row_count - 1);
+
+        let inner = self.0.inner.borrow();
+        if inner.cached_item_height <= LogicalLength::zero() {
+            return false;
+        }
+        let item_height = |c: &(RepeatedComponentState, Option<ComponentRc<C>>)| {
+            c.1.as_ref().map_or(inner.cached_item_height, |x| {
+                x.as_pin_ref().get_item_ref(0).as_ref().geometry().height_length()
+            })
+        };
+
+        // The coordinates are relative to `anchor_y`, like the viewport_y computed by ensure_updated_listview
+        let row_y = |row: usize| {
+            if row < inner.offset {
+                inner.anchor_y - inner.cached_item_height * (inner.offset - row) as Coord
+            } else {
+                let index = row - inner.offset;
+                let mut y = inner.anchor_y;
+                for c in inner.components.iter().take(index) {
+                    y += item_height(c);
+                }
+                if index > inner.components.len() {
+                    y += inner.cached_item_height * (index - inner.components.len()) as Coord;
+                }
+                y
+            }
+        };
+        let item_y = row_y(row);
+        let item_h = row
+            .checked_sub(inner.offset)
+            .and_then(|index| inner.components.get(index))
+            .map_or(inner.cached_item_height, item_height);
+        // The estimated position of the end of the last row
+        let content_height = row_y(row_count);
+
+        let current_y = viewport_y.get();
+        let new_y = match alignment {
+            ScrollAlignment::Start => -item_y,
+            ScrollAlignment::Center => (listview_height - item_h) / 2 as Coord - item_y,
+            ScrollAlignment::End => listview_height - item_h - item_y,
+            ScrollAlignment::Nearest => {
+                if item_y < -current_y {
+                    -item_y
+                } else if item_y + item_h > -current_y + listview_height {
+                    listview_height - item_h - item_y
+                } else {
+                    current_y
+                }
+            }
+        };
+        // Don't scroll past the end of the content
+        viewport_y.set(new_y.max(listview_height - content_height).min(LogicalLength::zero()));
+        true
+    }
+
     /// Sets the data directly in the model
     pub fn model_set_row_data(self: Pin<&Self>, row: usize, data: C::Data) {
         let model = self.model();
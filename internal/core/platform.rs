@@ -7,8 +7,9 @@
 
 #![warn(missing_docs)]
 
-use crate::api::LogicalPosition;
 pub use crate::api::PlatformError;
+use crate::api::{LogicalPosition, LogicalSize};
+pub use crate::clipboard::ClipboardContent;
 pub use crate::software_renderer;
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
 use crate::unsafe_single_threaded::{thread_local, OnceCell};
@@ -30,6 +31,22 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
         Err(PlatformError::NoEventLoopProvider)
     }
 
+    /// Processes the pending events, waiting up to `timeout` for new events if there are none, and
+    /// renders the visible windows. Unlike [`Self::run_event_loop()`], it returns after one iteration
+    /// of the event loop, so that Slint can be integrated in another event loop.
+    ///
+    /// Returns [`ControlFlow::Break`](core::ops::ControlFlow::Break) when the event loop would have
+    /// exited, because [`slint::quit_event_loop()`](crate::api::quit_event_loop()) was called, or
+    /// because no window remains visible.
+    ///
+    /// See also [`set_event_loop_waker()`] to know when to call this function again.
+    fn process_events(
+        &self,
+        _timeout: core::time::Duration,
+    ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+        Err(PlatformError::NoEventLoopProvider)
+    }
+
     /// Specify if the event loop should quit quen the last window is closed.
     /// The default behavior is `true`.
     /// When this is set to `false`, the event loop must keep running until
@@ -85,6 +102,22 @@ fn clipboard_text(&self, _clipboard: Clipboard) -> Option<String> {
         None
     }
 
+    /// Sends the given content into the system clipboard, in all the formats it provides.
+    ///
+    /// The default implementation only sends the text, with [`Self::set_clipboard_text`].
+    fn set_clipboard_content(&self, content: ClipboardContent, clipboard: Clipboard) {
+        if let Some(text) = content.text {
+            self.set_clipboard_text(&text, clipboard)
+        }
+    }
+
+    /// Returns a copy of the content stored in the system clipboard, if any.
+    ///
+    /// The default implementation only returns the text, from [`Self::clipboard_text`].
+    fn clipboard_content(&self, clipboard: Clipboard) -> Option<ClipboardContent> {
+        self.clipboard_text(clipboard).map(ClipboardContent::from_text)
+    }
+
     /// This function is called when debug() is used in .slint files. The implementation
     /// should direct the output to some developer visible terminal. The default implementation
     /// uses stderr if available, or `console.log` when targeting wasm.
@@ -93,7 +126,7 @@ fn debug_log(&self, _arguments: core::fmt::Arguments) {
     }
 }
 
-/// The clip board, used in [`Platform::clipboard_text`] and [Platform::set_clipboard_text`]
+/// The clip board, used in [`Platform::clipboard_text`] and [`Platform::set_clipboard_text`]
 #[non_exhaustive]
 #[derive(PartialEq, Clone, Default)]
 pub enum Clipboard {
@@ -150,6 +183,34 @@ pub(crate) fn event_loop_proxy() -> Option<&'static dyn EventLoopProxy> {
     EVENTLOOP_PROXY.get().map(core::ops::Deref::deref)
 }
 
+#[cfg(feature = "std")]
+static EVENT_LOOP_WAKER: std::sync::RwLock<Option<Box<dyn Fn() + Send + Sync>>> =
+    std::sync::RwLock::new(None);
+
+/// Sets a function that's called when Slint has work for the event loop: when a function was
+/// sent with [`slint::invoke_from_event_loop()`](crate::api::invoke_from_event_loop), when the
+/// event loop should quit, when a window needs to be redrawn, or when a timer was started.
+///
+/// The function can be called from any thread. It's meant to wake up another event loop that
+/// calls [`Platform::process_events()`], for example by writing to a pipe or an eventfd that this
+/// event loop watches, instead of polling. Note that the events of the windowing system aren't
+/// covered, and that the other event loop shouldn't wait longer than
+/// [`duration_until_next_timer_update()`].
+#[cfg(feature = "std")]
+pub fn set_event_loop_waker(waker: impl Fn() + Send + Sync + 'static) {
+    *EVENT_LOOP_WAKER.write().unwrap() = Some(Box::new(waker));
+}
+
+/// Calls the function set with [`set_event_loop_waker()`], if any.
+pub(crate) fn wake_event_loop() {
+    #[cfg(feature = "std")]
+    {
+        if let Some(waker) = EVENT_LOOP_WAKER.read().unwrap().as_ref() {
+            waker();
+        }
+    }
+}
+
 /// This enum describes the different error scenarios that may occur when [`set_platform`]
 /// fails.
 #[derive(Debug, Clone, PartialEq)]
@@ -177,13 +238,15 @@ pub fn set_platform(platform: Box<dyn Platform + 'static>) -> Result<(), SetPlat
 }
 
 /// Call this function to update and potentially activate any pending timers, as well
-/// as advance the state of any active animtaions.
+/// as advance the state of any active animtaions, and call the `changed` handlers of
+/// the properties whose value has changed.
 ///
 /// This function should be called before rendering or processing input event, at the
 /// beginning of each event loop iteration.
 pub fn update_timers_and_animations() {
     crate::animations::update_animations();
     crate::timers::TimerList::maybe_activate_timers(crate::animations::Instant::now());
+    crate::properties::ChangeTracker::run_change_handlers();
 }
 
 /// Returns the duration before the next timer is expected to be activated. This is the
@@ -269,6 +332,79 @@ pub enum WindowEvent {
         /// ```
         text: SharedString,
     },
+    /// The window was resized by the windowing system.
+    ///
+    /// This updates the size of the window's root element. The platform is still responsible for
+    /// resizing its rendering surface.
+    Resized {
+        /// The new size of the window, excluding the window frame.
+        size: LogicalSize,
+    },
+    /// The scale factor of the window has changed, for example because the window was moved
+    /// to a screen with a different pixel density.
+    ///
+    /// Dispatch this event before the [`WindowEvent::Resized`] event with the new size.
+    ScaleFactorChanged {
+        /// The new ratio between physical and logical pixels.
+        scale_factor: f32,
+    },
+    /// The window was minimized, maximized, or put in full screen mode, for example by the user
+    /// with the buttons of the window frame.
+    ///
+    /// This updates the `minimized`, `maximized`, and `full-screen` properties of the `Window` element.
+    WindowStateChanged {
+        /// Whether the window is minimized.
+        minimized: bool,
+        /// Whether the window is maximized.
+        maximized: bool,
+        /// Whether the window is in full screen mode.
+        full_screen: bool,
+    },
+    /// The window was activated or deactivated by the windowing system. An active window
+    /// receives the keyboard input.
+    WindowActiveChanged(bool),
+    /// The user requested to close the window, for example with the close button in the window frame.
+    ///
+    /// The callback set with [`Window::on_close_requested()`](`crate::api::Window::on_close_requested()`)
+    /// is invoked, and the window is hidden unless it returns [`CloseRequestResponse::KeepWindowShown`](`crate::api::CloseRequestResponse::KeepWindowShown`).
+    CloseRequested,
+    /// The input method updated the text it's composing, which isn't committed yet.
+    Preedit {
+        /// The text being composed. An empty text clears the composition.
+        text: SharedString,
+        /// The start of the selection within the pre-edit text, as a byte offset.
+        selection_start: usize,
+        /// The end of the selection within the pre-edit text, as a byte offset. It's the same as
+        /// `selection_start` when the input method only reports a cursor position.
+        selection_end: usize,
+    },
+    /// The input method committed the result of the composition. It replaces the pre-edit text.
+    Commit {
+        /// The text to insert.
+        text: SharedString,
+    },
+    /// Data dragged from outside of the application, such as files from a file manager, entered
+    /// the window or moved within it.
+    ///
+    /// Dispatch [`WindowEvent::Dropped`] or [`WindowEvent::DragExited`] to end the drag operation.
+    DragMoved {
+        position: LogicalPosition,
+        /// The type of the data, such as `text/plain`, or `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dragged data. For `text/uri-list`, one URI per line.
+        data: SharedString,
+    },
+    /// The data dragged from outside of the application left the window, or the drag operation
+    /// was cancelled.
+    DragExited,
+    /// Data dragged from outside of the application was dropped in the window.
+    Dropped {
+        position: LogicalPosition,
+        /// The type of the data, such as `text/plain`, or `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dropped data. For `text/uri-list`, one URI per line.
+        data: SharedString,
+    },
 }
 
 impl WindowEvent {
@@ -279,7 +415,164 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::DragMoved { position, .. } => Some(*position),
+            WindowEvent::Dropped { position, .. } => Some(*position),
             _ => None,
         }
     }
 }
+
+/// A platform for the unit tests that need an event loop.
+///
+/// The event loop proxy is global to the process, so the tests using this platform are serialized
+/// with the lock returned by [`init()`](test_platform::init), and only the first platform provides it.
+#[cfg(all(test, feature = "std"))]
+pub(crate) mod test_platform {
+    use super::*;
+    use crate::api::EventLoopError;
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Mutex, MutexGuard};
+
+    type Event = Box<dyn FnOnce() + Send>;
+    static EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+    static QUIT_REQUESTED: AtomicBool = AtomicBool::new(false);
+    static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    struct TestPlatform {
+        provides_proxy: bool,
+    }
+
+    impl Platform for TestPlatform {
+        fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+            Err(PlatformError::NoPlatform)
+        }
+
+        fn new_event_loop_proxy(&self) -> Option<Box<dyn EventLoopProxy>> {
+            self.provides_proxy.then(|| Box::new(TestProxy) as Box<dyn EventLoopProxy>)
+        }
+
+        fn process_events(
+            &self,
+            _timeout: core::time::Duration,
+        ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+            process_events();
+            Ok(if QUIT_REQUESTED.swap(false, Ordering::Relaxed) {
+                core::ops::ControlFlow::Break(())
+            } else {
+                core::ops::ControlFlow::Continue(())
+            })
+        }
+    }
+
+    struct TestProxy;
+
+    impl EventLoopProxy for TestProxy {
+        fn quit_event_loop(&self) -> Result<(), EventLoopError> {
+            QUIT_REQUESTED.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+
+        fn invoke_from_event_loop(&self, event: Event) -> Result<(), EventLoopError> {
+            EVENTS.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    /// Sets the test platform for the current thread, and returns the guard to keep until the end of the test.
+    pub fn init() -> MutexGuard<'static, ()> {
+        let guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        EVENTS.lock().unwrap().clear();
+        QUIT_REQUESTED.store(false, Ordering::Relaxed);
+        if PLATFORM_INSTANCE.with(|p| p.get().is_none()) {
+            static PROXY_SET: AtomicBool = AtomicBool::new(false);
+            let provides_proxy = !PROXY_SET.swap(true, Ordering::Relaxed);
+            set_platform(Box::new(TestPlatform { provides_proxy })).unwrap();
+        }
+        guard
+    }
+
+    /// Updates the timers, then runs the functions sent to the event loop.
+    pub fn process_events() {
+        update_timers_and_animations();
+        invoke_pending_events();
+    }
+
+    /// Returns the number of functions sent to the event loop that didn't run yet.
+    pub fn pending_event_count() -> usize {
+        EVENTS.lock().unwrap().len()
+    }
+
+    /// Runs the functions sent to the event loop, until there are none left.
+    pub fn invoke_pending_events() {
+        loop {
+            let events = core::mem::take(&mut *EVENTS.lock().unwrap());
+            if events.is_empty() {
+                break;
+            }
+            events.into_iter().for_each(|event| event());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+#[test]
+fn event_loop_waker() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let _guard = test_platform::init();
+    let wake_count = Arc::new(AtomicUsize::new(0));
+    let wake_count_clone = wake_count.clone();
+    set_event_loop_waker(move || {
+        wake_count_clone.fetch_add(1, Ordering::Relaxed);
+    });
+
+    // Sending a function from another thread wakes the event loop
+    let invoked = Arc::new(AtomicUsize::new(0));
+    let invoked_clone = invoked.clone();
+    std::thread::spawn(move || {
+        crate::api::invoke_from_event_loop(move || {
+            invoked_clone.fetch_add(1, Ordering::Relaxed);
+        })
+        .unwrap();
+    })
+    .join()
+    .unwrap();
+    assert_eq!(wake_count.load(Ordering::Relaxed), 1);
+    assert_eq!(invoked.load(Ordering::Relaxed), 0);
+    test_platform::invoke_pending_events();
+    assert_eq!(invoked.load(Ordering::Relaxed), 1);
+
+    // So does starting a timer
+    let timer = crate::timers::Timer::default();
+    timer.start(crate::timers::TimerMode::SingleShot, core::time::Duration::from_secs(60), || {});
+    assert_eq!(wake_count.load(Ordering::Relaxed), 2);
+    timer.stop();
+
+    // And quitting the event loop
+    crate::api::quit_event_loop().unwrap();
+    assert_eq!(wake_count.load(Ordering::Relaxed), 3);
+
+    set_event_loop_waker(|| {});
+}
+
+#[cfg(all(test, feature = "std"))]
+#[test]
+fn process_events_breaks_on_quit() {
+    use core::ops::ControlFlow;
+
+    let _guard = test_platform::init();
+    let process_events = || {
+        PLATFORM_INSTANCE
+            .with(|p| p.get().unwrap().process_events(core::time::Duration::ZERO))
+            .unwrap()
+    };
+    assert_eq!(process_events(), ControlFlow::Continue(()));
+
+    // Quitting from a function sent to the event loop breaks out of that iteration
+    crate::api::invoke_from_event_loop(|| crate::api::quit_event_loop().unwrap()).unwrap();
+    assert_eq!(process_events(), ControlFlow::Break(()));
+    // The quit request is consumed
+    assert_eq!(process_events(), ControlFlow::Continue(()));
+}
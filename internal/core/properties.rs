@@ -249,6 +249,8 @@ struct BindingVTable {
     intercept_set: unsafe fn(_self: *const BindingHolder, value: *const ()) -> bool,
     intercept_set_binding:
         unsafe fn(_self: *const BindingHolder, new_binding: *mut BindingHolder) -> bool,
+    animation_controller:
+        unsafe fn(_self: *const BindingHolder) -> Option<crate::animations::AnimationController>,
 }
 
 /// A binding trait object can be used to dynamically produces values for a property.
@@ -279,6 +281,11 @@ unsafe fn intercept_set_binding(self: Pin<&Self>, _new_binding: *mut BindingHold
         false
     }
 
+    /// Returns the controller of the animation if this binding animates the property.
+    fn animation_controller(self: Pin<&Self>) -> Option<crate::animations::AnimationController> {
+        None
+    }
+
     /// Set to true if and only if Self is a TwoWayBinding<T>
     const IS_TWO_WAY_BINDNG: bool = false;
 }
@@ -413,6 +420,13 @@ unsafe fn intercept_set_binding<B: BindingCallable>(
             .intercept_set_binding(new_binding)
     }
 
+    /// Safety: _self must be a pointer to a `BindingHolder<B>`
+    unsafe fn animation_controller<B: BindingCallable>(
+        _self: *const BindingHolder,
+    ) -> Option<crate::animations::AnimationController> {
+        Pin::new_unchecked(&((*(_self as *const BindingHolder<B>)).binding)).animation_controller()
+    }
+
     trait HasBindingVTable {
         const VT: &'static BindingVTable;
     }
@@ -423,6 +437,7 @@ impl<B: BindingCallable> HasBindingVTable for B {
             mark_dirty: mark_dirty::<B>,
             intercept_set: intercept_set::<B>,
             intercept_set_binding: intercept_set_binding::<B>,
+            animation_controller: animation_controller::<B>,
         };
     }
 
@@ -612,6 +627,13 @@ fn register_as_dependency_to_current_binding(
         }
     }
 
+    fn animation_controller(&self) -> Option<crate::animations::AnimationController> {
+        self.access(|binding| {
+            // Safety: the vtable matches the binding
+            binding.and_then(|b| unsafe { (b.vtable.animation_controller)(&*b as *const _) })
+        })
+    }
+
     fn mark_dirty(&self, #[cfg(slint_debug_property)] debug_name: &str) {
         #[cfg(not(slint_debug_property))]
         let debug_name = "";
@@ -898,6 +920,14 @@ pub fn is_dirty(&self) -> bool {
         self.handle.access(|binding| binding.map_or(false, |b| b.dirty.get()))
     }
 
+    /// Returns the controller of the animation of this property, if the property has an animated
+    /// binding, or is being animated after a call to `set_animated_value`.
+    ///
+    /// An animation started with `set_animated_value` no longer has a controller once it is finished.
+    pub fn animation_controller(&self) -> Option<crate::animations::AnimationController> {
+        self.handle.animation_controller()
+    }
+
     /// Internal function to mark the property as dirty and notify dependencies, regardless of
     /// whether the property value has actually changed or not.
     pub fn mark_dirty(&self) {
@@ -977,6 +1007,12 @@ unsafe fn intercept_set_binding(
                 true
             }
 
+            fn animation_controller(
+                self: Pin<&Self>,
+            ) -> Option<crate::animations::AnimationController> {
+                self.common_property.handle.animation_controller()
+            }
+
             const IS_TWO_WAY_BINDNG: bool = true;
         }
 
@@ -1141,6 +1177,9 @@ fn property_two_ways_recurse_from_binding() {
 mod properties_animations;
 pub use properties_animations::*;
 
+mod change_tracker;
+pub use change_tracker::ChangeTracker;
+
 /// Value of the state property
 ///
 /// A state is just the current state, but also has information about the previous state and the moment it changed
@@ -1223,6 +1262,7 @@ fn default() -> Self {
             mark_dirty: |_, _| (),
             intercept_set: |_, _| false,
             intercept_set_binding: |_, _| false,
+            animation_controller: |_| None,
         };
 
         let holder = BindingHolder {
@@ -1350,6 +1390,7 @@ impl<B: PropertyDirtyHandler> HasBindingVTable for B {
                 mark_dirty: mark_dirty::<B>,
                 intercept_set: |_, _| false,
                 intercept_set_binding: |_, _| false,
+                animation_controller: |_| None,
             };
         }
 
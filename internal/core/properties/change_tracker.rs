@@ -0,0 +1,283 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+use super::*;
+use alloc::rc::Weak;
+use alloc::vec::Vec;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+/// After that many rounds of change handlers changing properties that trigger other change handlers,
+/// the remaining handlers are postponed to the next call of [`ChangeTracker::run_change_handlers`]
+const MAX_CHANGE_HANDLER_ROUNDS: usize = 10;
+
+thread_local!(static DIRTY_CHANGE_TRACKERS : RefCell<Vec<Weak<dyn ChangeTrackerEntry>>> = RefCell::default());
+/// True when a call to [`ChangeTracker::run_change_handlers`] was requested from the event loop, or
+/// while the handlers are running
+thread_local!(static CHANGE_HANDLERS_SCHEDULED : Cell<bool> = Cell::new(false));
+
+/// Requests an iteration of the event loop that runs the change handlers, so that they run even
+/// if nothing else happens in the event loop.
+fn schedule_change_handlers() {
+    CHANGE_HANDLERS_SCHEDULED.with(|scheduled| {
+        if !scheduled.replace(true)
+            && crate::api::invoke_from_event_loop(ChangeTracker::run_change_handlers).is_err()
+        {
+            // No event loop: the handlers will run when the platform calls update_timers_and_animations
+            scheduled.set(false);
+        }
+    })
+}
+
+/// The value tracked by a ChangeTracker
+pub(super) trait TrackedValue {
+    /// Evaluate the value (while the dependencies are recorded) and return true if it has changed
+    fn evaluate(&self) -> bool;
+    /// Invoke the change handler
+    fn notify(&self);
+}
+
+trait ChangeTrackerEntry {
+    fn run(&self);
+}
+
+/// Queues the change tracker when one of its dependencies becomes dirty
+struct DirtyNotifier(Weak<dyn ChangeTrackerEntry>);
+
+impl PropertyDirtyHandler for DirtyNotifier {
+    fn notify(&self) {
+        // The property is still locked at this point, so the change tracker can't be evaluated yet.
+        // Scheduling doesn't re-enter: the handlers are only queued in the event loop, and at most
+        // once per turn of the event loop, however many trackers become dirty.
+        let was_empty = DIRTY_CHANGE_TRACKERS.with(|dirty| {
+            let mut dirty = dirty.borrow_mut();
+            dirty.push(self.0.clone());
+            dirty.len() == 1
+        });
+        if was_empty {
+            schedule_change_handlers();
+        }
+    }
+}
+
+struct ChangeTrackerInner<V> {
+    tracker: PropertyTracker<DirtyNotifier>,
+    value: V,
+}
+
+impl<V: TrackedValue> ChangeTrackerInner<V> {
+    fn evaluate(&self) -> bool {
+        // Safety: the tracker is within a Rc and is never moved
+        let tracker = unsafe { Pin::new_unchecked(&self.tracker) };
+        tracker.evaluate_as_dependency_root(|| self.value.evaluate())
+    }
+}
+
+impl<V: TrackedValue> ChangeTrackerEntry for ChangeTrackerInner<V> {
+    fn run(&self) {
+        if self.tracker.is_dirty() && self.evaluate() {
+            self.value.notify();
+        }
+    }
+}
+
+struct ValueWithHandler<Data, T, EF, NF> {
+    data: Data,
+    value: RefCell<T>,
+    eval_fn: EF,
+    notify_fn: NF,
+}
+
+impl<Data, T: PartialEq + Clone, EF: Fn(&Data) -> T, NF: Fn(&Data, &T)> TrackedValue
+    for ValueWithHandler<Data, T, EF, NF>
+{
+    fn evaluate(&self) -> bool {
+        let new_value = (self.eval_fn)(&self.data);
+        let mut value = self.value.borrow_mut();
+        if *value != new_value {
+            *value = new_value;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn notify(&self) {
+        // Clone the value so that the handler can cause the tracker to be evaluated again
+        let value = self.value.borrow().clone();
+        (self.notify_fn)(&self.data, &value)
+    }
+}
+
+/// A ChangeTracker calls a handler when the value returned by a function changes.
+///
+/// Contrary to a binding, the handler isn't called when the properties the function depends on
+/// are changed, but later, from [`ChangeTracker::run_change_handlers`], which is called by the
+/// event loop when the property changes have settled.
+#[derive(Default)]
+pub struct ChangeTracker {
+    inner: Cell<Option<Rc<dyn ChangeTrackerEntry>>>,
+}
+
+impl core::fmt::Debug for ChangeTracker {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChangeTracker").finish_non_exhaustive()
+    }
+}
+
+impl ChangeTracker {
+    /// Initialize the change tracker.
+    ///
+    /// `eval_fn` is called right away to compute the initial value, without calling `notify_fn`.
+    /// Then `notify_fn` is called with the new value each time the value returned by `eval_fn` changes.
+    /// Calling `init` again replaces the previous functions.
+    pub fn init<Data: 'static, T: Default + PartialEq + Clone + 'static>(
+        &self,
+        data: Data,
+        eval_fn: impl Fn(&Data) -> T + 'static,
+        notify_fn: impl Fn(&Data, &T) + 'static,
+    ) {
+        self.init_impl(ValueWithHandler {
+            data,
+            value: RefCell::new(T::default()),
+            eval_fn,
+            notify_fn,
+        })
+    }
+
+    pub(super) fn init_impl<V: TrackedValue + 'static>(&self, value: V) {
+        let inner = Rc::new_cyclic(|weak: &Weak<ChangeTrackerInner<V>>| ChangeTrackerInner {
+            tracker: PropertyTracker::new_with_dirty_handler(DirtyNotifier(weak.clone())),
+            value,
+        });
+        inner.evaluate();
+        self.inner.set(Some(inner));
+    }
+
+    /// Call the handlers of all the change trackers whose value has changed.
+    ///
+    /// If the handlers change properties that cause other trackers to change, these are called as well.
+    pub fn run_change_handlers() {
+        // The trackers that become dirty while the handlers run are handled by the loop below
+        CHANGE_HANDLERS_SCHEDULED.with(|scheduled| scheduled.set(true));
+        for _ in 0..MAX_CHANGE_HANDLER_ROUNDS {
+            let dirty =
+                DIRTY_CHANGE_TRACKERS.with(|dirty| core::mem::take(&mut *dirty.borrow_mut()));
+            if dirty.is_empty() {
+                break;
+            }
+            for entry in dirty {
+                if let Some(entry) = entry.upgrade() {
+                    entry.run();
+                }
+            }
+        }
+        CHANGE_HANDLERS_SCHEDULED.with(|scheduled| scheduled.set(false));
+        // Postpone the remaining handlers to the next iteration of the event loop
+        if DIRTY_CHANGE_TRACKERS.with(|dirty| !dirty.borrow().is_empty()) {
+            schedule_change_handlers();
+        }
+    }
+}
+
+#[test]
+fn test_change_tracker() {
+    let prop1 = Rc::pin(Property::new(1));
+    let prop2 = Rc::pin(Property::new(10));
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        (prop1.clone(), changes.clone()),
+        |(prop1, _)| prop1.as_ref().get() * 2,
+        |(_, changes), value| changes.borrow_mut().push(*value),
+    );
+    // The handler is not called for the initial value
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*changes.borrow(), Vec::<i32>::new());
+
+    prop1.as_ref().set(2);
+    // The handler is not called right away
+    assert_eq!(*changes.borrow(), Vec::<i32>::new());
+    prop1.as_ref().set(3);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*changes.borrow(), vec![6]);
+
+    // Not called when the value goes back to what it was
+    prop1.as_ref().set(4);
+    prop1.as_ref().set(3);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*changes.borrow(), vec![6]);
+
+    // A handler that changes a property tracked by another tracker
+    let tracker2 = ChangeTracker::default();
+    tracker2.init(
+        (prop2.clone(), prop1.clone()),
+        |(prop2, _)| prop2.as_ref().get(),
+        |(_, prop1), value| prop1.as_ref().set(*value),
+    );
+    prop2.as_ref().set(20);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(prop1.as_ref().get(), 20);
+    assert_eq!(*changes.borrow(), vec![6, 40]);
+
+    // Dropping the tracker stops the notifications
+    drop(tracker);
+    prop1.as_ref().set(5);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*changes.borrow(), vec![6, 40]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_change_handlers_from_event_loop() {
+    let _guard = crate::platform::test_platform::init();
+    let prop = Rc::pin(Property::new(0));
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        (prop.clone(), changes.clone()),
+        |(prop, _)| prop.as_ref().get(),
+        |(prop, changes), value| {
+            changes.borrow_mut().push(*value);
+            // Each change causes another one, for more rounds than MAX_CHANGE_HANDLER_ROUNDS
+            if *value < 15 {
+                prop.as_ref().set(*value + 1);
+            }
+        },
+    );
+
+    // Changing the property requests an iteration of the event loop to run the handlers,
+    // and the handlers that were postponed request another one
+    prop.as_ref().set(1);
+    assert_eq!(*changes.borrow(), Vec::<i32>::new());
+    crate::platform::test_platform::invoke_pending_events();
+    assert_eq!(*changes.borrow(), (1..=15).collect::<Vec<_>>());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_change_handlers_scheduled_once() {
+    let _guard = crate::platform::test_platform::init();
+    let prop = Rc::pin(Property::new(0));
+    let changes = Rc::new(Cell::new(0));
+    let trackers: Vec<ChangeTracker> = (0..100)
+        .map(|_| {
+            let tracker = ChangeTracker::default();
+            tracker.init(
+                (prop.clone(), changes.clone()),
+                |(prop, _)| prop.as_ref().get(),
+                |(_, changes), _| changes.set(changes.get() + 1),
+            );
+            tracker
+        })
+        .collect();
+
+    // All the trackers become dirty, and a single iteration of the event loop is requested
+    prop.as_ref().set(1);
+    prop.as_ref().set(2);
+    assert_eq!(crate::platform::test_platform::pending_event_count(), 1);
+    crate::platform::test_platform::invoke_pending_events();
+    assert_eq!(changes.get(), trackers.len());
+    assert_eq!(crate::platform::test_platform::pending_event_count(), 0);
+}
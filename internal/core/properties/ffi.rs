@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 use super::*;
+use crate::animations::{AnimationController, AnimationEvent};
 use crate::graphics::{Brush, Color};
 use crate::items::PropertyAnimation;
 use core::pin::Pin;
@@ -165,25 +166,16 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     to: T,
     animation_data: &PropertyAnimation,
 ) {
-    let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
-        from,
-        to,
-        animation_data.clone(),
-    ));
-    // Safety: The BindingCallable is for type T
-    unsafe {
-        handle.0.set_binding(move |val: *mut ()| {
-            let (value, finished) = d.borrow_mut().compute_interpolated_value();
-            *(val as *mut T) = value;
-            if finished {
-                BindingResult::RemoveBinding
-            } else {
-                crate::animations::CURRENT_ANIMATION_DRIVER
-                    .with(|driver| driver.set_has_active_animations());
-                BindingResult::KeepBinding
-            }
-        })
+    let binding = properties_animations::AnimatedValueBinding {
+        animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
+            from,
+            to,
+            animation_data.clone(),
+            handle.0.animation_controller().unwrap_or_default(),
+        )),
     };
+    // Safety: The BindingCallable is for type T
+    unsafe { handle.0.set_binding(binding) };
     handle.0.mark_dirty();
 }
 
@@ -250,6 +242,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         T::default(),
         T::default(),
         animation_data.cloned().unwrap_or_default(),
+        handle.0.animation_controller().unwrap_or_default(),
     ));
     if let Some(transition_data) = transition_data {
         handle.0.set_binding(properties_animations::AnimatedBindingCallable::<T, _> {
@@ -451,8 +444,200 @@ pub struct PropertyTrackerOpaque {
     core::ptr::drop_in_place(handle as *mut PropertyTracker);
 }
 
+/// Has the same layout as AnimationController
+#[repr(C)]
+pub struct AnimationControllerOpaque(*const c_void);
+
+static_assertions::assert_eq_align!(AnimationControllerOpaque, AnimationController);
+static_assertions::assert_eq_size!(AnimationControllerOpaque, AnimationController);
+
+/// Initialize `out` with the controller of the animation of the property, and return true.
+/// If the property is not animated, return false and leave `out` uninitialized.
+#[no_mangle]
+pub unsafe extern "C" fn slint_property_animation_controller(
+    handle: &PropertyHandleOpaque,
+    out: *mut AnimationControllerOpaque,
+) -> bool {
+    match handle.0.animation_controller() {
+        Some(controller) => {
+            core::ptr::write(out as *mut AnimationController, controller);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Initialize `out` with a copy of the controller
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_clone(
+    controller: &AnimationControllerOpaque,
+    out: *mut AnimationControllerOpaque,
+) {
+    let controller = &*(controller as *const _ as *const AnimationController);
+    core::ptr::write(out as *mut AnimationController, controller.clone());
+}
+
+/// Destroy the controller
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_drop(
+    controller: *mut AnimationControllerOpaque,
+) {
+    core::ptr::drop_in_place(controller as *mut AnimationController);
+}
+
+/// Query if the animation is running
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_is_running(
+    controller: &AnimationControllerOpaque,
+) -> bool {
+    (*(controller as *const _ as *const AnimationController)).is_running()
+}
+
+/// Query if the animation is paused
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_is_paused(
+    controller: &AnimationControllerOpaque,
+) -> bool {
+    (*(controller as *const _ as *const AnimationController)).is_paused()
+}
+
+/// Return the progress of the current iteration of the animation
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_progress(
+    controller: &AnimationControllerOpaque,
+) -> f32 {
+    (*(controller as *const _ as *const AnimationController)).progress()
+}
+
+/// Pause the animation
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_pause(controller: &AnimationControllerOpaque) {
+    (*(controller as *const _ as *const AnimationController)).pause()
+}
+
+/// Resume the animation
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_resume(controller: &AnimationControllerOpaque) {
+    (*(controller as *const _ as *const AnimationController)).resume()
+}
+
+/// Move the animation to the position, in milliseconds
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_seek(
+    controller: &AnimationControllerOpaque,
+    position_ms: u64,
+) {
+    (*(controller as *const _ as *const AnimationController))
+        .seek(core::time::Duration::from_millis(position_ms))
+}
+
+/// Cancel the animation
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_cancel(controller: &AnimationControllerOpaque) {
+    (*(controller as *const _ as *const AnimationController)).cancel()
+}
+
+/// Set the handler called when the animation starts or finishes
+#[no_mangle]
+pub unsafe extern "C" fn slint_animation_controller_set_event_handler(
+    controller: &AnimationControllerOpaque,
+    handler: extern "C" fn(user_data: *mut c_void, event: AnimationEvent),
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+) {
+    struct UserData {
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
+        handler: extern "C" fn(user_data: *mut c_void, event: AnimationEvent),
+    }
+
+    impl Drop for UserData {
+        fn drop(&mut self) {
+            if let Some(x) = self.drop_user_data {
+                x(self.user_data)
+            }
+        }
+    }
+
+    impl UserData {
+        fn call(&self, event: AnimationEvent) {
+            (self.handler)(self.user_data, event)
+        }
+    }
+
+    let ud = UserData { user_data, drop_user_data, handler };
+    (*(controller as *const _ as *const AnimationController))
+        .set_event_handler(move |event| ud.call(event));
+}
+
 /// return the current animation tick for the `animation-tick` function
 #[no_mangle]
 pub extern "C" fn slint_animation_tick() -> u64 {
     crate::animations::animation_tick()
 }
+
+struct FfiTrackedValue {
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    eval_fn: extern "C" fn(*mut c_void) -> bool,
+    notify_fn: extern "C" fn(*mut c_void),
+}
+
+impl Drop for FfiTrackedValue {
+    fn drop(&mut self) {
+        if let Some(x) = self.drop_user_data {
+            x(self.user_data)
+        }
+    }
+}
+
+impl change_tracker::TrackedValue for FfiTrackedValue {
+    fn evaluate(&self) -> bool {
+        (self.eval_fn)(self.user_data)
+    }
+    fn notify(&self) {
+        (self.notify_fn)(self.user_data)
+    }
+}
+
+/// Has the same layout as ChangeTracker
+#[repr(C)]
+pub struct ChangeTrackerOpaque([*const c_void; 2]);
+
+static_assertions::assert_eq_align!(ChangeTrackerOpaque, ChangeTracker);
+static_assertions::assert_eq_size!(ChangeTrackerOpaque, ChangeTracker);
+
+/// Initialize the change tracker.
+/// slint_change_tracker_drop needs to be called after that
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_construct(out: *mut ChangeTrackerOpaque) {
+    core::ptr::write(out as *mut ChangeTracker, ChangeTracker::default());
+}
+
+/// Destroy the change tracker
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_drop(ct: *mut ChangeTrackerOpaque) {
+    core::ptr::drop_in_place(ct as *mut ChangeTracker);
+}
+
+/// Set the functions of the change tracker.
+/// `eval_fn` must evaluate and store the value, and return true if it has changed.
+/// `notify_fn` is called when `eval_fn` returned true, except for the first evaluation
+/// done by this function.
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_init(
+    ct: &ChangeTrackerOpaque,
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+    notify_fn: extern "C" fn(user_data: *mut c_void),
+) {
+    let ct = &*(ct as *const ChangeTrackerOpaque as *const ChangeTracker);
+    ct.init_impl(FfiTrackedValue { user_data, drop_user_data, eval_fn, notify_fn });
+}
+
+/// Call the handlers of the change trackers whose value has changed
+#[no_mangle]
+pub extern "C" fn slint_change_tracker_run_change_handlers() {
+    ChangeTracker::run_change_handlers();
+}
@@ -2,7 +2,15 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 use super::*;
-use crate::{items::PropertyAnimation, lengths::LogicalLength};
+use crate::{
+    animations::{AnimationController, AnimationEvent, EasingCurve},
+    items::{AnimationDirection, PropertyAnimation},
+    lengths::LogicalLength,
+};
+
+/// A spring is considered at rest when both the distance to the target and the velocity
+/// are below this threshold (in progress, and in progress per second)
+const SPRING_REST_THRESHOLD: f32 = 0.001;
 
 enum AnimationState {
     Delaying,
@@ -16,29 +24,91 @@ pub(super) struct PropertyValueAnimationData<T> {
     details: PropertyAnimation,
     start_time: crate::animations::Instant,
     state: AnimationState,
+    /// The velocity of the spring when it was released, in progress per second
+    initial_velocity: f32,
+    /// The current velocity of the spring, in progress per second
+    velocity: f32,
+    controller: AnimationController,
+    /// The paused duration of the controller when this animation started
+    paused_baseline: core::time::Duration,
+    /// How far the animation was moved forward by seeking, in milliseconds
+    seek_offset: u64,
+    reported_start: bool,
+    reported_finish: bool,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
-    pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
+    pub fn new(
+        from_value: T,
+        to_value: T,
+        details: PropertyAnimation,
+        controller: AnimationController,
+    ) -> Self {
         let start_time = crate::animations::current_tick();
+        let paused_baseline = controller.paused_duration(start_time);
+
+        Self {
+            from_value,
+            to_value,
+            details,
+            start_time,
+            state: AnimationState::Delaying,
+            initial_velocity: 0.,
+            velocity: 0.,
+            controller,
+            paused_baseline,
+            seek_offset: 0,
+            reported_start: false,
+            reported_finish: false,
+        }
+    }
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+    /// The current tick, minus the time the animation spent paused, plus the seek offset
+    fn effective_tick(&self) -> crate::animations::Instant {
+        let tick = crate::animations::current_tick();
+        let paused = self.controller.paused_duration(tick).saturating_sub(self.paused_baseline);
+        crate::animations::Instant(
+            (tick.0 + self.seek_offset).saturating_sub(paused.as_millis() as u64),
+        )
+    }
+
+    /// Apply the requests made through the AnimationController since the last evaluation
+    fn apply_controller_requests(&mut self) {
+        self.controller.track();
+        if let Some(position) = self.controller.take_seek_request() {
+            self.start_time = self.effective_tick();
+            self.seek_offset += position.as_millis() as u64;
+            if matches!(self.state, AnimationState::Done) {
+                self.reported_start = false;
+                self.reported_finish = false;
+            }
+            self.state = AnimationState::Delaying;
+        }
+        if self.controller.take_cancel_request() {
+            self.state = AnimationState::Done;
+        }
     }
 
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
-        let new_tick = crate::animations::current_tick();
+        self.apply_controller_requests();
+        self.compute_interpolated_value_impl()
+    }
+
+    fn compute_interpolated_value_impl(&mut self) -> (T, bool) {
+        let new_tick = self.effective_tick();
         let mut time_progress = new_tick.duration_since(self.start_time).as_millis() as u64;
 
         match self.state {
             AnimationState::Delaying => {
                 if self.details.delay <= 0 {
                     self.state = AnimationState::Animating { current_iteration: 0 };
-                    return self.compute_interpolated_value();
+                    return self.compute_interpolated_value_impl();
                 }
 
                 let delay = self.details.delay as u64;
 
                 if time_progress < delay {
+                    self.controller.set_progress(0.);
                     (self.from_value.clone(), false)
                 } else {
                     self.start_time =
@@ -46,13 +116,36 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
 
                     // Decide on next state:
                     self.state = AnimationState::Animating { current_iteration: 0 };
-                    self.compute_interpolated_value()
+                    self.compute_interpolated_value_impl()
                 }
             }
             AnimationState::Animating { mut current_iteration } => {
+                if !self.reported_start {
+                    self.reported_start = true;
+                    self.controller.report(AnimationEvent::Started);
+                }
+
+                if let EasingCurve::Spring(parameters) = self.details.easing {
+                    // Springs ignore the duration and iteration count, they run until they settle
+                    let (progress, velocity) = crate::animations::spring_curve(
+                        parameters,
+                        self.initial_velocity,
+                        time_progress as f32 / 1000.,
+                    );
+                    if (1. - progress).abs() < SPRING_REST_THRESHOLD
+                        && velocity.abs() < SPRING_REST_THRESHOLD
+                    {
+                        self.state = AnimationState::Done;
+                        return self.compute_interpolated_value_impl();
+                    }
+                    self.velocity = velocity;
+                    self.controller.set_progress(progress.clamp(0., 1.));
+                    return (self.from_value.interpolate(&self.to_value, progress), false);
+                }
+
                 if self.details.duration <= 0 || self.details.iteration_count == 0. {
                     self.state = AnimationState::Done;
-                    return self.compute_interpolated_value();
+                    return self.compute_interpolated_value_impl();
                 }
 
                 let duration = self.details.duration as u64;
@@ -72,22 +165,72 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
 
                     let progress =
                         (time_progress as f32 / self.details.duration as f32).clamp(0., 1.);
+                    self.controller.set_progress(progress);
+                    let reversed = match self.details.direction {
+                        AnimationDirection::Normal => false,
+                        AnimationDirection::Reverse => true,
+                        AnimationDirection::Alternate => current_iteration % 2 == 1,
+                        AnimationDirection::AlternateReverse => current_iteration % 2 == 0,
+                    };
+                    let progress = if reversed { 1. - progress } else { progress };
                     let t = crate::animations::easing_curve(&self.details.easing, progress);
                     let val = self.from_value.interpolate(&self.to_value, t);
 
                     (val, false)
                 } else {
                     self.state = AnimationState::Done;
-                    self.compute_interpolated_value()
+                    self.compute_interpolated_value_impl()
+                }
+            }
+            AnimationState::Done => {
+                self.velocity = 0.;
+                if !self.reported_finish {
+                    self.reported_finish = true;
+                    self.controller.set_progress(1.);
+                    self.controller.report(AnimationEvent::Finished);
                 }
+                (self.to_value.clone(), true)
             }
-            AnimationState::Done => (self.to_value.clone(), true),
         }
     }
 
+    /// Returns true when the animation is paused and doesn't need to be updated on the next frame
+    fn is_paused(&self) -> bool {
+        self.controller.is_paused()
+    }
+
+    /// Returns true if the AnimationController asked to play a finished animation again
+    fn replay_requested(&self) -> bool {
+        self.controller.track();
+        self.controller.has_seek_request()
+    }
+
+    /// Returns the current velocity of a running spring animation, in units of the value per second.
+    fn value_velocity(&self) -> Option<f32> {
+        if self.velocity == 0. {
+            return None;
+        }
+        Some(self.velocity * self.from_value.difference(&self.to_value)?)
+    }
+
+    /// Start the spring with the given velocity, in units of the value per second.
+    fn set_value_velocity(&mut self, value_velocity: Option<f32>) {
+        self.initial_velocity = value_velocity
+            .and_then(|v| {
+                let distance = self.from_value.difference(&self.to_value)?;
+                (distance != 0.).then(|| v / distance)
+            })
+            .unwrap_or(0.);
+        self.velocity = self.initial_velocity;
+    }
+
     fn reset(&mut self) {
         self.state = AnimationState::Delaying;
         self.start_time = crate::animations::current_tick();
+        self.paused_baseline = self.controller.paused_duration(self.start_time);
+        self.seek_offset = 0;
+        self.reported_start = false;
+        self.reported_finish = false;
     }
 }
 
@@ -118,34 +261,52 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
         );
         match self.state.get() {
             AnimatedBindingState::Animating => {
-                let (val, finished) = self.animation_data.borrow_mut().compute_interpolated_value();
+                let mut animation_data = self.animation_data.borrow_mut();
+                let (val, finished) = animation_data.compute_interpolated_value();
                 *(value as *mut T) = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
-                } else {
+                } else if !animation_data.is_paused() {
                     crate::animations::CURRENT_ANIMATION_DRIVER
                         .with(|driver| driver.set_has_active_animations());
                 }
             }
             AnimatedBindingState::NotAnimating => {
-                self.original_binding.update(value);
+                let mut animation_data = self.animation_data.borrow_mut();
+                if animation_data.replay_requested() {
+                    // The finished animation was sought with the AnimationController
+                    self.state.set(AnimatedBindingState::Animating);
+                    let (val, finished) = animation_data.compute_interpolated_value();
+                    *(value as *mut T) = val;
+                    if finished {
+                        self.state.set(AnimatedBindingState::NotAnimating)
+                    } else if !animation_data.is_paused() {
+                        crate::animations::CURRENT_ANIMATION_DRIVER
+                            .with(|driver| driver.set_has_active_animations());
+                    }
+                } else {
+                    self.original_binding.update(value);
+                }
             }
             AnimatedBindingState::ShouldStart => {
                 let value = &mut *(value as *mut T);
                 self.state.set(AnimatedBindingState::Animating);
                 let mut animation_data = self.animation_data.borrow_mut();
                 // animation_data.details.iteration_count = 1.;
+                // Springs that are retargeted while moving keep their velocity
+                let value_velocity = animation_data.value_velocity();
                 animation_data.from_value = value.clone();
                 self.original_binding.update((&mut animation_data.to_value) as *mut T as *mut ());
                 if let Some((details, start_time)) = (self.compute_animation_details)() {
                     animation_data.start_time = start_time;
                     animation_data.details = details;
                 }
+                animation_data.set_value_velocity(value_velocity);
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
-                } else {
+                } else if !animation_data.is_paused() {
                     crate::animations::CURRENT_ANIMATION_DRIVER
                         .with(|driver| driver.set_has_active_animations());
                 }
@@ -163,6 +324,35 @@ fn mark_dirty(self: Pin<&Self>) {
             self.animation_data.borrow_mut().reset();
         }
     }
+    fn animation_controller(self: Pin<&Self>) -> Option<AnimationController> {
+        Some(self.animation_data.borrow().controller.clone())
+    }
+}
+
+/// The binding set by [`Property::set_animated_value`], which animates the property to
+/// the new value and is removed once the animation is finished.
+pub(super) struct AnimatedValueBinding<T> {
+    pub(super) animation_data: RefCell<PropertyValueAnimationData<T>>,
+}
+
+unsafe impl<T: InterpolatedPropertyValue + Clone> BindingCallable for AnimatedValueBinding<T> {
+    unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
+        let mut animation_data = self.animation_data.borrow_mut();
+        let (val, finished) = animation_data.compute_interpolated_value();
+        *(value as *mut T) = val;
+        if finished {
+            BindingResult::RemoveBinding
+        } else {
+            if !animation_data.is_paused() {
+                crate::animations::CURRENT_ANIMATION_DRIVER
+                    .with(|driver| driver.set_has_active_animations());
+            }
+            BindingResult::KeepBinding
+        }
+    }
+    fn animation_controller(self: Pin<&Self>) -> Option<AnimationController> {
+        Some(self.animation_data.borrow().controller.clone())
+    }
 }
 
 /// InterpolatedPropertyValue is a trait used to enable properties to be used with
@@ -174,36 +364,58 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Returns the signed distance from self to target_value, for types that can be
+    /// represented by a single number. This is used to preserve the velocity of spring
+    /// animations that are retargeted while running.
+    fn difference(&self, _target_value: &Self) -> Option<f32> {
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+    fn difference(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32) as i32
     }
+    fn difference(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32) as Self
     }
+    fn difference(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         ((*self as f32) + (t * ((*target_value as f32) - (*self as f32)))).min(255.).max(0.) as u8
     }
+    fn difference(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for LogicalLength {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         LogicalLength::new(self.get().interpolate(&target_value.get(), t))
     }
+    fn difference(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value.get() - self.get()) as f32)
+    }
 }
 
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
@@ -215,25 +427,19 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
-            self.get_internal(),
-            value,
-            animation_data,
-        ));
-        // Safety: the BindingCallable will cast its argument to T
+        let binding = properties_animations::AnimatedValueBinding {
+            animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
+                self.get_internal(),
+                value,
+                animation_data,
+                // Keep the controller of a running animation, so handles to it stay valid
+                self.animation_controller().unwrap_or_default(),
+            )),
+        };
+        // Safety: the AnimatedValueBinding's type match the property type
         unsafe {
             self.handle.set_binding(
-                move |val: *mut ()| {
-                    let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                    *(val as *mut T) = value;
-                    if finished {
-                        BindingResult::RemoveBinding
-                    } else {
-                        crate::animations::CURRENT_ANIMATION_DRIVER
-                            .with(|driver| driver.set_has_active_animations());
-                        BindingResult::KeepBinding
-                    }
-                },
+                binding,
                 #[cfg(slint_debug_property)]
                 self.debug_name.borrow().as_str(),
             );
@@ -267,6 +473,7 @@ pub fn set_animated_binding(
                 T::default(),
                 T::default(),
                 animation_data,
+                self.animation_controller().unwrap_or_default(),
             )),
             compute_animation_details: || -> properties_animations::AnimationDetail { None },
         };
@@ -309,6 +516,7 @@ pub fn set_animated_binding_for_transition(
                 T::default(),
                 T::default(),
                 PropertyAnimation::default(),
+                self.animation_controller().unwrap_or_default(),
             )),
             compute_animation_details: move || Some(compute_animation_details()),
         };
@@ -965,4 +1173,283 @@ fn test_loop_via_binding() {
 
         assert_eq!(get_prop_value(&compo.width), 300);
     }
+
+    #[test]
+    fn test_alternate_direction() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 2.,
+            direction: AnimationDirection::Alternate,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        // The second iteration goes backward
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // the binding should be removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn test_keyframes() {
+        use crate::animations::EasingKeyframe;
+        let compo = Component::new_test_component();
+
+        static KEYFRAMES: [EasingKeyframe; 2] = [
+            EasingKeyframe { position: 0.5, progress: 2. },
+            EasingKeyframe { position: 0.75, progress: 0. },
+        ];
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            easing: EasingCurve::Keyframes(crate::slice::Slice::from_slice(&KEYFRAMES)),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 300);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 7 / 8));
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
+    #[test]
+    fn test_spring() {
+        let compo = Component::new_test_component();
+
+        // Critically damped spring
+        let animation_details = PropertyAnimation {
+            easing: EasingCurve::Spring([100., 20., 1.]),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        // The duration is ignored
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(100))
+        });
+        let width = get_prop_value(&compo.width);
+        assert!(width > 120 && width < 130, "{width}");
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(5000))
+        });
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // the binding should be removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn test_spring_retarget_keeps_velocity() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            easing: EasingCurve::Spring([100., 20., 1.]),
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        let retarget_time = start_time + instant::Duration::from_millis(100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(retarget_time));
+        let width_at_retarget = get_prop_value(&compo.width);
+
+        compo.feed_property.set(300);
+        assert_eq!(get_prop_value(&compo.width), width_at_retarget);
+
+        // A spring starting at rest would only have moved by about 2 pixels after 16ms,
+        // but this one still moves at more than 300 pixels per second
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + instant::Duration::from_millis(16))
+        });
+        assert!(get_prop_value(&compo.width) > width_at_retarget + 4);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + instant::Duration::from_millis(5000))
+        });
+        assert_eq!(get_prop_value(&compo.width), 300);
+    }
+
+    #[test]
+    fn test_animation_controller() {
+        use crate::animations::AnimationEvent;
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        let controller = compo.width.animation_controller().unwrap();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        controller.set_event_handler({
+            let events = events.clone();
+            move |event| events.borrow_mut().push(event)
+        });
+        assert!(!controller.is_running());
+
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 100);
+        assert!(controller.is_running());
+        // The events are delivered on the next frame
+        assert!(events.borrow().is_empty());
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(*events.borrow(), [AnimationEvent::Started]);
+        assert_eq!(get_prop_value(&compo.width), 125);
+        assert_eq!(controller.progress(), 0.25);
+
+        controller.pause();
+        assert!(controller.is_paused());
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        controller.resume();
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 150);
+        assert_eq!(get_prop_value(&compo.width_times_two), 300);
+
+        controller.seek(DURATION * 9 / 10);
+        assert_eq!(get_prop_value(&compo.width), 190);
+
+        controller.cancel();
+        assert_eq!(get_prop_value(&compo.width), 200);
+        assert!(!controller.is_running());
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(*events.borrow(), [AnimationEvent::Started, AnimationEvent::Finished]);
+
+        // Seeking a finished animation plays it again
+        controller.seek(DURATION / 2);
+        assert_eq!(get_prop_value(&compo.width), 150);
+        assert!(controller.is_running());
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+        assert!(!controller.is_running());
+    }
+
+    #[test]
+    fn test_animation_controller_for_value() {
+        use crate::animations::AnimationEvent;
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+        assert!(compo.width.animation_controller().is_none());
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details.clone());
+        let controller = compo.width.animation_controller().unwrap();
+        let finished = Rc::new(Cell::new(false));
+        controller.set_event_handler({
+            let finished = finished.clone();
+            move |event| finished.set(event == AnimationEvent::Finished)
+        });
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        // Setting a new value while the animation runs keeps the controller
+        compo.width.set_animated_value(300, animation_details);
+        assert_eq!(compo.width.animation_controller(), Some(controller));
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2));
+        assert_eq!(get_prop_value(&compo.width), 300);
+        assert!(!finished.get());
+        // the binding, and its controller, should be removed
+        assert!(compo.width.animation_controller().is_none());
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3));
+        assert!(finished.get());
+    }
 }
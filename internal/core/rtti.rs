@@ -103,6 +103,12 @@ fn as_property_info(&'static self) -> &'static dyn PropertyInfo<Item, Value>
     /// the property2 must be a pinned pointer to a Property of the same type
     #[allow(unsafe_code)]
     unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ());
+
+    /// Returns the controller of the animation of the property, if the property is animated
+    fn animation_controller(
+        &self,
+        item: Pin<&Item>,
+    ) -> Option<crate::animations::AnimationController>;
 }
 
 impl<Item, T: PartialEq + Clone + 'static, Value: 'static> PropertyInfo<Item, Value>
@@ -153,6 +159,13 @@ unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ()) {
         let p2 = Pin::new_unchecked((property2 as *const crate::Property<T>).as_ref().unwrap());
         crate::Property::link_two_way(p1, p2);
     }
+
+    fn animation_controller(
+        &self,
+        item: Pin<&Item>,
+    ) -> Option<crate::animations::AnimationController> {
+        self.apply_pin(item).animation_controller()
+    }
 }
 
 /// Wrapper for a field offset that optionally implement PropertyInfo and uses
@@ -246,6 +259,13 @@ unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ()) {
         let p2 = Pin::new_unchecked((property2 as *const crate::Property<T>).as_ref().unwrap());
         crate::Property::link_two_way(p1, p2);
     }
+
+    fn animation_controller(
+        &self,
+        item: Pin<&Item>,
+    ) -> Option<crate::animations::AnimationController> {
+        self.apply_pin(item).animation_controller()
+    }
 }
 
 pub trait CallbackInfo<Item, Value> {
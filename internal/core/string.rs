@@ -244,6 +244,40 @@ pub fn format(args: core::fmt::Arguments<'_>) -> SharedString {
     output
 }
 
+/// Format `value` with `decimals` digits after the decimal point, rounding the last digit.
+/// If `grouping` is true, the digits of the integral part are grouped by three and separated with `,`.
+///
+/// This is the implementation of the `format-number()` function of the .slint language. The result
+/// doesn't depend on the locale: the decimal separator is always `.`.
+///
+/// ### Example
+/// ```rust
+/// # use i_slint_core::string::format_number;
+/// assert_eq!(format_number(1234567.891, 2, true), "1,234,567.89");
+/// assert_eq!(format_number(-1234.5, 0, false), "-1234");
+/// ```
+pub fn format_number(value: f64, decimals: i32, grouping: bool) -> SharedString {
+    let decimals = decimals.max(0) as usize;
+    if !grouping || !value.is_finite() {
+        return crate::format!("{:.*}", decimals, value);
+    }
+    let formatted = alloc::format!("{:.*}", decimals, value);
+    let (sign, digits) = match formatted.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", formatted.as_str()),
+    };
+    let (integral, fraction) = digits.split_at(digits.find('.').unwrap_or(digits.len()));
+    let mut output = SharedString::from(sign);
+    for (i, c) in integral.chars().enumerate() {
+        if i > 0 && (integral.len() - i) % 3 == 0 {
+            output.push_str(",");
+        }
+        output.write_char(c).unwrap();
+    }
+    output.push_str(fraction);
+    output
+}
+
 #[test]
 fn simple_test() {
     let x = SharedString::from("hello world!");
@@ -266,6 +300,19 @@ fn simple_test() {
     assert_eq!(SharedString::from('😎'), "😎");
 }
 
+#[test]
+fn test_format_number() {
+    assert_eq!(format_number(0., 0, true), "0");
+    assert_eq!(format_number(0.5, 2, true), "0.50");
+    assert_eq!(format_number(123., 1, true), "123.0");
+    assert_eq!(format_number(1234., 0, true), "1,234");
+    assert_eq!(format_number(1234., 0, false), "1234");
+    assert_eq!(format_number(-1234567.891, 2, true), "-1,234,567.89");
+    assert_eq!(format_number(999999.999, 2, true), "1,000,000.00");
+    assert_eq!(format_number(12.345, -1, true), "12");
+    assert_eq!(format_number(f64::INFINITY, 2, true), "inf");
+}
+
 #[test]
 fn threading() {
     let shared_cst = SharedString::from("Hello there!");
@@ -384,6 +431,41 @@ fn test_slint_shared_string_from_number() {
         let str = core::str::from_utf8(core::slice::from_raw_parts(bytes, len)).unwrap();
         self_.push_str(str);
     }
+    /// Set `out` to the upper case version of `ss`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_to_uppercase(out: &mut SharedString, ss: &SharedString) {
+        *out = ss.to_uppercase().into();
+    }
+
+    /// Set `out` to the lower case version of `ss`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_to_lowercase(out: &mut SharedString, ss: &SharedString) {
+        *out = ss.to_lowercase().into();
+    }
+
+    /// Set `out` to `ss` without the leading and trailing whitespace
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_trim(out: &mut SharedString, ss: &SharedString) {
+        *out = ss.trim().into();
+    }
+
+    /// Returns the number of unicode characters in the string
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_character_count(ss: &SharedString) -> usize {
+        ss.chars().count()
+    }
+
+    /// Set `out` to the result of `format_number(value, decimals, grouping)`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_format_number(
+        out: &mut SharedString,
+        value: f64,
+        decimals: i32,
+        grouping: bool,
+    ) {
+        *out = format_number(value, decimals, grouping);
+    }
+
     #[test]
     fn test_slint_shared_string_append() {
         let mut s = SharedString::default();
@@ -295,6 +295,7 @@ fn start_or_restart_timer(
             self.timers.insert(timer_data)
         };
         self.activate_timer(inactive_timer_id);
+        crate::platform::wake_event_loop();
         inactive_timer_id
     }
 
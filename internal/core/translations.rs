@@ -0,0 +1,207 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! Runtime support for the `@tr(...)` macro of the .slint language.
+//!
+//! The translated string is looked up with gettext when the `gettext-rs` feature is enabled,
+//! and the placeholders are then replaced by the formatting arguments.
+
+use crate::{Property, SharedString};
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt::Write;
+use core::pin::Pin;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+thread_local!(
+/// Property that all translated strings depend on, so that they get re-evaluated when
+/// the language changes.
+static TRANSLATIONS_DIRTY : Pin<Box<Property<()>>> = Box::pin(Property::new_named((), "translations_dirty"))
+);
+
+/// Mark all the translated strings as dirty, so that they are translated again the next time
+/// they are needed. Call this after changing the language.
+pub fn mark_all_translations_dirty() {
+    TRANSLATIONS_DIRTY.with(|dirty| dirty.as_ref().mark_dirty())
+}
+
+/// Implementation of the `@tr(...)` macro.
+///
+/// `original` is the message in the source language and `plural` its plural form, which is
+/// empty if the message has no plural form. `n` selects the form and is the value of `{n}`.
+pub fn translate(
+    original: &str,
+    context: &str,
+    domain: &str,
+    arguments: &[SharedString],
+    n: i32,
+    plural: &str,
+) -> SharedString {
+    TRANSLATIONS_DIRTY.with(|dirty| dirty.as_ref().get());
+    let translated = translate_message(original, context, domain, n, plural);
+    let mut output = SharedString::default();
+    write_formatted(&mut output, &translated, arguments, n).unwrap();
+    output
+}
+
+#[cfg(all(feature = "gettext-rs", target_family = "unix"))]
+fn translate_message(original: &str, context: &str, domain: &str, n: i32, plural: &str) -> String {
+    // gettext stores the context in the message id, separated by the EOT character
+    fn mangle_context(context: &str, s: &str) -> String {
+        alloc::format!("{}\u{4}{}", context, s)
+    }
+    fn demangle_context(r: String) -> String {
+        match r.split_once('\u{4}') {
+            Some((_, s)) => s.into(),
+            None => r,
+        }
+    }
+
+    if plural.is_empty() {
+        if context.is_empty() {
+            gettextrs::dgettext(domain, original)
+        } else {
+            demangle_context(gettextrs::dgettext(domain, mangle_context(context, original)))
+        }
+    } else if context.is_empty() {
+        gettextrs::dngettext(domain, original, plural, n as u32)
+    } else {
+        demangle_context(gettextrs::dngettext(
+            domain,
+            mangle_context(context, original),
+            mangle_context(context, plural),
+            n as u32,
+        ))
+    }
+}
+
+#[cfg(not(all(feature = "gettext-rs", target_family = "unix")))]
+fn translate_message(
+    original: &str,
+    _context: &str,
+    _domain: &str,
+    n: i32,
+    plural: &str,
+) -> String {
+    // Without translations, use the rule of the English language
+    if plural.is_empty() || n == 1 {
+        original.into()
+    } else {
+        plural.into()
+    }
+}
+
+/// Bind the gettext `domain` to the `dirname` directory which contains the translations
+/// in `<dirname>/<lang>/LC_MESSAGES/<domain>.mo`, and initialize the locale from the environment.
+#[cfg(feature = "gettext-rs")]
+pub fn gettext_bindtextdomain(_domain: &str, _dirname: std::path::PathBuf) -> std::io::Result<()> {
+    #[cfg(target_family = "unix")]
+    {
+        gettextrs::bindtextdomain(_domain, _dirname)?;
+        static START: std::sync::Once = std::sync::Once::new();
+        START.call_once(|| {
+            gettextrs::setlocale(gettextrs::LocaleCategory::LcAll, "");
+        });
+    }
+    Ok(())
+}
+
+/// Write `format` to `output`, replacing the placeholders:
+///  - `{}` is replaced by the next argument
+///  - `{0}`, `{1}`, ... are replaced by the argument at that position
+///  - `{n}` is replaced by `n`
+///  - `{{` and `}}` are replaced by `{` and `}`
+///
+/// Placeholders that don't correspond to an argument are left as is.
+fn write_formatted(
+    output: &mut impl Write,
+    format: &str,
+    arguments: &[SharedString],
+    n: i32,
+) -> core::fmt::Result {
+    let mut arg_idx = 0;
+    let mut pos = 0;
+    while let Some(p) = format[pos..].find(|c| c == '{' || c == '}') {
+        let p = p + pos;
+        let escaped = format[p + 1..].starts_with(&format[p..p + 1]);
+        if format[p..].starts_with('}') || escaped {
+            output.write_str(&format[pos..=p])?;
+            pos = if escaped { p + 2 } else { p + 1 };
+            continue;
+        }
+        let Some(end) = format[p..].find('}').map(|end| end + p) else { break };
+        let argument = &format[p + 1..end];
+        let value = if argument.is_empty() {
+            arg_idx += 1;
+            arguments.get(arg_idx - 1).map(|a| a.as_str())
+        } else if argument == "n" {
+            output.write_str(&format[pos..p])?;
+            write!(output, "{}", n)?;
+            pos = end + 1;
+            continue;
+        } else {
+            argument.parse::<usize>().ok().and_then(|i| arguments.get(i)).map(|a| a.as_str())
+        };
+        output.write_str(&format[pos..p])?;
+        output.write_str(value.unwrap_or(&format[p..=end]))?;
+        pos = end + 1;
+    }
+    output.write_str(&format[pos..])
+}
+
+#[test]
+fn test_format() {
+    let format = |format: &str, args: &[&str], n: i32| {
+        let args = args.iter().map(|a| SharedString::from(*a)).collect::<alloc::vec::Vec<_>>();
+        let mut output = String::new();
+        write_formatted(&mut output, format, &args, n).unwrap();
+        output
+    };
+    assert_eq!(format("Hello", &[], 0), "Hello");
+    assert_eq!(format("Hello {}!", &["world"], 0), "Hello world!");
+    assert_eq!(format("{}, {}", &["a", "b"], 0), "a, b");
+    assert_eq!(format("{1} {0}{1}", &["a", "b"], 0), "b ab");
+    assert_eq!(format("{n} files", &[], 42), "42 files");
+    assert_eq!(format("{{}} {{{}}}", &["a"], 0), "{} {a}");
+    assert_eq!(format("}} {", &[], 0), "} {");
+    // Placeholders without argument are kept
+    assert_eq!(format("{} {} {3} {x}", &["a"], 0), "a {} {3} {x}");
+}
+
+#[test]
+fn test_translate_fallback() {
+    let args = [SharedString::from("Olivier")];
+    assert_eq!(translate("Hello {}", "", "", &args, 1, ""), "Hello Olivier");
+    assert_eq!(translate("one file", "", "", &[], 1, "{n} files"), "one file");
+    assert_eq!(translate("one file", "", "", &[], 3, "{n} files"), "3 files");
+}
+
+#[cfg(feature = "ffi")]
+pub(crate) mod ffi {
+    #![allow(unsafe_code)]
+    use super::*;
+    use crate::slice::Slice;
+
+    /// Translate the string, with the semantics of `@tr(...)`. `to_translate` contains the original
+    /// string and is replaced by the result.
+    #[no_mangle]
+    pub extern "C" fn slint_translate(
+        to_translate: &mut SharedString,
+        context: &SharedString,
+        domain: &SharedString,
+        arguments: Slice<SharedString>,
+        n: i32,
+        plural: &SharedString,
+    ) {
+        *to_translate =
+            translate(to_translate.as_str(), context, domain, arguments.as_slice(), n, plural)
+    }
+
+    /// Mark all translated strings as dirty so that they are re-evaluated
+    #[no_mangle]
+    pub extern "C" fn slint_translations_mark_dirty() {
+        mark_all_translations_dirty();
+    }
+}
@@ -16,19 +16,30 @@
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, KeyInputEvent,
     KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker,
 };
-use crate::item_tree::ItemRc;
-use crate::items::{ItemRef, MouseCursor};
+use crate::item_tree::{ItemRc, ItemWeak};
+use crate::items::{DropEvent, ItemRef, MouseCursor, PopupClosePolicy};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
 use crate::{Callback, Coord};
 use alloc::boxed::Box;
 use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use euclid::num::Zero;
 use vtable::VRcMapped;
 
+/// Returns the component at the root of the item tree containing the item. For an item within a
+/// popup, this is the component of that popup.
+fn root_component(item: &ItemRc) -> ComponentRc {
+    let mut root_item = item.clone();
+    while let Some(parent) = root_item.parent_item() {
+        root_item = parent;
+    }
+    root_item.component()
+}
+
 fn next_focus_item(item: ItemRc) -> ItemRc {
     item.next_focus_item()
 }
@@ -179,10 +190,9 @@ fn set_position(&self, _position: WindowPosition) {}
     ///
     /// Called from [`Window::set_size`], but contrary to it, does not change report the size to the Slint's Window element
     ///
-    /// When you recieve a resize event from the windowing system, the platform implementation should call
-    /// [`Window::set_size`] to communicate the new window size to Slint.
-    /// [`Window::set_size`] will call this function again, so you must be prepared to get recursions.
-    // FIXME: before making that public, we need to add a WindowEvent::Resized to avoid the above recursion
+    /// When you receive a resize event from the windowing system, the platform implementation should
+    /// dispatch a [`WindowEvent::Resized`](crate::platform::WindowEvent::Resized) event to communicate
+    /// the new window size to Slint. Contrary to [`Window::set_size`], this doesn't call this function again.
     fn set_size(&self, _size: WindowSize) {}
 
     /// returns wether a dark theme is used
@@ -221,6 +231,7 @@ impl crate::properties::PropertyDirtyHandler for WindowRedrawTracker {
     fn notify(&self) {
         if let Some(window_adapter) = self.window_adapter_weak.upgrade() {
             window_adapter.request_redraw();
+            crate::platform::wake_event_loop();
         };
     }
 }
@@ -233,6 +244,9 @@ enum PopupWindowLocation {
     ChildWindow(LogicalPoint),
 }
 
+/// A popup rendered within its parent window: its component, position and close policy.
+type EmbeddedPopup = (ComponentRc, LogicalPoint, PopupClosePolicy);
+
 /// This structure defines a graphical element that is designed to pop up from the surrounding
 /// UI content, for example to show a context menu.
 struct PopupWindow {
@@ -240,6 +254,22 @@ struct PopupWindow {
     location: PopupWindowLocation,
     /// The component that is responsible for providing the popup content.
     component: ComponentRc,
+    /// Defines when the popup closes automatically.
+    close_policy: PopupClosePolicy,
+    /// The item in which the `PopupWindow` element was declared.
+    parent_item: ItemWeak,
+}
+
+/// A window declared with a `ChildWindow` element. It's shown in its own window adapter, created
+/// by the platform, and its component can access the properties of the component it's declared in.
+struct ChildWindow {
+    /// The item in which the `ChildWindow` element was declared.
+    parent_item: ItemWeak,
+    /// The index of the `ChildWindow` among the ones declared in the component of the parent item.
+    index: usize,
+    window_adapter: Rc<dyn WindowAdapter>,
+    /// The component that provides the content of the window. It's kept when the window is hidden.
+    component: ComponentRc,
 }
 
 #[pin_project::pin_project]
@@ -269,9 +299,17 @@ pub struct WindowInner {
     cursor_blinker: RefCell<pin_weak::rc::PinWeak<crate::input::TextCursorBlinker>>,
 
     pinned_fields: Pin<Box<WindowPinnedFields>>,
-    active_popup: RefCell<Option<PopupWindow>>,
+    /// The stack of the popups currently shown. The last one is the topmost.
+    active_popups: RefCell<Vec<PopupWindow>>,
+    /// The child windows that were shown at least once.
+    child_windows: RefCell<Vec<ChildWindow>>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
+    /// The data of the drag and drop operation in progress, if any. Its position isn't used.
+    drag_data: RefCell<Option<DropEvent>>,
+    /// The number of frames rendered, and the tasks waiting for the next one.
+    frame_count: Cell<u64>,
+    frame_wakers: RefCell<Vec<core::task::Waker>>,
     /// This is a cache of the size set by the set_inner_size setter.
     /// It should be mapping with the WindowItem::width and height (only in physical)
     pub(crate) inner_size: Cell<PhysicalSize>,
@@ -282,6 +320,8 @@ fn drop(&mut self) {
         if let Some(existing_blinker) = self.cursor_blinker.borrow().upgrade() {
             existing_blinker.stop();
         }
+        // The tasks awaiting the next frame complete, as there won't be any
+        core::mem::take(self.frame_wakers.get_mut()).into_iter().for_each(core::task::Waker::wake);
     }
 }
 
@@ -323,17 +363,21 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             }),
             focus_item: Default::default(),
             cursor_blinker: Default::default(),
-            active_popup: Default::default(),
+            active_popups: Default::default(),
+            child_windows: Default::default(),
+            frame_count: Default::default(),
+            frame_wakers: Default::default(),
             close_requested: Default::default(),
             inner_size: Default::default(),
             click_state: ClickState::default(),
+            drag_data: Default::default(),
         }
     }
 
     /// Associates this window with the specified component. Further event handling and rendering, etc. will be
     /// done with that component.
     pub fn set_component(&self, component: &ComponentRc) {
-        self.close_popup();
+        self.close_all_popups();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
         self.modifiers.replace(Default::default());
@@ -379,39 +423,67 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         // handle multiple press release
         event = self.click_state.check_repeat(event);
 
-        let embedded_popup_component =
-            self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
-                PopupWindowLocation::TopLevel(_) => None,
-                PopupWindowLocation::ChildWindow(coordinates) => {
-                    Some((popup.component.clone(), coordinates))
-                }
-            });
+        // While dragging, the pointer movements are delivered to the drop areas
+        let dragging = self.drag_data.borrow().is_some();
+        if dragging {
+            event = match event {
+                MouseEvent::Moved { position } => MouseEvent::DragMove { position },
+                MouseEvent::Released { position, .. } => MouseEvent::Drop { position },
+                event => event,
+            };
+            if matches!(event, MouseEvent::DragMove { .. }) {
+                // A DropArea accepting the data changes the cursor again
+                self.window_adapter().set_mouse_cursor(MouseCursor::NoDrop);
+            }
+        }
 
-        let component = embedded_popup_component
-            .as_ref()
-            .and_then(|(popup_component, coordinates)| {
-                event.translate(-coordinates.to_vector());
+        let popups = self.embedded_popups();
+        let popup_contains = |(popup_component, coordinates, _): &&EmbeddedPopup| {
+            event.position().map_or(false, |position| {
+                ComponentRc::borrow_pin(popup_component)
+                    .as_ref()
+                    .get_item_ref(0)
+                    .as_ref()
+                    .geometry()
+                    .contains(position - coordinates.to_vector())
+            })
+        };
 
-                if let MouseEvent::Pressed { position, .. } = &event {
-                    // close the popup if one press outside the popup
-                    let geom = ComponentRc::borrow_pin(popup_component)
-                        .as_ref()
-                        .get_item_ref(0)
-                        .as_ref()
-                        .geometry();
-                    if !geom.contains(*position) {
-                        self.close_popup();
-                        return None;
-                    }
+        let target = if matches!(event, MouseEvent::Pressed { .. }) {
+            // Starting from the topmost popup, close the popups that are pressed outside of,
+            // until reaching the one that contains the mouse.
+            let mut closed_popup = false;
+            let mut target = None;
+            for popup in popups.iter().rev() {
+                if popup_contains(&popup) {
+                    target = Some(popup);
+                    break;
+                }
+                if popup.2 != PopupClosePolicy::NoAutoClose {
+                    self.close_popups_from(&popup.0);
+                    closed_popup = true;
                 }
-                Some(popup_component.clone())
+            }
+            if target.is_none() && closed_popup {
+                // The press that closed the popup is not delivered to the content below it
+                return;
+            }
+            target
+        } else {
+            popups.iter().rev().find(popup_contains).or_else(|| {
+                popups.iter().rev().find(|popup| popup.2 != PopupClosePolicy::NoAutoClose)
             })
-            .or_else(|| self.component.borrow().upgrade());
+        };
 
-        let component = if let Some(component) = component {
-            component
-        } else {
-            return;
+        let component = match target {
+            Some((popup_component, coordinates, _)) => {
+                event.translate(-coordinates.to_vector());
+                popup_component.clone()
+            }
+            None => match self.component.borrow().upgrade() {
+                Some(component) => component,
+                None => return,
+            },
         };
 
         self.mouse_input_state.set(crate::input::process_mouse_input(
@@ -421,13 +493,36 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
             self.mouse_input_state.take(),
         ));
 
-        if embedded_popup_component.is_some() {
-            //FIXME: currently the ComboBox is the only thing that uses the popup, and it should close automatically
-            // on release.  But ideally, there would be API to close the popup rather than always closing it on release
+        if let Some((popup_component, ..)) = target {
             if matches!(event, MouseEvent::Released { .. }) {
-                self.close_popup();
+                self.close_popup_on_click(|popup| {
+                    vtable::VRc::ptr_eq(&popup.component, popup_component)
+                });
             }
         }
+
+        if dragging && matches!(event, MouseEvent::Drop { .. } | MouseEvent::Exit) {
+            self.drag_data.take();
+            self.window_adapter().set_mouse_cursor(MouseCursor::Default);
+        }
+    }
+
+    /// Starts a drag and drop operation with the given data. The following pointer movements
+    /// are delivered to the `DropArea`s, until the pointer is released or exits the window.
+    pub fn start_drag(&self, data: DropEvent) {
+        *self.drag_data.borrow_mut() = Some(data);
+    }
+
+    /// Returns the data of the drag and drop operation in progress, if any.
+    pub fn drag_data(&self) -> Option<DropEvent> {
+        self.drag_data.borrow().clone()
+    }
+
+    /// Ends the drag and drop operation in progress without dropping the data.
+    pub fn cancel_drag(&self) {
+        if self.drag_data.borrow().is_some() {
+            self.process_mouse_input(MouseEvent::Exit);
+        }
     }
 
     /// Called by the input code's internal timer to send an event that was delayed
@@ -468,12 +563,46 @@ pub fn process_key_input(&self, event: KeyInputEvent) {
                     &focus_item,
                 ) == crate::input::KeyEventResult::EventAccepted
                 {
+                    // Accepting the return key within a popup activates something, like a click
+                    if event.text.starts_with(key_codes::Return)
+                        && event.event_type == KeyEventType::KeyPressed
+                    {
+                        let component = root_component(&focus_item);
+                        self.close_popup_on_click(|popup| {
+                            vtable::VRc::ptr_eq(&popup.component, &component)
+                        });
+                    }
                     return;
                 }
             }
             item = focus_item.parent_item();
         }
 
+        // Up and down arrows move the focus between the items of the topmost popup
+        if (event.text.starts_with(key_codes::UpArrow)
+            || event.text.starts_with(key_codes::DownArrow))
+            && event.event_type == KeyEventType::KeyPressed
+        {
+            let top_popup = self.active_popups.borrow().last().map(|popup| popup.component.clone());
+            if let Some(top_popup) = top_popup {
+                self.move_focus_in_popup(top_popup, event.text.starts_with(key_codes::DownArrow));
+                return;
+            }
+        }
+
+        // Escape closes the topmost popup, unless it is meant to stay open
+        if event.text.starts_with(key_codes::Escape)
+            && event.event_type == KeyEventType::KeyPressed
+            && self
+                .active_popups
+                .borrow()
+                .last()
+                .map_or(false, |popup| popup.close_policy != PopupClosePolicy::NoAutoClose)
+        {
+            self.close_popup();
+            return;
+        }
+
         // Make Tab/Backtab handle keyboard focus
         if event.text.starts_with(key_codes::Tab)
             && !event.modifiers.shift
@@ -588,6 +717,37 @@ fn move_focus(&self, start_item: ItemRc, forward: impl Fn(ItemRc) -> ItemRc) ->
         }
     }
 
+    /// Moves the keyboard focus to the next or previous item of the popup. If the focus isn't
+    /// within the popup yet, it moves to the first or last item of the popup that accepts it.
+    fn move_focus_in_popup(&self, popup_component: ComponentRc, forward: bool) {
+        let focus_in_popup =
+            self.focus_item.borrow().upgrade().map_or(false, |item| {
+                vtable::VRc::ptr_eq(&root_component(&item), &popup_component)
+            });
+        if focus_in_popup {
+            if forward {
+                self.focus_next_item()
+            } else {
+                self.focus_previous_item()
+            }
+            return;
+        }
+
+        let old = self.take_focus_item();
+        let root = ItemRc::new(popup_component, 0);
+        let new = if forward {
+            self.move_focus(root, next_focus_item)
+        } else {
+            self.move_focus(previous_focus_item(root), previous_focus_item)
+        };
+        if new.is_none() {
+            // Nothing in the popup accepts the focus, keep it where it was
+            self.publish_focus_item(&old);
+            return;
+        }
+        self.window_adapter().handle_focus_change(old, new);
+    }
+
     /// Move keyboard focus to the next item
     pub fn focus_next_item(&self) {
         let component = self.component();
@@ -650,32 +810,42 @@ pub fn draw_contents<T>(
         &self,
         render_components: impl FnOnce(&[(&ComponentRc, LogicalPoint)]) -> T,
     ) -> T {
+        // Let the change handlers update the properties before they are rendered
+        crate::properties::ChangeTracker::run_change_handlers();
         let draw_fn = || {
             let component_rc = self.component();
+            let popups = self.embedded_popups();
 
-            let popup_component =
-                self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
-                    PopupWindowLocation::TopLevel(_) => None,
-                    PopupWindowLocation::ChildWindow(coordinates) => {
-                        Some((popup.component.clone(), coordinates))
-                    }
-                });
-
-            if let Some((popup_component, popup_coordinates)) = popup_component {
-                render_components(&[
-                    (&component_rc, LogicalPoint::default()),
-                    (&popup_component, popup_coordinates),
-                ])
-            } else {
-                render_components(&[(&component_rc, LogicalPoint::default())])
-            }
+            let components = core::iter::once((&component_rc, LogicalPoint::default()))
+                .chain(popups.iter().map(|(component, coordinates, _)| (component, *coordinates)))
+                .collect::<Vec<_>>();
+            render_components(&components)
         };
 
-        self.pinned_fields
+        let result = self
+            .pinned_fields
             .as_ref()
             .project_ref()
             .redraw_tracker
-            .evaluate_as_dependency_root(draw_fn)
+            .evaluate_as_dependency_root(draw_fn);
+
+        self.frame_count.set(self.frame_count.get().wrapping_add(1));
+        let frame_wakers = core::mem::take(&mut *self.frame_wakers.borrow_mut());
+        frame_wakers.into_iter().for_each(core::task::Waker::wake);
+        result
+    }
+
+    /// Returns the number of frames rendered so far.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count.get()
+    }
+
+    /// Wakes the waker after the next call to [`Self::draw_contents()`].
+    pub fn wake_after_next_frame(&self, waker: core::task::Waker) {
+        let mut frame_wakers = self.frame_wakers.borrow_mut();
+        if !frame_wakers.iter().any(|w| w.will_wake(&waker)) {
+            frame_wakers.push(waker);
+        }
     }
 
     /// Registers the window with the windowing system, in order to render the component's items and react
@@ -691,16 +861,43 @@ pub fn hide(&self) -> Result<(), PlatformError> {
         self.window_adapter().hide()
     }
 
-    /// Show a popup at the given position relative to the item
+    /// Show a popup at the given position relative to the item. The popup is stacked on top of
+    /// any popup that is already shown, unless it's the same popup, which is replaced.
     pub fn show_popup(
         &self,
         popup_componentrc: &ComponentRc,
         position: Point,
+        close_policy: PopupClosePolicy,
         parent_item: &ItemRc,
     ) {
+        // Showing a popup that is already open replaces it, along with the popups opened from it.
+        // A new component is created each time, so the popups are told apart by their parent item.
+        let parent_item_weak = parent_item.downgrade();
+        let existing_index = self
+            .active_popups
+            .borrow()
+            .iter()
+            .position(|popup| popup.parent_item == parent_item_weak);
+        if let Some(index) = existing_index {
+            self.truncate_popups(index);
+        }
+
         let position = parent_item.map_to_window(
             parent_item.geometry().origin + LogicalPoint::from_untyped(position).to_vector(),
         );
+        // When the parent item is within a popup rendered in this window, the position is relative
+        // to that popup.
+        let parent_component = root_component(parent_item);
+        let parent_popup_offset =
+            self.active_popups.borrow().iter().find_map(|popup| match popup.location {
+                PopupWindowLocation::ChildWindow(coordinates)
+                    if vtable::VRc::ptr_eq(&popup.component, &parent_component) =>
+                {
+                    Some(coordinates.to_vector())
+                }
+                _ => None,
+            });
+        let position = parent_popup_offset.map_or(position, |offset| position + offset);
         let popup_component = ComponentRc::borrow_pin(popup_componentrc);
         let popup_root = popup_component.as_ref().get_item_ref(0);
 
@@ -749,17 +946,167 @@ pub fn show_popup(
             }
         };
 
-        self.active_popup
-            .replace(Some(PopupWindow { location, component: popup_componentrc.clone() }));
+        self.active_popups.borrow_mut().push(PopupWindow {
+            location,
+            component: popup_componentrc.clone(),
+            close_policy,
+            parent_item: parent_item_weak,
+        });
     }
 
-    /// Removes any active popup.
+    /// Removes the topmost popup.
     pub fn close_popup(&self) {
-        if let Some(current_popup) = self.active_popup.replace(None) {
-            if let PopupWindowLocation::ChildWindow(offset) = current_popup.location {
+        let len = self.active_popups.borrow().len();
+        self.truncate_popups(len.saturating_sub(1));
+    }
+
+    /// Removes all the popups.
+    pub fn close_all_popups(&self) {
+        self.truncate_popups(0);
+    }
+
+    /// Returns true if the child window at the given index, declared in the component of `parent_item`,
+    /// was already created by [`Self::show_child_window()`].
+    pub fn has_child_window(&self, index: usize, parent_item: &ItemRc) -> bool {
+        self.child_window_adapter(index, parent_item).is_some()
+    }
+
+    /// Shows the child window at the given index, declared in the component of `parent_item`.
+    ///
+    /// The first time, `create` is called to create the window adapter of the child window, and its
+    /// component, which must use that window adapter.
+    pub fn show_child_window(
+        &self,
+        index: usize,
+        parent_item: &ItemRc,
+        create: impl FnOnce() -> Result<(Rc<dyn WindowAdapter>, ComponentRc), PlatformError>,
+    ) {
+        let window_adapter = match self.child_window_adapter(index, parent_item) {
+            Some(window_adapter) => window_adapter,
+            None => {
+                let (window_adapter, component) = match create() {
+                    Ok(created) => created,
+                    Err(err) => {
+                        crate::debug_log!("Error creating the window of a ChildWindow: {}", err);
+                        return;
+                    }
+                };
+                WindowInner::from_pub(window_adapter.window()).set_component(&component);
+                self.child_windows.borrow_mut().push(ChildWindow {
+                    parent_item: parent_item.downgrade(),
+                    index,
+                    window_adapter: window_adapter.clone(),
+                    component,
+                });
+                window_adapter
+            }
+        };
+        if let Err(err) = window_adapter.window().show() {
+            crate::debug_log!("Error showing a ChildWindow: {}", err);
+        }
+    }
+
+    /// Hides the child window at the given index, declared in the component of `parent_item`, if it was shown.
+    pub fn hide_child_window(&self, index: usize, parent_item: &ItemRc) {
+        if let Some(window_adapter) = self.child_window_adapter(index, parent_item) {
+            if let Err(err) = window_adapter.window().hide() {
+                crate::debug_log!("Error hiding a ChildWindow: {}", err);
+            }
+        }
+    }
+
+    /// Hides and drops the child windows whose parent item was destroyed, for example by a
+    /// repeater. Called when a component shown in this window is destroyed.
+    pub(crate) fn drop_orphan_child_windows(&self) {
+        // They are dropped after releasing the borrow, in case their destruction accesses this window.
+        let destroyed = {
+            let mut child_windows = self.child_windows.borrow_mut();
+            let (alive, destroyed): (Vec<_>, Vec<_>) = core::mem::take(&mut *child_windows)
+                .into_iter()
+                .partition(|child_window| child_window.parent_item.upgrade().is_some());
+            *child_windows = alive;
+            destroyed
+        };
+        for child_window in destroyed {
+            child_window.window_adapter.window().hide().ok();
+        }
+    }
+
+    fn child_window_adapter(
+        &self,
+        index: usize,
+        parent_item: &ItemRc,
+    ) -> Option<Rc<dyn WindowAdapter>> {
+        let parent_item = parent_item.downgrade();
+        self.child_windows
+            .borrow()
+            .iter()
+            .find(|child_window| {
+                child_window.index == index && child_window.parent_item == parent_item
+            })
+            .map(|child_window| child_window.window_adapter.clone())
+    }
+
+    /// Called when the mouse is released within the popup rendered in the given top-level window.
+    /// This is used by back-ends that render popups in their own top-level window, which then
+    /// receive the mouse events themselves.
+    pub fn close_popup_on_release(&self, popup_window: &Window) {
+        self.close_popup_on_click(|popup| {
+            matches!(&popup.location, PopupWindowLocation::TopLevel(window_adapter)
+                if core::ptr::eq(window_adapter.window(), popup_window))
+        });
+    }
+
+    /// Called when something was clicked in a popup. If that popup closes on click and is still
+    /// the topmost one (the click didn't open another popup), it is removed along with the popups
+    /// closing on click it was opened from, so that activating an entry of a sub-menu closes the
+    /// whole menu.
+    fn close_popup_on_click(&self, is_clicked_popup: impl Fn(&PopupWindow) -> bool) {
+        let len = {
+            let popups = self.active_popups.borrow();
+            match popups.last() {
+                Some(popup)
+                    if popup.close_policy == PopupClosePolicy::CloseOnClick
+                        && is_clicked_popup(popup) =>
+                {
+                    popups
+                        .iter()
+                        .rposition(|popup| popup.close_policy != PopupClosePolicy::CloseOnClick)
+                        .map_or(0, |index| index + 1)
+                }
+                _ => return,
+            }
+        };
+        self.truncate_popups(len);
+    }
+
+    /// Removes the popup providing the given component, along with all the popups stacked on top of it.
+    fn close_popups_from(&self, popup_component: &ComponentRc) {
+        let index = self
+            .active_popups
+            .borrow()
+            .iter()
+            .position(|popup| vtable::VRc::ptr_eq(&popup.component, popup_component));
+        if let Some(index) = index {
+            self.truncate_popups(index);
+        }
+    }
+
+    /// Removes the topmost popups until only `len` popups are left.
+    fn truncate_popups(&self, len: usize) {
+        loop {
+            // Don't keep the borrow while the popup is dropped
+            let popup = {
+                let mut popups = self.active_popups.borrow_mut();
+                if popups.len() <= len {
+                    break;
+                }
+                popups.pop().unwrap()
+            };
+            if let PopupWindowLocation::ChildWindow(offset) = popup.location {
                 // Refresh the area that was previously covered by the popup.
                 let popup_region = crate::properties::evaluate_no_tracking(|| {
-                    let popup_component = ComponentRc::borrow_pin(&current_popup.component);
+                    let popup_component = ComponentRc::borrow_pin(&popup.component);
                     popup_component.as_ref().get_item_ref(0).as_ref().geometry()
                 })
                 .translate(offset.to_vector());
@@ -773,6 +1120,20 @@ pub fn close_popup(&self) {
         }
     }
 
+    /// Returns the popups that are rendered within this window, from the bottom to the top of the stack.
+    fn embedded_popups(&self) -> Vec<EmbeddedPopup> {
+        self.active_popups
+            .borrow()
+            .iter()
+            .filter_map(|popup| match popup.location {
+                PopupWindowLocation::TopLevel(_) => None,
+                PopupWindowLocation::ChildWindow(coordinates) => {
+                    Some((popup.component.clone(), coordinates, popup.close_policy))
+                }
+            })
+            .collect()
+    }
+
     /// Returns the scale factor set on the window, as provided by the windowing system.
     pub fn scale_factor(&self) -> f32 {
         self.pinned_fields.as_ref().project_ref().scale_factor.get()
@@ -814,6 +1175,25 @@ pub fn set_window_item_geometry(&self, size: LogicalSize) {
         }
     }
 
+    /// Sets the minimized, maximized, and full screen properties of the window item. This method is
+    /// typically called when the state of the window was changed by the windowing system.
+    ///
+    /// Only the properties whose value differs are set, so that the bindings of the others are kept.
+    pub fn set_window_item_state(&self, minimized: bool, maximized: bool, full_screen: bool) {
+        if let Some(window_item) = self.window_item() {
+            let window_item = window_item.as_pin_ref();
+            if window_item.minimized() != minimized {
+                window_item.minimized.set(minimized);
+            }
+            if window_item.maximized() != maximized {
+                window_item.maximized.set(maximized);
+            }
+            if window_item.full_screen() != full_screen {
+                window_item.full_screen.set(full_screen);
+            }
+        }
+    }
+
     /// Sets the close_requested callback. The callback will be run when the user tries to close a window.
     pub fn on_close_requested(&self, mut callback: impl FnMut() -> CloseRequestResponse + 'static) {
         self.close_requested.set_handler(move |()| callback());
@@ -990,12 +1370,67 @@ pub enum GraphicsAPI {
         handle: *const WindowAdapterRcOpaque,
         popup: &ComponentRc,
         position: crate::graphics::Point,
+        close_policy: PopupClosePolicy,
+        parent_item: &ItemRc,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).show_popup(
+            popup,
+            position,
+            close_policy,
+            parent_item,
+        );
+    }
+
+    /// Returns true if the child window was already created by slint_windowrc_show_child_window.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_has_child_window(
+        handle: *const WindowAdapterRcOpaque,
+        index: usize,
+        parent_item: &ItemRc,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).has_child_window(index, parent_item)
+    }
+
+    /// Show a child window. If the child window wasn't created yet, its new window adapter must be
+    /// provided, with the component created for it.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_show_child_window(
+        handle: *const WindowAdapterRcOpaque,
+        index: usize,
         parent_item: &ItemRc,
+        child_window_adapter: Option<&WindowAdapterRcOpaque>,
+        component: Option<&ComponentRc>,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).show_popup(popup, position, parent_item);
+        let child_window_adapter = child_window_adapter
+            .map(|w| &*(w as *const WindowAdapterRcOpaque as *const Rc<dyn WindowAdapter>));
+        WindowInner::from_pub(window_adapter.window()).show_child_window(
+            index,
+            parent_item,
+            || {
+                let child_window_adapter = child_window_adapter
+                    .expect("the window of a new child window must be provided");
+                let component =
+                    component.expect("the component of a new child window must be provided");
+                Ok((child_window_adapter.clone(), component.clone()))
+            },
+        );
     }
-    /// Close the current popup
+
+    /// Hide a child window
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_hide_child_window(
+        handle: *const WindowAdapterRcOpaque,
+        index: usize,
+        parent_item: &ItemRc,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).hide_child_window(index, parent_item);
+    }
+
+    /// Close the topmost popup
     pub unsafe extern "C" fn slint_windowrc_close_popup(handle: *const WindowAdapterRcOpaque) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
         WindowInner::from_pub(window_adapter.window()).close_popup();
@@ -1158,6 +1593,63 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().set_size(crate::api::LogicalSize::new(size.width, size.height));
     }
 
+    /// Returns true if the window is in full screen mode.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_fullscreen(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_fullscreen()
+    }
+
+    /// Shows the window in full screen mode, or leaves the full screen mode.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_fullscreen(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_fullscreen(value)
+    }
+
+    /// Returns true if the window is maximized.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_maximized(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_maximized()
+    }
+
+    /// Maximizes the window, or restores it to its previous size.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_maximized(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_maximized(value)
+    }
+
+    /// Returns true if the window is minimized.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_minimized(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_minimized()
+    }
+
+    /// Minimizes the window, or restores it.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_minimized(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_minimized(value)
+    }
+
     /// Return wether the style is using a dark theme
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_dark_color_scheme(
@@ -16,6 +16,7 @@
 #[doc(inline)]
 pub use i_slint_compiler::diagnostics::{Diagnostic, DiagnosticLevel};
 
+pub use i_slint_core::animations::{AnimationController, AnimationEvent};
 pub use i_slint_core::api::*;
 
 use crate::dynamic_component::ErasedComponentBox;
@@ -42,6 +43,8 @@ pub enum ValueType {
     Brush,
     /// Correspond to `image` type in .slint.
     Image,
+    /// Correspond to the `date-time` type in .slint.
+    DateTime,
     /// The type is not a public type but something internal.
     #[doc(hidden)]
     Other = -1,
@@ -65,6 +68,7 @@ fn from(ty: LangType) -> Self {
             LangType::Struct { .. } => Self::Struct,
             LangType::Void => Self::Void,
             LangType::Image => Self::Image,
+            LangType::DateTime => Self::DateTime,
             _ => Self::Other,
         }
     }
@@ -115,6 +119,8 @@ pub enum Value {
     EnumerationValue(String, String) = 10,
     #[doc(hidden)]
     LayoutCache(SharedVector<f32>) = 11,
+    /// Correspond to the `date-time` type in .slint
+    DateTime(i_slint_core::date_time::DateTime) = 12,
 }
 
 impl Value {
@@ -129,6 +135,7 @@ pub fn value_type(&self) -> ValueType {
             Value::Struct(_) => ValueType::Struct,
             Value::Brush(_) => ValueType::Brush,
             Value::Image(_) => ValueType::Image,
+            Value::DateTime(_) => ValueType::DateTime,
             _ => ValueType::Other,
         }
     }
@@ -157,6 +164,7 @@ fn eq(&self, other: &Self) -> bool {
                 matches!(other, Value::EnumerationValue(rhs_name, rhs_value) if lhs_name == rhs_name && lhs_value == rhs_value)
             }
             Value::LayoutCache(lhs) => matches!(other, Value::LayoutCache(rhs) if lhs == rhs),
+            Value::DateTime(lhs) => matches!(other, Value::DateTime(rhs) if lhs == rhs),
         }
     }
 }
@@ -180,6 +188,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Value::EasingCurve(c) => write!(f, "Value::EasingCurve({:?})", c),
             Value::EnumerationValue(n, v) => write!(f, "Value::EnumerationValue({:?}, {:?})", n, v),
             Value::LayoutCache(v) => write!(f, "Value::LayoutCache({:?})", v),
+            Value::DateTime(d) => write!(f, "Value::DateTime({:?})", d),
         }
     }
 }
@@ -221,6 +230,7 @@ fn try_from(v: Value) -> Result<$ty, Self::Error> {
 declare_value_conversion!(PathData => [PathData]);
 declare_value_conversion!(EasingCurve => [i_slint_core::animations::EasingCurve]);
 declare_value_conversion!(LayoutCache => [SharedVector<f32>] );
+declare_value_conversion!(DateTime => [i_slint_core::date_time::DateTime] );
 
 /// Implement From / TryFrom for Value that convert a `struct` to/from `Value::Object`
 macro_rules! declare_value_struct_conversion {
@@ -259,6 +269,7 @@ fn try_from(v: Value) -> Result<$name, Self::Error> {
 declare_value_struct_conversion!(struct i_slint_core::layout::LayoutInfo { min, max, min_percent, max_percent, preferred, stretch });
 declare_value_struct_conversion!(struct i_slint_core::graphics::Point { x, y, ..Default::default()});
 declare_value_struct_conversion!(struct i_slint_core::items::PointerEvent { kind, button });
+declare_value_struct_conversion!(struct i_slint_core::items::DropEvent { mime_type, data, position });
 
 /// Implement From / TryFrom for Value that convert an `enum` to/from `Value::EnumerationValue`
 ///
@@ -480,6 +491,11 @@ pub fn style(&self) -> Option<&String> {
         self.config.style.as_ref()
     }
 
+    /// Sets the domain used to look up the translations of the strings marked with `@tr(...)`.
+    pub fn set_translation_domain(&mut self, domain: String) {
+        self.config.translation_domain = Some(domain);
+    }
+
     /// Sets the callback that will be invoked when loading imported .slint files. The specified
     /// `file_loader_callback` parameter will be called with a canonical file path as argument
     /// and is expected to return a future that, when resolved, provides the source code of the
@@ -793,6 +809,85 @@ pub fn get_property(&self, name: &str) -> Result<Value, GetPropertyError> {
             .map_err(|()| GetPropertyError::NoSuchProperty)
     }
 
+    /// Returns the controller of the animation of a public property of this component,
+    /// or None if the property is not currently animated.
+    ///
+    /// The controller can be used to pause, resume, seek or cancel the animation, and to be
+    /// notified when the animation starts or finishes.
+    pub fn get_property_animation(
+        &self,
+        name: &str,
+    ) -> Result<Option<AnimationController>, GetPropertyError> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let name = normalize_identifier(name);
+
+        if comp
+            .description()
+            .original
+            .root_element
+            .borrow()
+            .property_declarations
+            .get(name.as_ref())
+            .map_or(true, |d| !d.expose_in_public_api)
+        {
+            return Err(GetPropertyError::NoSuchProperty);
+        }
+
+        comp.description()
+            .get_property_animation(comp.borrow(), &name)
+            .map_err(|()| GetPropertyError::NoSuchProperty)
+    }
+
+    /// Install a callback that is called with the new value of a public property of this
+    /// component when it has changed.
+    ///
+    /// The callback isn't called right away when the property changes, but from the event loop,
+    /// once the property changes have settled. If the property changes several times before that,
+    /// the callback is only called once. The callback stays installed as long as the component is alive.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init();
+    /// use slint_interpreter::{ComponentDefinition, ComponentCompiler, Value};
+    /// use std::{cell::Cell, rc::Rc};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         in-out property <int> my_property: 42;
+    ///     }
+    /// "#;
+    /// let mut compiler = ComponentCompiler::default();
+    /// let definition = spin_on::spin_on(
+    ///     compiler.build_from_source(code.into(), Default::default()));
+    /// let instance = definition.unwrap().create().unwrap();
+    /// let last_value = Rc::new(Cell::new(0));
+    /// let last_value_clone = last_value.clone();
+    /// instance.observe_property("my_property", move |value| {
+    ///     last_value_clone.set(i32::try_from(value).unwrap());
+    /// }).unwrap();
+    /// instance.set_property("my_property", Value::from(100)).unwrap();
+    /// i_slint_core::tests::slint_mock_elapsed_time(16);
+    /// assert_eq!(last_value.get(), 100);
+    /// ```
+    pub fn observe_property(
+        &self,
+        name: &str,
+        callback: impl FnMut(Value) + 'static,
+    ) -> Result<(), GetPropertyError> {
+        // Check that this is a valid property
+        self.get_property(name)?;
+        let name = normalize_identifier(name).into_owned();
+        crate::dynamic_component::add_property_observer(
+            &self.inner,
+            move |instance| {
+                instance.component_type.get_property(instance.borrow(), &name).unwrap_or_default()
+            },
+            callback,
+        );
+        Ok(())
+    }
+
     /// Set the value for a public property of this component
     pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyError> {
         let name = normalize_identifier(name);
@@ -912,6 +1007,34 @@ pub fn get_global_property(
             .map_err(|()| GetPropertyError::NoSuchProperty)
     }
 
+    /// Install a callback that is called with the new value of a property within an exported
+    /// global singleton used by this component when it has changed.
+    ///
+    /// See [`Self::observe_property`] for details.
+    pub fn observe_global_property(
+        &self,
+        global: &str,
+        property: &str,
+        callback: impl FnMut(Value) + 'static,
+    ) -> Result<(), GetPropertyError> {
+        // Check that this is a valid property
+        self.get_global_property(global, property)?;
+        let global = normalize_identifier(global).into_owned();
+        let property = normalize_identifier(property).into_owned();
+        crate::dynamic_component::add_property_observer(
+            &self.inner,
+            move |instance| {
+                instance
+                    .component_type
+                    .get_global(instance.borrow(), &global)
+                    .and_then(|g| g.as_ref().get_property(&property))
+                    .unwrap_or_default()
+            },
+            callback,
+        );
+        Ok(())
+    }
+
     /// Set the value for a property within an exported global singleton used by this component.
     pub fn set_global_property(
         &self,
@@ -994,24 +1117,9 @@ pub fn invoke_global(
             .description()
             .get_global(comp.borrow(), &normalize_identifier(global))
             .map_err(|()| InvokeError::NoSuchCallable)?; // FIXME: should there be a NoSuchGlobal error?
-        let callable_name = normalize_identifier(callable_name);
-        if matches!(
-            comp.description()
-                .original
-                .root_element
-                .borrow()
-                .lookup_property(&callable_name)
-                .property_type,
-            LangType::Function { .. }
-        ) {
-            g.as_ref()
-                .eval_function(&callable_name, args.to_vec())
-                .map_err(|()| InvokeError::NoSuchCallable)
-        } else {
-            g.as_ref()
-                .invoke_callback(&callable_name, args)
-                .map_err(|()| InvokeError::NoSuchCallable)
-        }
+        g.as_ref()
+            .invoke_callback(&normalize_identifier(callable_name), args)
+            .map_err(|()| InvokeError::NoSuchCallable)
     }
 
     /// Highlight the elements which are pointed by a given source location.
@@ -1146,6 +1254,27 @@ pub fn run_event_loop() -> Result<(), PlatformError> {
     i_slint_backend_selector::with_platform(|b| b.run_event_loop())
 }
 
+/// Processes the pending events and renders the visible windows, waiting up to `timeout` for
+/// new events if there are none. Use this function instead of [`run_event_loop()`] to integrate
+/// Slint into another event loop, such as the main loop of a game.
+///
+/// Returns [`ControlFlow::Break`](core::ops::ControlFlow::Break) when the event loop would have
+/// exited. See [`Platform::process_events()`](i_slint_core::platform::Platform::process_events()) for details,
+/// and [`set_event_loop_waker()`](i_slint_core::platform::set_event_loop_waker()) to know when there are new events to process.
+pub fn process_events(
+    timeout: core::time::Duration,
+) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+    i_slint_backend_selector::with_platform(|b| b.process_events(timeout))
+}
+
+/// Re-translate all the strings marked with `@tr(...)` in the `.slint` files.
+///
+/// Call this function after changing the language at run-time, so that the bindings
+/// that depend on translated strings are re-evaluated.
+pub fn update_all_translations() {
+    i_slint_core::translations::mark_all_translations_dirty()
+}
+
 /// This module contains a few functions used by the tests
 #[doc(hidden)]
 pub mod testing {
@@ -14,8 +14,10 @@
 use i_slint_compiler::*;
 use i_slint_compiler::{diagnostics::BuildDiagnostics, object_tree::PropertyDeclaration};
 use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::animations::{AnimationController, AnimationEvent};
 use i_slint_core::component::{
-    Component, ComponentRef, ComponentRefPin, ComponentVTable, ComponentWeak, IndexRange,
+    Component, ComponentRc, ComponentRef, ComponentRefPin, ComponentVTable, ComponentWeak,
+    IndexRange,
 };
 use i_slint_core::item_tree::{
     ItemRc, ItemTreeNode, ItemVisitorRefMut, ItemVisitorVTable, ItemWeak, TraversalOrder,
@@ -27,7 +29,7 @@
 use i_slint_core::model::RepeatedComponent;
 use i_slint_core::model::Repeater;
 use i_slint_core::platform::PlatformError;
-use i_slint_core::properties::InterpolatedPropertyValue;
+use i_slint_core::properties::{ChangeTracker, InterpolatedPropertyValue};
 use i_slint_core::rtti::{self, AnimatedBindingKind, FieldOffset, PropertyInfo};
 use i_slint_core::slice::Slice;
 use i_slint_core::window::{WindowAdapter, WindowInner};
@@ -240,6 +242,10 @@ pub(crate) struct ComponentExtraData {
         once_cell::unsync::OnceCell<vtable::VWeak<ComponentVTable, ErasedComponentBox>>,
     // resource id -> file path
     pub(crate) embedded_file_resources: HashMap<usize, String>,
+    /// The trackers of the `changed` handlers
+    pub(crate) change_trackers: once_cell::unsync::OnceCell<Vec<ChangeTracker>>,
+    /// The observers installed with `ComponentInstance::observe_property`
+    pub(crate) property_observers: core::cell::RefCell<Vec<ChangeTracker>>,
 }
 
 struct ErasedRepeaterWithinComponent<'id>(RepeaterWithinComponent<'id, 'static>);
@@ -508,6 +514,35 @@ pub fn get_property(&self, component: ComponentRefPin, name: &str) -> Result<Val
         }
     }
 
+    /// Returns the controller of the animation running on the property
+    ///
+    /// Returns an error if the component is not an instance corresponding to this ComponentDescription,
+    /// or if the property with this name does not exist in this component
+    pub fn get_property_animation(
+        &self,
+        component: ComponentRefPin,
+        name: &str,
+    ) -> Result<Option<AnimationController>, ()> {
+        if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
+            return Err(());
+        }
+        generativity::make_guard!(guard);
+        // Safety: we just verified that the component has the right vtable
+        let c = unsafe { InstanceRef::from_pin_ref(component, guard) };
+        if let Some(alias) = self
+            .original
+            .root_element
+            .borrow()
+            .property_declarations
+            .get(name)
+            .and_then(|d| d.is_alias.as_ref())
+        {
+            eval::property_animation_controller(c, &alias.element(), alias.name())
+        } else {
+            eval::property_animation_controller(c, &self.original.root_element, name)
+        }
+    }
+
     /// Sets an handler for a callback
     ///
     /// Returns an error if the component is not an instance corresponding to this ComponentDescription,
@@ -683,7 +718,24 @@ fn ensure_repeater_updated<'id>(
         let get_prop = |nr: &NamedReference| -> LogicalLength {
             eval::load_property(instance_ref, &nr.element(), nr.name()).unwrap().try_into().unwrap()
         };
-        repeater.ensure_updated_listview(
+        let load = |nr: &NamedReference| -> Value {
+            eval::load_property(instance_ref, &nr.element(), nr.name()).unwrap()
+        };
+        let store = |nr: &NamedReference, value: Value| {
+            eval::store_property(instance_ref, &nr.element(), nr.name(), value).unwrap()
+        };
+        let scroll_request_row: i32 = load(&lv.scroll_request_row).try_into().unwrap();
+        if scroll_request_row >= 0
+            && repeater.listview_scroll_to(
+                scroll_request_row as usize,
+                load(&lv.scroll_request_alignment).try_into().unwrap(),
+                assume_property_logical_length(get_property_ptr(&lv.viewport_y, instance_ref)),
+                get_prop(&lv.listview_height),
+            )
+        {
+            store(&lv.scroll_request_row, Value::Number(-1.));
+        }
+        let visible_rows = repeater.ensure_updated_listview(
             init,
             assume_property_logical_length(get_property_ptr(&lv.viewport_width, instance_ref)),
             assume_property_logical_length(get_property_ptr(&lv.viewport_height, instance_ref)),
@@ -691,6 +743,10 @@ fn ensure_repeater_updated<'id>(
             get_prop(&lv.listview_width),
             assume_property_logical_length(get_property_ptr(&lv.listview_height, instance_ref)),
         );
+        let first_visible_row =
+            if visible_rows.is_empty() { -1 } else { visible_rows.start as i32 };
+        store(&lv.first_visible_row, Value::Number(first_visible_row as f64));
+        store(&lv.last_visible_row, Value::Number(visible_rows.end as f64 - 1.));
     } else {
         repeater.ensure_updated(init);
     }
@@ -782,6 +838,8 @@ pub(crate) fn generate_component<'id>(
                 rtti_for::<FocusScope>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
+                rtti_for::<DragArea>(),
+                rtti_for::<DropArea>(),
                 rtti_for::<WindowItem>(),
                 rtti_for::<TextInput>(),
                 rtti_for::<Clip>(),
@@ -971,6 +1029,7 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
             Type::Color => animated_property_info::<Color>(),
             Type::Brush => animated_property_info::<Brush>(),
             Type::Duration => animated_property_info::<i64>(),
+            Type::DateTime => property_info::<i_slint_core::date_time::DateTime>(),
             Type::Angle => animated_property_info::<f32>(),
             Type::PhysicalLength => animated_property_info::<f32>(),
             Type::LogicalLength => animated_property_info::<f32>(),
@@ -988,6 +1047,8 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
             Type::Struct { .. } => property_info::<Value>(),
             Type::Array(_) => property_info::<Value>(),
             Type::Percent => property_info::<f32>(),
+            // Enums declared in .slint have no native type
+            Type::Enumeration(e) if e.node.is_some() => property_info::<Value>(),
             Type::Enumeration(e) => {
                 macro_rules! match_enum_type {
                     ($( $(#[$enum_doc:meta])* enum $Name:ident { $($body:tt)* })*) => {
@@ -1165,6 +1226,77 @@ pub fn animation_for_property(
     }
 }
 
+/// Returns the handler that calls the `animation-started` and `animation-finished` callbacks
+/// of the given animation, or None if the animation doesn't declare any.
+pub fn animation_event_handler(
+    component: InstanceRef,
+    animation: &Option<i_slint_compiler::object_tree::PropertyAnimation>,
+) -> Option<Box<dyn Fn(AnimationEvent)>> {
+    fn callbacks(anim_elem: &ElementRc) -> Option<[Option<Expression>; 2]> {
+        let anim_elem = anim_elem.borrow();
+        let callback = |name| anim_elem.bindings.get(name).map(|b| b.borrow().expression.clone());
+        let callbacks = [callback("animation-started"), callback("animation-finished")];
+        callbacks.iter().any(Option::is_some).then_some(callbacks)
+    }
+
+    fn call(callbacks: &[Option<Expression>; 2], event: AnimationEvent, component: InstanceRef) {
+        if let Some(expr) = &callbacks[(event == AnimationEvent::Finished) as usize] {
+            eval::eval_expression(
+                expr,
+                &mut eval::EvalLocalContext::from_component_instance(component),
+            );
+        }
+    }
+
+    let self_weak = component.self_weak().get().unwrap().clone();
+    match animation {
+        Some(i_slint_compiler::object_tree::PropertyAnimation::Static(anim_elem)) => {
+            let callbacks = callbacks(anim_elem)?;
+            Some(Box::new(move |event| {
+                // The events are delivered on the next animation tick, the component might be gone by then
+                if let Some(self_rc) = self_weak.upgrade() {
+                    generativity::make_guard!(guard);
+                    let self_ = self_rc.unerase(guard);
+                    call(&callbacks, event, self_.borrow_instance());
+                }
+            }))
+        }
+        Some(i_slint_compiler::object_tree::PropertyAnimation::Transition {
+            animations,
+            state_ref,
+        }) => {
+            let animations = animations
+                .iter()
+                .map(|a| (a.state_id, a.is_out, callbacks(&a.animation)))
+                .collect::<Vec<_>>();
+            if animations.iter().all(|(.., callbacks)| callbacks.is_none()) {
+                return None;
+            }
+            let state_ref = state_ref.clone();
+            Some(Box::new(move |event| {
+                if let Some(self_rc) = self_weak.upgrade() {
+                    generativity::make_guard!(guard);
+                    let self_ = self_rc.unerase(guard);
+                    let component = self_.borrow_instance();
+                    let state = eval::eval_expression(
+                        &state_ref,
+                        &mut eval::EvalLocalContext::from_component_instance(component),
+                    );
+                    let state_info: i_slint_core::properties::StateInfo = state.try_into().unwrap();
+                    let active = animations.iter().find(|(state_id, is_out, _)| {
+                        (*is_out && *state_id == state_info.previous_state)
+                            || (!*is_out && *state_id == state_info.current_state)
+                    });
+                    if let Some((.., Some(callbacks))) = active {
+                        call(callbacks, event, component);
+                    }
+                }
+            }))
+        }
+        None => None,
+    }
+}
+
 fn make_callback_eval_closure(
     expr: Expression,
     self_weak: &vtable::VWeak<ComponentVTable, ErasedComponentBox>,
@@ -1198,6 +1330,32 @@ fn make_binding_eval_closure(
     }
 }
 
+/// Install an observer that calls `callback` with the value returned by `eval` when it changes,
+/// for as long as the component is alive.
+pub(crate) fn add_property_observer(
+    component: &DynamicComponentVRc,
+    eval: impl Fn(InstanceRef) -> Value + 'static,
+    callback: impl FnMut(Value) + 'static,
+) {
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        (vtable::VRc::downgrade(component), core::cell::RefCell::new(callback)),
+        move |(weak, _)| {
+            weak.upgrade().map_or_else(Value::default, |component| {
+                generativity::make_guard!(guard);
+                let component = component.unerase(guard);
+                eval(component.borrow_instance())
+            })
+        },
+        |(_, callback), value| (callback.borrow_mut())(value.clone()),
+    );
+    generativity::make_guard!(guard);
+    let component = component.unerase(guard);
+    let instance_ref = component.borrow_instance();
+    let extra_data = instance_ref.component_type.extra_data_offset.apply(instance_ref.as_ref());
+    extra_data.property_observers.borrow_mut().push(tracker);
+}
+
 pub fn instantiate(
     component_type: Rc<ComponentDescription>,
     parent_ctx: Option<ComponentRefPin>,
@@ -1339,6 +1497,12 @@ pub fn instantiate(
                             )
                             .unwrap();
                     }
+                    if let Some((handler, controller)) =
+                        animation_event_handler(instance_ref, &binding.animation)
+                            .zip(prop_info.animation_controller(item))
+                    {
+                        controller.set_event_handler(handler);
+                    }
                 }
                 for nr in &binding.two_way_bindings {
                     // Safety: The compiler must have ensured that the properties exist and are of the same type
@@ -1375,6 +1539,12 @@ pub fn instantiate(
                                 maybe_animation,
                             );
                         }
+                        if let Some((handler, controller)) =
+                            animation_event_handler(instance_ref, &binding.animation)
+                                .zip(prop_rtti.animation_controller(item))
+                        {
+                            controller.set_event_handler(handler);
+                        }
                     }
                 } else {
                     panic!("unknown property {}", prop_name);
@@ -1466,6 +1636,36 @@ pub fn run_setup_code(&self) {
                 &mut eval::EvalLocalContext::from_component_instance(instance_ref),
             );
         }
+
+        // The change trackers are owned by the instance, so the closures can always upgrade self_weak
+        let self_weak = instance_ref.self_weak().get().unwrap();
+        let mut change_trackers = Vec::new();
+        object_tree::recurse_elem(
+            &self.0.component_type.original.root_element,
+            &(),
+            &mut |elem, _| {
+                for (prop, handlers) in &elem.borrow().change_callbacks {
+                    for handler in handlers.borrow().iter() {
+                        let eval_fn = make_binding_eval_closure(
+                            Expression::PropertyReference(NamedReference::new(elem, prop)),
+                            self_weak,
+                        );
+                        let handler_fn = make_binding_eval_closure(handler.clone(), self_weak);
+                        let tracker = ChangeTracker::default();
+                        tracker.init(
+                            (),
+                            move |_| eval_fn(),
+                            move |_, _| {
+                                handler_fn();
+                            },
+                        );
+                        change_trackers.push(tracker);
+                    }
+                }
+            },
+        );
+        let extra_data = self.0.component_type.extra_data_offset.apply(instance_ref.as_ref());
+        let _ = extra_data.change_trackers.set(change_trackers);
     }
 }
 impl<'id> From<ComponentBox<'id>> for ErasedComponentBox {
@@ -1731,10 +1931,25 @@ pub fn toplevel_instance(&self) -> InstanceRef<'a, 'id> {
     }
 }
 
+/// Creates the window of a `ChildWindow` and the component to be shown in it.
+pub fn instantiate_child_window(
+    child_window: &object_tree::PopupWindow,
+    parent_comp: ComponentRefPin,
+) -> Result<(Rc<dyn WindowAdapter>, ComponentRc), PlatformError> {
+    let window_adapter = i_slint_backend_selector::with_platform(|b| b.create_window_adapter())?;
+    generativity::make_guard!(guard);
+    // FIXME: we should compile once and keep the cached compiled component
+    let compiled = generate_component(&child_window.component, guard);
+    let inst = instantiate(compiled, Some(parent_comp), &window_adapter, Default::default());
+    inst.run_setup_code();
+    Ok((window_adapter, vtable::VRc::into_dyn(inst)))
+}
+
 /// Show the popup at the given location
 pub fn show_popup(
     popup: &object_tree::PopupWindow,
     pos: i_slint_core::graphics::Point,
+    close_policy: i_slint_core::items::PopupClosePolicy,
     parent_comp: ComponentRefPin,
     parent_window_adapter: &Rc<dyn WindowAdapter>,
     parent_item: &ItemRc,
@@ -1747,6 +1962,7 @@ pub fn show_popup(
     WindowInner::from_pub(parent_window_adapter.window()).show_popup(
         &vtable::VRc::into_dyn(inst),
         pos,
+        close_policy,
         parent_item,
     );
 }
@@ -5,14 +5,17 @@
 use crate::dynamic_component::InstanceRef;
 use core::convert::TryInto;
 use core::pin::Pin;
+use corelib::animations::AnimationController;
+use corelib::date_time::DateTime;
 use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
 use corelib::items::{ItemRef, PropertyAnimation};
-use corelib::model::{Model, ModelRc};
+use corelib::model::{Model, ModelExt, ModelRc};
 use corelib::rtti::AnimatedBindingKind;
 use corelib::window::WindowInner;
 use corelib::{Brush, Color, PathData, SharedString, SharedVector};
 use i_slint_compiler::expression_tree::{
-    BuiltinFunction, EasingCurve, Expression, Path as ExprPath, PathElement as ExprPathElement,
+    ArrayOperation, BuiltinFunction, DateTimeComponent, EasingCurve, Expression, Path as ExprPath,
+    PathElement as ExprPathElement,
 };
 use i_slint_compiler::langtype::Type;
 use i_slint_compiler::object_tree::ElementRc;
@@ -35,6 +38,7 @@ fn set_binding(
         animation: AnimatedBindingKind,
     );
     fn offset(&self) -> usize;
+    fn animation_controller(&self, item: Pin<ItemRef>) -> Option<AnimationController>;
 
     /// Safety: Property2 must be a (pinned) pointer to a `Property<T>`
     /// where T is the same T as the one represented by this property.
@@ -66,6 +70,9 @@ fn set_binding(
     fn offset(&self) -> usize {
         (*self).offset()
     }
+    fn animation_controller(&self, item: Pin<ItemRef>) -> Option<AnimationController> {
+        (*self).animation_controller(ItemRef::downcast_pin(item).unwrap())
+    }
     unsafe fn link_two_ways(&self, item: Pin<ItemRef>, property2: *const ()) {
         // Safety: ErasedPropertyInfo::link_two_ways and PropertyInfo::link_two_ways have the same safety requirement
         (*self).link_two_ways(ItemRef::downcast_pin(item).unwrap(), property2)
@@ -189,6 +196,43 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 }
             }
         }
+        Expression::ArrayOperation { op, array, element, index, body } => {
+            let model = match eval_expression(array, local_context) {
+                Value::Model(model) => model,
+                _ => ModelRc::default(),
+            };
+            model.model_tracker().track_row_count_changes();
+            let mut rows = (0..model.row_count())
+                .filter_map(|row| Some((row, model.row_data_tracked(row)?)));
+            let mut eval_body = |row: usize, data: Value| {
+                local_context.local_variables.insert(element.clone(), data);
+                local_context.local_variables.insert(index.clone(), Value::Number(row as f64));
+                eval_expression(body, local_context)
+            };
+            let mut is_true = |row, data| matches!(eval_body(row, data), Value::Bool(true));
+            let to_model = |values: SharedVector<Value>| {
+                Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(values)))
+            };
+            match op {
+                ArrayOperation::Filter => to_model(
+                    rows.filter(|(row, data)| is_true(*row, data.clone()))
+                        .map(|(_, data)| data)
+                        .collect(),
+                ),
+                ArrayOperation::Map => {
+                    to_model(rows.map(|(row, data)| eval_body(row, data)).collect())
+                }
+                ArrayOperation::Any => Value::Bool(rows.any(|(row, data)| is_true(row, data))),
+                ArrayOperation::All => Value::Bool(rows.all(|(row, data)| is_true(row, data))),
+                ArrayOperation::Sum => Value::Number(
+                    rows.map(|(row, data)| eval_body(row, data).try_into().unwrap_or(0.)).sum(),
+                ),
+                ArrayOperation::IndexOf => Value::Number(
+                    rows.find(|(row, data)| is_true(*row, data.clone()))
+                        .map_or(-1., |(row, _)| row as f64),
+                ),
+            }
+        }
         Expression::Cast { from, to } => {
             let v = eval_expression(&*from, local_context);
             match (v, to) {
@@ -198,6 +242,12 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 }
                 (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
                 (Value::Brush(brush), Type::Color) => brush.color().into(),
+                (Value::Number(n), Type::DateTime) => {
+                    Value::DateTime(DateTime::from_timestamp_millis(n as i64))
+                }
+                (Value::DateTime(date), Type::Duration) => {
+                    Value::Number(date.timestamp_millis() as f64)
+                }
                 (v, _) => v,
             }
         }
@@ -346,6 +396,18 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::EaseInElastic => corelib::animations::EasingCurve::EaseInElastic,
+            EasingCurve::EaseOutElastic => corelib::animations::EasingCurve::EaseOutElastic,
+            EasingCurve::EaseInOutElastic => corelib::animations::EasingCurve::EaseInOutElastic,
+            EasingCurve::EaseInBounce => corelib::animations::EasingCurve::EaseInBounce,
+            EasingCurve::EaseOutBounce => corelib::animations::EasingCurve::EaseOutBounce,
+            EasingCurve::EaseInOutBounce => corelib::animations::EasingCurve::EaseInOutBounce,
+            EasingCurve::Spring(stiffness, damping, mass) => {
+                corelib::animations::EasingCurve::Spring([*stiffness, *damping, *mass])
+            }
+            EasingCurve::Keyframes(keyframes) => {
+                corelib::animations::EasingCurve::Keyframes(static_keyframes(keyframes))
+            }
         }),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
@@ -553,6 +615,12 @@ fn call_builtin_function(
                     popup.y.name(),
                 )
                 .unwrap();
+                let close_policy = load_property_helper(
+                    local_context.component_instance,
+                    &popup.close_policy.element(),
+                    popup.close_policy.name(),
+                )
+                .unwrap();
 
                 generativity::make_guard!(guard);
                 let enclosing_component =
@@ -572,6 +640,7 @@ fn call_builtin_function(
                         x.try_into().unwrap(),
                         y.try_into().unwrap(),
                     ),
+                    close_policy.try_into().unwrap(),
                     component.borrow(),
                     window_adapter_ref(component).unwrap(),
                     &parent_item,
@@ -581,6 +650,63 @@ fn call_builtin_function(
                 panic!("internal error: argument to SetFocusItem must be an element")
             }
         }
+        BuiltinFunction::ShowChildWindow | BuiltinFunction::HideChildWindow => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to {:?}", f)
+            }
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot show or hide a child window from a global component")
+                }
+            };
+            if let Expression::ElementReference(child_window) = &arguments[0] {
+                let child_window = child_window.upgrade().unwrap();
+                let child_comp = child_window.borrow().enclosing_component.upgrade().unwrap();
+                let parent_component = child_comp
+                    .parent_element
+                    .upgrade()
+                    .unwrap()
+                    .borrow()
+                    .enclosing_component
+                    .upgrade()
+                    .unwrap();
+                let popup_list = parent_component.popup_windows.borrow();
+                let (index, child_window) = popup_list
+                    .iter()
+                    .enumerate()
+                    .find(|(_, p)| Rc::ptr_eq(&p.component, &child_comp))
+                    .unwrap();
+
+                generativity::make_guard!(guard);
+                let enclosing_component =
+                    enclosing_component_for_element(&child_window.parent_element, component, guard);
+                let parent_item_info = &enclosing_component.component_type.items
+                    [child_window.parent_element.borrow().id.as_str()];
+                let parent_item_comp =
+                    enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+                let parent_item = corelib::items::ItemRc::new(
+                    vtable::VRc::into_dyn(parent_item_comp),
+                    parent_item_info.item_index(),
+                );
+
+                let window_adapter = window_adapter_ref(component).unwrap();
+                let window = WindowInner::from_pub(window_adapter.window());
+                if matches!(f, BuiltinFunction::ShowChildWindow) {
+                    window.show_child_window(index, &parent_item, || {
+                        crate::dynamic_component::instantiate_child_window(
+                            child_window,
+                            component.borrow(),
+                        )
+                    });
+                } else {
+                    window.hide_child_window(index, &parent_item);
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to {:?} must be an element", f)
+            }
+        }
         BuiltinFunction::StringIsFloat => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to StringIsFloat")
@@ -601,6 +727,106 @@ fn call_builtin_function(
                 panic!("Argument not a string");
             }
         }
+        BuiltinFunction::StringToUppercase
+        | BuiltinFunction::StringToLowercase
+        | BuiltinFunction::StringTrim
+        | BuiltinFunction::StringCharacterCount => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to {:?}", f)
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            match f {
+                BuiltinFunction::StringToUppercase => Value::String(s.to_uppercase().into()),
+                BuiltinFunction::StringToLowercase => Value::String(s.to_lowercase().into()),
+                BuiltinFunction::StringTrim => Value::String(s.trim().into()),
+                _ => Value::Number(s.chars().count() as f64),
+            }
+        }
+        BuiltinFunction::StringContains
+        | BuiltinFunction::StringStartsWith
+        | BuiltinFunction::StringSplit => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to {:?}", f)
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let pattern: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            match f {
+                BuiltinFunction::StringContains => Value::Bool(s.contains(pattern.as_str())),
+                BuiltinFunction::StringStartsWith => Value::Bool(s.starts_with(pattern.as_str())),
+                _ => {
+                    let parts: SharedVector<Value> = if pattern.is_empty() {
+                        core::iter::once(Value::String(s)).collect()
+                    } else {
+                        s.split(pattern.as_str()).map(|p| Value::String(p.into())).collect()
+                    };
+                    Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(parts)))
+                }
+            }
+        }
+        BuiltinFunction::FormatNumber => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to FormatNumber")
+            }
+            let value: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let decimals: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let grouping: bool = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::string::format_number(value, decimals, grouping))
+        }
+        BuiltinFunction::DateTimeFromTimestamp => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to DateTimeFromTimestamp")
+            }
+            let milliseconds: i64 =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::DateTime(DateTime::from_timestamp_millis(milliseconds))
+        }
+        BuiltinFunction::DateTimeFromComponents => {
+            if arguments.len() != 6 {
+                panic!("internal error: incorrect argument count to DateTimeFromComponents")
+            }
+            let mut c = arguments
+                .iter()
+                .map(|a| -> i32 { eval_expression(a, local_context).try_into().unwrap() });
+            let mut next = || c.next().unwrap();
+            Value::DateTime(DateTime::from_components(
+                next(),
+                next(),
+                next(),
+                next(),
+                next(),
+                next(),
+            ))
+        }
+        BuiltinFunction::DateTimeComponent(component) => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to DateTimeComponent")
+            }
+            let date: DateTime = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(match component {
+                DateTimeComponent::Year => date.year(),
+                DateTimeComponent::Month => date.month(),
+                DateTimeComponent::Day => date.day(),
+                DateTimeComponent::Hour => date.hour(),
+                DateTimeComponent::Minute => date.minute(),
+                DateTimeComponent::Second => date.second(),
+                DateTimeComponent::DayOfWeek => date.day_of_week(),
+            } as f64)
+        }
+        BuiltinFunction::DateTimeTimestamp => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to DateTimeTimestamp")
+            }
+            let date: DateTime = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(date.timestamp_millis() as f64)
+        }
+        BuiltinFunction::DateTimeToIsoString => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to DateTimeToIsoString")
+            }
+            let date: DateTime = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::String(date.to_iso_string())
+        }
         BuiltinFunction::ColorBrighter => {
             if arguments.len() != 2 {
                 panic!("internal error: incorrect argument count to ColorBrighter")
@@ -670,6 +896,40 @@ fn call_builtin_function(
             let a: u8 = (255. * a).max(0.).min(255.) as u8;
             Value::Brush(Brush::SolidColor(Color::from_argb_u8(a, r, g, b)))
         }
+        BuiltinFunction::Translate => {
+            let original: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let context: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let domain: SharedString =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let args = match eval_expression(&arguments[3], local_context) {
+                Value::Model(model) => {
+                    model.iter().map(|v| SharedString::try_from(v).unwrap()).collect::<Vec<_>>()
+                }
+                _ => panic!("Translate arguments not an array"),
+            };
+            let n: i32 = eval_expression(&arguments[4], local_context).try_into().unwrap();
+            let plural: SharedString =
+                eval_expression(&arguments[5], local_context).try_into().unwrap();
+            Value::String(corelib::translations::translate(
+                &original, &context, &domain, &args, n, &plural,
+            ))
+        }
+        BuiltinFunction::ClipboardText => Value::String(corelib::clipboard::clipboard_text()),
+        BuiltinFunction::SetClipboardText => {
+            let text: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            corelib::clipboard::set_clipboard_text(text);
+            Value::Void
+        }
+        BuiltinFunction::ClipboardImage => Value::Image(corelib::clipboard::clipboard_image()),
+        BuiltinFunction::SetClipboardImage => {
+            if let Value::Image(image) = eval_expression(&arguments[0], local_context) {
+                corelib::clipboard::set_clipboard_image(&image);
+            }
+            Value::Void
+        }
         BuiltinFunction::DarkColorScheme => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 Value::Bool(window_adapter_ref(component).unwrap().dark_color_scheme())
@@ -922,6 +1182,43 @@ fn load_property_helper(
     }
 }
 
+/// Returns the controller of the animation running on the property, if it is animated
+pub fn property_animation_controller(
+    component: InstanceRef,
+    element: &ElementRc,
+    name: &str,
+) -> Result<Option<AnimationController>, ()> {
+    generativity::make_guard!(guard);
+    match enclosing_component_instance_for_element(
+        element,
+        ComponentInstance::InstanceRef(component),
+        guard,
+    ) {
+        ComponentInstance::InstanceRef(enclosing_component) => {
+            let element = element.borrow();
+            if element.id == element.enclosing_component.upgrade().unwrap().root_element.borrow().id
+            {
+                if let Some(x) = enclosing_component.component_type.custom_properties.get(name) {
+                    return Ok(unsafe {
+                        x.prop.animation_controller(Pin::new_unchecked(
+                            &*enclosing_component.as_ptr().add(x.offset),
+                        ))
+                    });
+                } else if enclosing_component.component_type.original.is_global() {
+                    return Err(());
+                }
+            };
+            let item_info =
+                enclosing_component.component_type.items.get(element.id.as_str()).ok_or(())?;
+            core::mem::drop(element);
+            let item = unsafe { item_info.item_from_component(enclosing_component.as_ptr()) };
+            Ok(item_info.rtti.properties.get(name).ok_or(())?.animation_controller(item))
+        }
+        // Properties of globals can't be animated
+        ComponentInstance::GlobalComponent(_) => Ok(None),
+    }
+}
+
 pub fn store_property(
     component_instance: InstanceRef,
     element: &ElementRc,
@@ -944,6 +1241,12 @@ pub fn store_property(
                     crate::dynamic_component::animation_for_property(enclosing_component, &None)
                 }
             };
+            let animation_event_handler = element.borrow().bindings.get(name).and_then(|b| {
+                crate::dynamic_component::animation_event_handler(
+                    enclosing_component,
+                    &b.borrow().animation,
+                )
+            });
 
             let component = element.borrow().enclosing_component.upgrade().unwrap();
             if element.borrow().id == component.root_element.borrow().id {
@@ -963,10 +1266,15 @@ pub fn store_property(
                     }
                     unsafe {
                         let p = Pin::new_unchecked(&*enclosing_component.as_ptr().add(x.offset));
-                        return x
-                            .prop
+                        x.prop
                             .set(p, value, maybe_animation.as_animation())
-                            .map_err(|()| SetPropertyError::WrongType);
+                            .map_err(|()| SetPropertyError::WrongType)?;
+                        if let Some((handler, controller)) =
+                            animation_event_handler.zip(x.prop.animation_controller(p))
+                        {
+                            controller.set_event_handler(handler);
+                        }
+                        return Ok(());
                     }
                 } else if enclosing_component.component_type.original.is_global() {
                     return Err(SetPropertyError::NoSuchProperty);
@@ -977,6 +1285,11 @@ pub fn store_property(
             let p = &item_info.rtti.properties.get(name).ok_or(SetPropertyError::NoSuchProperty)?;
             p.set(item, value, maybe_animation.as_animation())
                 .map_err(|()| SetPropertyError::WrongType)?;
+            if let Some((handler, controller)) =
+                animation_event_handler.zip(p.animation_controller(item))
+            {
+                controller.set_event_handler(handler);
+            }
         }
         ComponentInstance::GlobalComponent(glob) => {
             glob.as_ref().set_property(name, value)?;
@@ -1007,6 +1320,7 @@ fn check_value_type(value: &Value, ty: &Type) -> bool {
         | Type::Angle
         | Type::Percent => matches!(value, Value::Number(_)),
         Type::Image => matches!(value, Value::Image(_)),
+        Type::DateTime => matches!(value, Value::DateTime(_)),
         Type::Bool => matches!(value, Value::Bool(_)),
         Type::Model => {
             matches!(value, Value::Model(_) | Value::Bool(_) | Value::Number(_))
@@ -1200,6 +1514,32 @@ pub(crate) fn enclosing_component_instance_for_element<'a, 'old_id, 'new_id>(
     }
 }
 
+/// The core library needs the keyframes of an easing curve to be 'static, so leak them,
+/// but only once for every distinct set of keyframes.
+fn static_keyframes(
+    keyframes: &[(f32, f32)],
+) -> corelib::slice::Slice<'static, corelib::animations::EasingKeyframe> {
+    type Cache = HashMap<Vec<(u32, u32)>, &'static [corelib::animations::EasingKeyframe]>;
+    thread_local! {
+        static KEYFRAMES: core::cell::RefCell<Cache> = Default::default();
+    }
+    let key = keyframes.iter().map(|(a, b)| (a.to_bits(), b.to_bits())).collect::<Vec<_>>();
+    KEYFRAMES.with(|cache| {
+        let slice = *cache.borrow_mut().entry(key).or_insert_with(|| {
+            &*Box::leak(
+                keyframes
+                    .iter()
+                    .map(|(position, progress)| corelib::animations::EasingKeyframe {
+                        position: *position,
+                        progress: *progress,
+                    })
+                    .collect::<Box<[_]>>(),
+            )
+        });
+        corelib::slice::Slice::from_slice(slice)
+    })
+}
+
 pub fn new_struct_with_bindings<ElementType: 'static + Default + corelib::rtti::BuiltinItem>(
     bindings: &i_slint_compiler::object_tree::BindingsMap,
     local_context: &mut EvalLocalContext,
@@ -1301,10 +1641,19 @@ pub fn default_value_for_type(ty: &Type) -> Value {
             Value::Number(0.)
         }
         Type::Image => Value::Image(Default::default()),
+        Type::DateTime => Value::DateTime(Default::default()),
         Type::Bool => Value::Bool(false),
         Type::Callback { .. } => Value::Void,
         Type::Struct { fields, .. } => Value::Struct(
-            fields.iter().map(|(n, t)| (n.clone(), default_value_for_type(t))).collect::<Struct>(),
+            fields
+                .iter()
+                .map(|(n, t)| {
+                    let value = ty
+                        .struct_field_default(n)
+                        .map_or_else(|| default_value_for_type(t), |e| struct_field_default(&e));
+                    (n.clone(), value)
+                })
+                .collect::<Struct>(),
         ),
         Type::Array(_) | Type::Model => Value::Model(Default::default()),
         Type::Percent => Value::Number(0.),
@@ -1324,3 +1673,22 @@ pub fn default_value_for_type(ty: &Type) -> Value {
         }
     }
 }
+
+/// The value of the default of a struct field, which was folded to a literal
+/// (see [`i_slint_compiler::langtype::StructDefinition::field_defaults`])
+fn struct_field_default(expr: &Expression) -> Value {
+    match expr {
+        Expression::StringLiteral(s) => Value::String(s.into()),
+        Expression::NumberLiteral(n, unit) => Value::Number(unit.normalize(*n)),
+        Expression::BoolLiteral(b) => Value::Bool(*b),
+        Expression::EnumerationValue(value) => {
+            Value::EnumerationValue(value.enumeration.name.clone(), value.to_string())
+        }
+        Expression::Cast { from, to } => match (struct_field_default(from), to) {
+            (Value::Number(n), Type::Int32) => Value::Number(n.round()),
+            (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
+            (v, _) => v,
+        },
+        _ => default_value_for_type(&expr.ty()),
+    }
+}
@@ -108,6 +108,19 @@ pub extern "C" fn slint_interpreter_value_eq(a: &ValueOpaque, b: &ValueOpaque) -
     std::ptr::write(val as *mut Value, Value::Image(img.clone()))
 }
 
+/// Construct a new Value in the given memory location as a date-time, from a number of
+/// milliseconds since the Unix epoch
+#[no_mangle]
+pub unsafe extern "C" fn slint_interpreter_value_new_date_time(
+    timestamp_ms: i64,
+    val: *mut ValueOpaque,
+) {
+    std::ptr::write(
+        val as *mut Value,
+        Value::DateTime(DateTime::from_timestamp_millis(timestamp_ms)),
+    )
+}
+
 /// Construct a new Value containing a model in the given memory location
 #[no_mangle]
 pub unsafe extern "C" fn slint_interpreter_value_new_model(
@@ -207,6 +220,19 @@ pub extern "C" fn slint_interpreter_value_to_image(val: &ValueOpaque) -> Option<
     }
 }
 
+/// Writes the number of milliseconds since the Unix epoch of the date-time held by `val`
+/// into `out` and returns true; returns false if the value does not hold a date-time.
+#[no_mangle]
+pub extern "C" fn slint_interpreter_value_to_date_time(val: &ValueOpaque, out: &mut i64) -> bool {
+    match val.as_value() {
+        Value::DateTime(date) => {
+            *out = date.timestamp_millis();
+            true
+        }
+        _ => false,
+    }
+}
+
 #[repr(C)]
 #[cfg(target_pointer_width = "64")]
 pub struct StructOpaque([usize; 6]);
@@ -526,22 +552,8 @@ pub extern "C" fn slint_interpreter_component_instance_set_global_property(
         .description()
         .get_global(comp.borrow(), &normalize_identifier(std::str::from_utf8(&global).unwrap()))
         .and_then(|g| {
-            if matches!(
-                comp.description()
-                    .original
-                    .root_element
-                    .borrow()
-                    .lookup_property(callable_name)
-                    .property_type,
-                i_slint_compiler::langtype::Type::Function { .. }
-            ) {
-                g.as_ref().eval_function(
-                    &normalize_identifier(callable_name),
-                    args.as_slice().iter().cloned().collect(),
-                )
-            } else {
-                g.as_ref().invoke_callback(&normalize_identifier(callable_name), args.as_slice())
-            }
+            // This also calls the functions of the global
+            g.as_ref().invoke_callback(&normalize_identifier(callable_name), args.as_slice())
         }) {
         Ok(val) => {
             std::ptr::write(out as *mut Value, val);
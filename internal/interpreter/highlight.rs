@@ -355,6 +355,7 @@ fn add_highlight_items(doc: &Document) {
                         .collect(),
                     name: None,
                     node: None,
+                    definition: None,
                 }
                 .into(),
             ),
@@ -363,6 +364,8 @@ fn add_highlight_items(doc: &Document) {
             is_alias: None,
             visibility: PropertyVisibility::Input,
             pure: None,
+            is_const: false,
+            default_arguments: vec![],
         },
     );
     doc.root_component.root_element.borrow_mut().property_analysis.borrow_mut().insert(
@@ -460,6 +463,8 @@ fn add_current_item_callback(doc: &Document) {
             is_alias: None,
             visibility: PropertyVisibility::Private,
             pure: None,
+            is_const: false,
+            default_arguments: vec![],
         },
     );
     doc.root_component.root_element.borrow_mut().property_analysis.borrow_mut().insert(
@@ -481,6 +486,8 @@ fn add_current_item_callback(doc: &Document) {
             is_alias: None,
             visibility: PropertyVisibility::Input,
             pure: None,
+            is_const: false,
+            default_arguments: vec![],
         },
     );
     doc.root_component.root_element.borrow_mut().property_analysis.borrow_mut().insert(
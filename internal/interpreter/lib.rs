@@ -90,6 +90,10 @@
 #[doc(inline)]
 pub use i_slint_core::{Brush, Color, SharedString, SharedVector};
 
+/// (Re-export from corelib.)
+#[doc(inline)]
+pub use i_slint_core::date_time::DateTime;
+
 /// One need to use at least one function in each module in order to get them
 /// exported in the final binary.
 /// This only use functions from modules which are not otherwise used.
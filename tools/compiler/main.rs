@@ -47,6 +47,10 @@ struct Cli {
     #[arg(long, name = "value", value_enum)]
     embed_resources: Option<Embedding>,
 
+    /// Translation domain used to look up the translations of the strings marked with `@tr(...)`
+    #[arg(long = "translation-domain", action)]
+    translation_domain: Option<String>,
+
     /// Sets the output file ('-' for stdout)
     #[arg(name = "file to generate", short = 'o', default_value = "-", action)]
     output: std::path::PathBuf,
@@ -80,6 +84,7 @@ fn main() -> std::io::Result<()> {
     if let Some(style) = args.style {
         compiler_config.style = Some(style);
     }
+    compiler_config.translation_domain = args.translation_domain;
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
     let (doc, diag) = spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
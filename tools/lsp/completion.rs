@@ -193,6 +193,7 @@ pub(crate) fn completion_at(
             | SyntaxKind::UnaryOpExpression
             | SyntaxKind::Array
             | SyntaxKind::AtGradient
+            | SyntaxKind::AtTr
             | SyntaxKind::StringTemplate
             | SyntaxKind::IndexExpression
     ) {
@@ -205,6 +206,7 @@ pub(crate) fn completion_at(
                     ("image-url", "image-url(\"$1\")"),
                     ("linear-gradient", "linear-gradient($1)"),
                     ("radial-gradient", "radial-gradient(circle, $1)"),
+                    ("tr", "tr(\"$1\")"),
                 ]
                 .into_iter()
                 .map(|(label, insert)| {
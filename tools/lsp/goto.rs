@@ -29,6 +29,7 @@ pub fn goto_definition(
                     let doc = document_cache.documents.get_document(node.source_file.path())?;
                     match doc.local_registry.lookup_qualified(&qual.members) {
                         Type::Struct { node: Some(node), .. } => goto_node(node.parent().as_ref()?),
+                        Type::Enumeration(e) => goto_node(e.node.as_ref()?),
                         _ => None,
                     }
                 }
@@ -65,6 +66,20 @@ pub fn goto_definition(
                         Some(expr_it)
                     })?;
                     let gn = match lr? {
+                        LookupResult::Expression {
+                            expression: Expression::EnumerationValue(v),
+                            ..
+                        } => v
+                            .enumeration
+                            .node
+                            .as_ref()?
+                            .EnumValue()
+                            .find(|n| {
+                                i_slint_compiler::parser::identifier_text(n).as_ref()
+                                    == Some(&v.enumeration.values[v.value])
+                            })?
+                            .into(),
+                        LookupResult::Enumeration(e) => e.node.clone()?.into(),
                         LookupResult::Expression {
                             expression: Expression::ElementReference(e),
                             ..
@@ -58,6 +58,7 @@ pub fn get_semantic_tokens(
                 SyntaxKind::PropertyDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::Function => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyAnimation => Some((self::KEYWORD, 0)),
+                SyntaxKind::PropertyChangedCallback => Some((self::KEYWORD, 0)),
                 SyntaxKind::QualifiedName => match token.parent().parent()?.kind() {
                     SyntaxKind::Type => Some((self::TYPE, 0)),
                     // the base type
@@ -77,17 +78,19 @@ pub fn get_semantic_tokens(
                         SyntaxKind::CallbackDeclaration => {
                             Some((self::FUNCTION, 1 << self::DEFINITION))
                         }
-                        SyntaxKind::CallbackConnection => {
+                        SyntaxKind::CallbackConnection | SyntaxKind::Lambda => {
                             Some((self::PARAMETER, 1 << self::DEFINITION))
                         }
                         SyntaxKind::PropertyDeclaration => {
                             Some((self::PROPERTY, 1 << self::DEFINITION))
                         }
+                        SyntaxKind::PropertyChangedCallback => Some((self::PROPERTY, 0)),
                         SyntaxKind::State | SyntaxKind::Transition => {
                             // This is the state name, but what semantic type is that?
                             None
                         }
                         SyntaxKind::StructDeclaration => Some((self::TYPE, 1 << self::DEFINITION)),
+                        SyntaxKind::EnumDeclaration => Some((self::TYPE, 1 << self::DEFINITION)),
                         _ => None,
                     }
                 }
@@ -96,6 +99,7 @@ pub fn get_semantic_tokens(
                 SyntaxKind::ReturnStatement => Some((self::KEYWORD, 0)),
                 SyntaxKind::AtImageUrl => Some((self::MACRO, 0)),
                 SyntaxKind::AtGradient => Some((self::MACRO, 0)),
+                SyntaxKind::AtTr => Some((self::MACRO, 0)),
                 SyntaxKind::ConditionalExpression => Some((self::KEYWORD, 0)),
                 SyntaxKind::ObjectMember => Some((self::PROPERTY, 1 << self::DECLARATION)),
                 SyntaxKind::States => Some((self::KEYWORD, 0)),
@@ -132,6 +136,8 @@ pub fn get_semantic_tokens(
                 SyntaxKind::InternalName => Some((self::TYPE, 1 << self::DECLARATION)),
                 SyntaxKind::ObjectTypeMember => Some((self::PROPERTY, 1 << self::DEFINITION)),
                 SyntaxKind::StructDeclaration => Some((self::KEYWORD, 0)),
+                SyntaxKind::EnumDeclaration => Some((self::KEYWORD, 0)),
+                SyntaxKind::EnumValue => Some((self::PROPERTY, 1 << self::DEFINITION)),
                 _ => None,
             },
             SyntaxKind::PlusEqual
@@ -151,7 +157,9 @@ pub fn get_semantic_tokens(
             | SyntaxKind::Minus
             | SyntaxKind::Star
             | SyntaxKind::Div
-            | SyntaxKind::Equal => Some((self::OPERATOR, 0)),
+            | SyntaxKind::Equal
+            | SyntaxKind::Pipe
+            | SyntaxKind::Percent => Some((self::OPERATOR, 0)),
             SyntaxKind::Question => Some((self::OPERATOR, 0)),
             SyntaxKind::At => Some((self::MACRO, 0)),
             _ => None,
@@ -9,6 +9,7 @@
 use crate::{completion, goto, semantic_tokens, util};
 
 use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::expression_tree::Expression;
 use i_slint_compiler::langtype::Type;
 use i_slint_compiler::object_tree::ElementRc;
 use i_slint_compiler::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken};
@@ -256,6 +257,7 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 .into(),
             ),
             document_highlight_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(true.into()),
             rename_provider: Some(
                 if client_cap
                     .text_document
@@ -328,18 +330,10 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         });
         Ok(result)
     });
-    rh.register::<HoverRequest, _>(|_params, _ctx| async move {
-        /*let result =
-            token_descr(document_cache, params.text_document_position_params).map(|x| Hover {
-                contents: lsp_types::HoverContents::Scalar(MarkedString::from_language_code(
-                    "text".into(),
-                    format!("{:?}", x.token),
-                )),
-                range: None,
-            });
-        let resp = Response::new_ok(id, result);
-        connection.sender.send(Message::Response(resp))?;*/
-        Ok(None::<Hover>)
+    rh.register::<HoverRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let params = &params.text_document_position_params;
+        Ok(get_hover(document_cache, &params.text_document.uri, &params.position))
     });
     rh.register::<CodeActionRequest, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
@@ -1056,6 +1050,59 @@ pub fn token_at_offset(doc: &syntax_nodes::Document, offset: u32) -> Option<Synt
     Some(SyntaxToken { token, source_file: doc.source_file.clone() })
 }
 
+/// Show the value of a property binding that can be folded into a constant
+fn get_hover(
+    document_cache: &mut DocumentCache,
+    text_document_uri: &Url,
+    pos: &Position,
+) -> Option<Hover> {
+    let (token, _) = token_descr(document_cache, text_document_uri, pos)?;
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let parent = token.parent();
+    let is_property_name = match parent.kind() {
+        SyntaxKind::Binding => true,
+        SyntaxKind::DeclaredIdentifier => {
+            parent.parent().map_or(false, |p| p.kind() == SyntaxKind::PropertyDeclaration)
+        }
+        _ => false,
+    };
+    if !is_property_name {
+        return None;
+    }
+    let element = element_at_position(document_cache, text_document_uri, pos)?;
+    let name = i_slint_compiler::parser::normalize_identifier(token.text());
+    let value = {
+        let element = element.borrow();
+        let binding = element.bindings.get(&name)?.borrow();
+        i_slint_compiler::passes::const_propagation::constant_value(&binding.expression)?
+    };
+    Some(Hover {
+        contents: lsp_types::HoverContents::Scalar(lsp_types::MarkedString::from_language_code(
+            "slint".into(),
+            format!("{}: {}", name, format_constant(&value)),
+        )),
+        range: map_token(&token),
+    })
+}
+
+/// Format a folded constant with the .slint syntax
+fn format_constant(value: &Expression) -> String {
+    match value {
+        Expression::Cast { from, to: Type::Color } => match **from {
+            Expression::NumberLiteral(argb, _) => format!("#{:08x}", (argb as u32).rotate_left(8)),
+            _ => format_constant(from),
+        },
+        Expression::Cast { from, .. } => format_constant(from),
+        _ => {
+            let mut result = String::new();
+            let _ = i_slint_compiler::expression_tree::pretty_print(&mut result, value);
+            result
+        }
+    }
+}
+
 fn get_code_actions(
     _document_cache: &mut DocumentCache,
     node: SyntaxNode,
@@ -1136,7 +1183,7 @@ fn get_document_symbols(
     .unwrap();
 
     let inner_components = doc.inner_components.clone();
-    let inner_structs = doc.inner_structs.clone();
+    let inner_types = doc.inner_types.clone();
 
     let mut r = inner_components
         .iter()
@@ -1161,7 +1208,7 @@ fn get_document_symbols(
         })
         .collect::<Vec<_>>();
 
-    r.extend(inner_structs.iter().filter_map(|c| match c {
+    r.extend(inner_types.iter().filter_map(|c| match c {
         Type::Struct { name: Some(name), node: Some(node), .. } => Some(DocumentSymbol {
             range: map_node(node.parent().as_ref()?)?,
             selection_range: map_node(node)?,
@@ -1169,6 +1216,15 @@ fn get_document_symbols(
             kind: lsp_types::SymbolKind::STRUCT,
             ..ds.clone()
         }),
+        Type::Enumeration(enumeration) => enumeration.node.as_ref().and_then(|node| {
+            Some(DocumentSymbol {
+                range: map_node(node)?,
+                selection_range: map_node(&node.DeclaredIdentifier())?,
+                name: enumeration.name.clone(),
+                kind: lsp_types::SymbolKind::ENUM,
+                ..ds.clone()
+            })
+        }),
         _ => None,
     }));
 
@@ -1555,4 +1611,34 @@ fn test_document_symbols_hello_world() {
             unreachable!();
         }
     }
+
+    #[test]
+    fn test_hover_constant_value() {
+        let (mut dc, url, _) = loaded_document_cache(
+            r#"export component Main inherits Rectangle {
+    const property <length> unit: 4px;
+    property <length> margin: unit * 2;
+    const property <int> answer: 6 * 7;
+    property <color> tint: #ff000080;
+    property <bool> pressed: touch.pressed;
+    touch := TouchArea { width: 3px + 4px; }
+}"#
+            .into(),
+        );
+
+        let mut hover = |line, character| {
+            get_hover(&mut dc, &url, &Position::new(line, character)).map(|h| match h.contents {
+                lsp_types::HoverContents::Scalar(lsp_types::MarkedString::LanguageString(s)) => {
+                    s.value
+                }
+                _ => unreachable!(),
+            })
+        };
+        assert_eq!(hover(1, 30).as_deref(), Some("unit: 4px"));
+        assert_eq!(hover(2, 24).as_deref(), Some("margin: 8px"));
+        assert_eq!(hover(3, 27).as_deref(), Some("answer: 42"));
+        assert_eq!(hover(4, 22).as_deref(), Some("tint: #ff000080"));
+        assert_eq!(hover(5, 22), None);
+        assert_eq!(hover(6, 27).as_deref(), Some("width: 7px"));
+    }
 }
@@ -0,0 +1,214 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+    Extract the strings marked with `@tr(...)` in .slint files into a gettext template (.pot) file.
+    ```sh
+        cargo run --bin slint-tr-extractor -- -o messages.pot some_file.slint
+    ```
+*/
+
+use clap::Parser;
+use i_slint_compiler::diagnostics::{BuildDiagnostics, Spanned};
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode};
+use std::collections::HashMap;
+use std::io::Write;
+
+#[derive(clap::Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[arg(name = "path to .slint file(s)", action)]
+    paths: Vec<std::path::PathBuf>,
+
+    /// Write the output to the specified file ('-' for stdout)
+    #[arg(short = 'o', default_value = "messages.pot", action)]
+    output: std::path::PathBuf,
+
+    /// The package name written in the header of the .pot file
+    #[arg(long = "package-name", action)]
+    package_name: Option<String>,
+
+    /// The package version written in the header of the .pot file
+    #[arg(long = "package-version", action)]
+    package_version: Option<String>,
+}
+
+#[derive(Default)]
+struct Message {
+    context: String,
+    msgid: String,
+    plural: Option<String>,
+    locations: Vec<String>,
+}
+
+/// The messages in the order in which they are found
+#[derive(Default)]
+struct Messages {
+    messages: Vec<Message>,
+    index: HashMap<(String, String), usize>,
+}
+
+impl Messages {
+    fn add(&mut self, context: String, msgid: String, plural: Option<String>, location: String) {
+        let index = *self.index.entry((context.clone(), msgid.clone())).or_insert_with(|| {
+            self.messages.push(Message { context, msgid, ..Default::default() });
+            self.messages.len() - 1
+        });
+        let message = &mut self.messages[index];
+        if message.plural.is_none() {
+            message.plural = plural;
+        }
+        message.locations.push(location);
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    let args = Cli::parse();
+
+    let mut messages = Messages::default();
+    let mut diag = BuildDiagnostics::default();
+    for path in &args.paths {
+        if let Some(doc) = i_slint_compiler::parser::parse_file(path, &mut diag) {
+            visit_node(&doc, &mut messages);
+        }
+    }
+    if diag.has_error() {
+        diag.print();
+        std::process::exit(1);
+    }
+
+    if args.output == std::path::Path::new("-") {
+        write_pot(&mut std::io::stdout(), &messages, &args)
+    } else {
+        write_pot(&mut std::fs::File::create(&args.output)?, &messages, &args)
+    }
+}
+
+fn visit_node(node: &SyntaxNode, messages: &mut Messages) {
+    for n in node.children() {
+        if let Some(tr) = syntax_nodes::AtTr::new(n.clone()) {
+            extract_message(&tr, messages);
+        }
+        visit_node(&n, messages);
+    }
+}
+
+fn extract_message(tr: &syntax_nodes::AtTr, messages: &mut Messages) {
+    let unescape = |node: &SyntaxNode| {
+        node.child_text(SyntaxKind::StringLiteral)
+            .and_then(|s| i_slint_compiler::literals::unescape_string(&s))
+    };
+    let Some(msgid) = unescape(tr) else { return };
+    let context = tr.TrContext().and_then(|n| unescape(&n)).unwrap_or_default();
+    let plural = tr.TrPlural().and_then(|n| unescape(&n));
+
+    let (line, _) = tr.source_file.line_column(tr.span().offset);
+    let location = format!("{}:{}", tr.source_file.path().display(), line);
+    messages.add(context, msgid, plural, location);
+}
+
+fn write_pot(out: &mut impl Write, messages: &Messages, args: &Cli) -> std::io::Result<()> {
+    writeln!(out, "# SOME DESCRIPTIVE TITLE.")?;
+    writeln!(out, "# This file is distributed under the same license as the PACKAGE package.")?;
+    writeln!(out, "# FIRST AUTHOR <EMAIL@ADDRESS>, YEAR.")?;
+    writeln!(out, "#")?;
+    writeln!(out, "#, fuzzy")?;
+    writeln!(out, "msgid \"\"")?;
+    writeln!(out, "msgstr \"\"")?;
+    writeln!(
+        out,
+        "\"Project-Id-Version: {} {}\\n\"",
+        args.package_name.as_deref().unwrap_or("PACKAGE"),
+        args.package_version.as_deref().unwrap_or("VERSION")
+    )?;
+    writeln!(out, "\"PO-Revision-Date: YEAR-MO-DA HO:MI+ZONE\\n\"")?;
+    writeln!(out, "\"Last-Translator: FULL NAME <EMAIL@ADDRESS>\\n\"")?;
+    writeln!(out, "\"Language-Team: LANGUAGE <LL@li.org>\\n\"")?;
+    writeln!(out, "\"Language: \\n\"")?;
+    writeln!(out, "\"MIME-Version: 1.0\\n\"")?;
+    writeln!(out, "\"Content-Type: text/plain; charset=UTF-8\\n\"")?;
+    writeln!(out, "\"Content-Transfer-Encoding: 8bit\\n\"")?;
+    writeln!(out, "\"Plural-Forms: nplurals=INTEGER; plural=EXPRESSION;\\n\"")?;
+
+    for message in &messages.messages {
+        writeln!(out)?;
+        writeln!(out, "#: {}", message.locations.join(" "))?;
+        if !message.context.is_empty() {
+            writeln!(out, "msgctxt {}", escape(&message.context))?;
+        }
+        writeln!(out, "msgid {}", escape(&message.msgid))?;
+        if let Some(plural) = &message.plural {
+            writeln!(out, "msgid_plural {}", escape(plural))?;
+            writeln!(out, "msgstr[0] \"\"")?;
+            writeln!(out, "msgstr[1] \"\"")?;
+        } else {
+            writeln!(out, "msgstr \"\"")?;
+        }
+    }
+    Ok(())
+}
+
+/// Quote and escape a string for the .po format
+fn escape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[test]
+fn test_extract() {
+    let source = r#"
+export component Foo {
+    property <int> count;
+    Text { text: @tr("Hello"); }
+    Text { text: @tr("Menu" => "Open \"{}\"", "file"); }
+    Text { text: @tr("one file" | "{n} files" % count); }
+    Text { text: @tr("Hello"); }
+}
+"#;
+    let mut diag = BuildDiagnostics::default();
+    let doc = i_slint_compiler::parser::parse(
+        source.into(),
+        Some(std::path::Path::new("foo.slint")),
+        &mut diag,
+    );
+    assert!(!diag.has_error());
+    let mut messages = Messages::default();
+    visit_node(&doc, &mut messages);
+    let args = Cli::parse_from(["slint-tr-extractor"]);
+    let mut output = Vec::new();
+    write_pot(&mut output, &messages, &args).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let body = &output[output.find("\n\n").unwrap()..];
+    assert_eq!(
+        body,
+        r#"
+
+#: foo.slint:4 foo.slint:7
+msgid "Hello"
+msgstr ""
+
+#: foo.slint:5
+msgctxt "Menu"
+msgid "Open \"{}\""
+msgstr ""
+
+#: foo.slint:6
+msgid "one file"
+msgid_plural "{n} files"
+msgstr[0] ""
+msgstr[1] ""
+"#
+    );
+}